@@ -5,7 +5,8 @@ use std::process::ExitCode;
 
 use bugbite::config::Config;
 use bugbite::output::verbose;
-use clap::{Parser, error::ErrorKind};
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use clap_complete::CompleteEnv;
 use clap_verbosity_flag::{Verbosity, WarnLevel, log::LevelFilter};
 use tracing_log::AsTrace;
 
@@ -73,8 +74,15 @@ pub(crate) struct Command {
     subcmd: Subcommand,
 }
 
+fn main() -> anyhow::Result<ExitCode> {
+    // handle dynamic shell completion requests
+    CompleteEnv::with_factory(Command::command).complete();
+
+    run()
+}
+
 #[tokio::main]
-async fn main() -> anyhow::Result<ExitCode> {
+async fn run() -> anyhow::Result<ExitCode> {
     // TODO: drop this once stable rust supports `unix_sigpipe`,
     // see https://github.com/rust-lang/rust/issues/97889.
     //
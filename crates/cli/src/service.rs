@@ -4,8 +4,15 @@ use clap::Args;
 
 // service modules
 pub(crate) mod bugzilla;
+pub(crate) mod debbugs;
+pub(crate) mod gitea;
 pub(crate) mod github;
+pub(crate) mod gitlab;
+pub(crate) mod jira;
+pub(crate) mod launchpad;
+pub(crate) mod mantis;
 pub(crate) mod redmine;
+pub(crate) mod trac;
 
 #[derive(Args, Debug)]
 #[clap(next_help_heading = "Service options")]
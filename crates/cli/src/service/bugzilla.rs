@@ -2,6 +2,7 @@ use std::io::{IsTerminal, Write};
 use std::process::ExitCode;
 
 use bugbite::config::Config;
+use bugbite::service::ClientParameters;
 use bugbite::service::bugzilla::Bugzilla;
 use clap::Args;
 use tracing::debug;
@@ -9,13 +10,22 @@ use tracing::debug;
 mod attachment;
 mod comment;
 mod create;
+mod dupes;
+mod edit;
 mod fields;
+mod flag_types;
 mod get;
+mod graph;
 mod history;
+mod migrate;
+mod product;
+mod requests;
+mod revert;
 mod search;
 mod update;
 mod user;
 mod version;
+mod watch;
 
 #[derive(Args, Debug)]
 pub(crate) struct Command {
@@ -31,11 +41,11 @@ impl Command {
     where
         W: IsTerminal + Write,
     {
-        let service = Bugzilla::config_builder(config, self.service.connection.as_deref())?
-            .client(self.service.into())
-            .build()?;
+        let builder = Bugzilla::config_builder(config, self.service.connection.as_deref())?;
+        let client = ClientParameters::from(self.service);
+        let service = builder.client(client.clone()).build()?;
         debug!("Service: {service}");
-        self.cmd.run(&service, f).await
+        self.cmd.run(config, &client, &service, f).await
     }
 }
 
@@ -52,16 +62,41 @@ enum Subcommand {
     #[command(visible_alias = "c")]
     Create(Box<create::Command>),
 
+    /// Find possible duplicate bugs
+    Dupes(Box<dupes::Command>),
+
+    /// Interactively edit bugs
+    #[command(visible_alias = "e")]
+    Edit(Box<edit::Command>),
+
     /// Get bugzilla fields
     Fields(Box<fields::Command>),
 
+    /// Get flag types
+    FlagTypes(Box<flag_types::Command>),
+
     /// Get bugs
     #[command(visible_alias = "g")]
     Get(Box<get::Command>),
 
+    /// Get bug dependency graph
+    Graph(Box<graph::Command>),
+
     /// Get bug changes
     History(Box<history::Command>),
 
+    /// Migrate bugs to another connection
+    Migrate(Box<migrate::Command>),
+
+    /// Get products
+    Product(Box<product::Command>),
+
+    /// Get flag requests
+    Requests(Box<requests::Command>),
+
+    /// Revert bug changes
+    Revert(Box<revert::Command>),
+
     /// Search bugs
     #[command(visible_alias = "s")]
     Search(Box<search::Command>),
@@ -75,10 +110,19 @@ enum Subcommand {
 
     /// Get bugzilla version
     Version(Box<version::Command>),
+
+    /// Watch searches for changes
+    Watch(Box<watch::Command>),
 }
 
 impl Subcommand {
-    async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    async fn run<W>(
+        self,
+        config: &Config,
+        client: &ClientParameters,
+        service: &Bugzilla,
+        f: &mut W,
+    ) -> anyhow::Result<ExitCode>
     where
         W: IsTerminal + Write,
     {
@@ -86,13 +130,22 @@ impl Subcommand {
             Self::Attachment(cmd) => cmd.run(service, f).await,
             Self::Comment(cmd) => cmd.run(service, f).await,
             Self::Create(cmd) => cmd.run(service, f).await,
+            Self::Dupes(cmd) => cmd.run(service, f).await,
+            Self::Edit(cmd) => cmd.run(service, f).await,
             Self::Fields(cmd) => cmd.run(service, f).await,
+            Self::FlagTypes(cmd) => cmd.run(service, f).await,
             Self::Get(cmd) => cmd.run(service, f).await,
+            Self::Graph(cmd) => cmd.run(service, f).await,
             Self::History(cmd) => cmd.run(service, f).await,
+            Self::Migrate(cmd) => cmd.run(config, client, service, f).await,
+            Self::Product(cmd) => cmd.run(service, f).await,
+            Self::Requests(cmd) => cmd.run(service, f).await,
+            Self::Revert(cmd) => cmd.run(service, f).await,
             Self::Search(cmd) => cmd.run(service, f).await,
             Self::Update(cmd) => cmd.run(service, f).await,
             Self::User(cmd) => cmd.run(service, f).await,
             Self::Version(cmd) => cmd.run(service, f).await,
+            Self::Watch(cmd) => cmd.run(service, f).await,
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::io::{IsTerminal, Write};
+use std::io::{self, IsTerminal, Write};
 use std::process::ExitCode;
 
 use bugbite::args::MaybeStdinVec;
@@ -9,9 +9,11 @@ use bugbite::service::bugzilla::create::*;
 use bugbite::traits::{Merge, RequestSend, RequestTemplate};
 use bugbite::utils::is_terminal;
 use clap::Args;
+use clap_complete::engine::ArgValueCandidates;
 use itertools::Itertools;
 
 use crate::service::TemplateOptions;
+use crate::utils::confirm;
 
 #[derive(Args, Debug)]
 #[clap(next_help_heading = "Attribute options")]
@@ -33,7 +35,11 @@ struct Params {
     cc: Option<Vec<String>>,
 
     /// set component
-    #[arg(short = 'C', long)]
+    #[arg(
+        short = 'C',
+        long,
+        add = ArgValueCandidates::new(super::product::complete_components),
+    )]
     component: Option<String>,
 
     /// set custom field
@@ -83,7 +89,11 @@ struct Params {
     priority: Option<String>,
 
     /// set product
-    #[arg(short, long)]
+    #[arg(
+        short,
+        long,
+        add = ArgValueCandidates::new(super::product::complete_products),
+    )]
     product: Option<String>,
 
     /// set QA contact
@@ -165,9 +175,17 @@ impl From<Params> for Parameters {
 #[derive(Args, Debug)]
 #[clap(next_help_heading = "Create options")]
 pub(super) struct Options {
+    /// check for possible duplicates before creating
+    #[arg(long)]
+    check_dupes: bool,
+
     /// read attributes from an existing bug
     #[arg(long, value_name = "ID", conflicts_with = "from")]
     from_bug: Option<String>,
+
+    /// validate product-related values
+    #[arg(long)]
+    validate: bool,
 }
 
 #[derive(Args, Debug)]
@@ -214,7 +232,22 @@ impl Command {
         }
 
         if !self.template.dry_run {
-            let id = request.send().await?;
+            if self.options.check_dupes {
+                let candidates = request.dupes()?.send().await?;
+                if !candidates.is_empty() {
+                    let mut stderr = io::stderr().lock();
+                    writeln!(stderr, "Possible duplicates:")?;
+                    for candidate in &candidates {
+                        writeln!(stderr, "  {candidate}")?;
+                    }
+                    if !io::stdin().is_terminal() || !confirm("Create bug anyway?", false)? {
+                        let ids = candidates.iter().map(|x| x.bug.id).join(", ");
+                        anyhow::bail!("possible duplicates: {ids}");
+                    }
+                }
+            }
+
+            let id = request.validate(self.options.validate).send().await?;
             if is_terminal!(f) {
                 verbose!(f, "Created bug {id}")?;
             } else {
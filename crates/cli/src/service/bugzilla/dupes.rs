@@ -0,0 +1,54 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::traits::RequestSend;
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Dupes options")]
+struct Options {
+    /// maximum number of candidates
+    #[arg(short, long, value_name = "NUM", default_value = "5")]
+    limit: usize,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    /// bug ID or alias
+    #[clap(help_heading = "Arguments")]
+    id: String,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let bug = service
+            .get([&self.id])
+            .send()
+            .await?
+            .into_iter()
+            .next()
+            .expect("failed getting bug");
+        let Some(summary) = &bug.summary else {
+            anyhow::bail!("bug {} missing summary", bug.id);
+        };
+
+        let mut request = service.dupes(summary);
+        request.exclude(bug.id).limit(self.options.limit);
+        if let Some(value) = &bug.product {
+            request.product(value);
+        }
+
+        for candidate in request.send().await? {
+            writeln!(f, "{candidate}")?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
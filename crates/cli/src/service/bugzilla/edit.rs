@@ -0,0 +1,101 @@
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::process::ExitCode;
+
+use anyhow::Context;
+use bugbite::args::MaybeStdinVec;
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::edit;
+use bugbite::service::bugzilla::update::bulk::{self, Entry, Format};
+use bugbite::traits::RequestSend;
+use camino_tempfile::Builder;
+use clap::Args;
+
+use crate::utils::{confirm, launch_editor};
+
+use super::update::update_bulk;
+
+/// Instructions prepended to documents for editing.
+const HEADER: &str = "\
+# Edit bug fields below, saving the file to apply the changes.
+#
+# List fields are compared to their original values, adding or removing the
+# differences. Fields lacking values can be added, e.g. comment = \"text\".
+# Removing a field leaves it unchanged while setting it to \"\" clears it.
+# Removing a bug's table skips it while removing all bugs aborts the edit.
+
+";
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Edit options")]
+struct Options {
+    /// validate product-related values
+    #[arg(long)]
+    validate: bool,
+
+    /// output changes without applying them
+    #[arg(short = 'n', long)]
+    dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// bug IDs or aliases
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+/// Interactively edit bug field values, returning the changes made.
+fn edit_entries(entries: &[Entry]) -> anyhow::Result<Vec<Entry>> {
+    let temp_file = Builder::new().suffix(".toml").tempfile()?;
+    let data = format!("{HEADER}{}", bulk::dump(entries, Format::Toml)?);
+    fs::write(&temp_file, data).context("failed saving bug file")?;
+
+    loop {
+        let status = launch_editor(temp_file.path())?;
+        if !status.success() {
+            anyhow::bail!("failed editing bugs");
+        }
+        let data = fs::read_to_string(&temp_file).context("failed reading bug file")?;
+        match bulk::parse(&data, Format::Toml).and_then(|x| edit::entries(entries, &x)) {
+            Ok(changes) => return Ok(changes),
+            Err(e) => {
+                if !io::stdin().is_terminal() {
+                    return Err(e.into());
+                }
+                if !confirm(format!("Error: {e}\nEdit again?"), true)? {
+                    anyhow::bail!("failed editing bugs");
+                }
+            }
+        }
+    }
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let entries = service.edit(ids).send().await?;
+        let changes = edit_entries(&entries)?;
+        if changes.is_empty() {
+            writeln!(f, "No changes made")?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        if self.options.dry_run {
+            write!(f, "{}", bulk::dump(&changes, Format::Toml)?)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let mut request = service.update_bulk(changes);
+        request.validate(self.options.validate);
+        update_bulk(&request, None, f).await
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::traits::RequestSend;
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Flag type options")]
+struct Options {
+    /// output requestable flag names only
+    #[arg(short, long)]
+    list: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    /// product name
+    #[clap(help_heading = "Arguments")]
+    product: String,
+
+    /// component name
+    #[clap(help_heading = "Arguments")]
+    component: Option<String>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(&self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.flag_types(&self.product);
+        if let Some(value) = &self.component {
+            request.component(value);
+        }
+        let flag_types = request.send().await?;
+
+        if self.options.list {
+            let flags = flag_types.bug.iter().chain(&flag_types.attachment);
+            for flag in flags.filter(|x| x.is_requestable) {
+                writeln!(f, "{}", flag.name)?;
+            }
+        } else {
+            for (kind, flags) in [
+                ("Bug", &flag_types.bug),
+                ("Attachment", &flag_types.attachment),
+            ] {
+                if !flags.is_empty() {
+                    writeln!(f, "{kind} flags:")?;
+                    for flag in flags {
+                        writeln!(f, "  {flag}")?;
+                    }
+                }
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
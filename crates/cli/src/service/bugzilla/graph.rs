@@ -0,0 +1,90 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::MaybeStdinVec;
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::graph::Relation;
+use bugbite::traits::RequestSend;
+use clap::Args;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use strum::{Display, EnumString, VariantNames};
+
+/// Graph output formats.
+#[derive(Display, EnumString, VariantNames, Debug, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+enum Format {
+    Dot,
+    Json,
+    Tree,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Graph options")]
+struct Options {
+    /// maximum traversal depth
+    #[arg(short, long, value_name = "NUM")]
+    depth: Option<usize>,
+
+    /// output format
+    #[arg(
+        short,
+        long,
+        value_name = "FORMAT",
+        default_value = "tree",
+        value_parser = PossibleValuesParser::new(Format::VARIANTS)
+            .map(|s| s.parse::<Format>().unwrap()),
+    )]
+    format: Format,
+
+    /// hide resolved bugs
+    #[arg(long)]
+    hide_resolved: bool,
+
+    /// relation to follow
+    #[arg(
+        short,
+        long,
+        value_name = "RELATION",
+        default_value = "depends",
+        value_parser = PossibleValuesParser::new(Relation::VARIANTS)
+            .map(|s| s.parse::<Relation>().unwrap()),
+    )]
+    relation: Relation,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// bug IDs or aliases
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(&self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.iter().flatten();
+        let mut request = service.graph(ids);
+        request
+            .relation(self.options.relation)
+            .resolved(!self.options.hide_resolved);
+        if let Some(value) = self.options.depth {
+            request.depth(value);
+        }
+        let graph = request.send().await?;
+
+        match self.options.format {
+            Format::Dot => write!(f, "{}", graph.dot())?,
+            Format::Json => writeln!(f, "{}", serde_json::to_string(&graph)?)?,
+            Format::Tree => write!(f, "{}", graph.tree())?,
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
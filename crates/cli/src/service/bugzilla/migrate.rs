@@ -0,0 +1,86 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::MaybeStdinVec;
+use bugbite::config::Config;
+use bugbite::service::ClientParameters;
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::migrate::Reference;
+use bugbite::traits::RequestSend;
+use clap::Args;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use strum::VariantNames;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Migrate options")]
+struct Options {
+    /// target connection
+    #[arg(long, value_name = "CONNECTION")]
+    to_connection: String,
+
+    /// cross-reference type
+    #[arg(
+        long,
+        value_name = "TYPE",
+        default_value = "comment",
+        value_parser = PossibleValuesParser::new(Reference::VARIANTS)
+            .map(|s| s.parse::<Reference>().unwrap()),
+    )]
+    reference: Reference,
+
+    /// close source bugs
+    #[arg(
+        long = "move",
+        value_name = "STATUS[:RESOLUTION]",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "RESOLVED:MOVED",
+    )]
+    close: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// bug IDs or aliases
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(
+        self,
+        config: &Config,
+        client: &ClientParameters,
+        service: &Bugzilla,
+        f: &mut W,
+    ) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        // apply command line client options to the target's configured options
+        let target = Bugzilla::config_builder(config, Some(&self.options.to_connection))?
+            .client(client.clone())
+            .build()?;
+
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.migrate(&target, ids);
+        request.reference(self.options.reference);
+        if let Some(value) = &self.options.close {
+            match value.split_once(':') {
+                Some((status, resolution)) => request.close(status, Some(resolution)),
+                None => request.close(value.as_str(), None),
+            };
+        }
+
+        for migration in request.send().await? {
+            writeln!(f, "{migration}")?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
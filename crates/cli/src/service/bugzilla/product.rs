@@ -0,0 +1,164 @@
+use std::env;
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::MaybeStdinVec;
+use bugbite::config::Config;
+use bugbite::objects::bugzilla::Product;
+use bugbite::output::render_items;
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::product::ProductType;
+use bugbite::traits::RequestSend;
+use clap::Args;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap_complete::engine::CompletionCandidate;
+use itertools::Itertools;
+use strum::VariantNames;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Product options")]
+struct Options {
+    /// restrict by classification
+    #[arg(short, long, value_name = "NAME", conflicts_with = "ids")]
+    classification: Option<String>,
+
+    /// output names only
+    #[arg(short, long)]
+    list: bool,
+
+    /// product type
+    #[arg(
+        short = 't',
+        long = "type",
+        value_name = "TYPE",
+        default_value = "accessible",
+        conflicts_with_all = ["classification", "ids"],
+        value_parser = PossibleValuesParser::new(ProductType::VARIANTS)
+            .map(|s| s.parse::<ProductType>().unwrap()),
+    )]
+    kind: ProductType,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// product IDs or names
+    #[clap(help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(&self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut ids: Vec<_> = self.ids.iter().flatten().cloned().collect();
+
+        // pull product names from the classification
+        if let Some(name) = &self.options.classification {
+            for classification in service.classification([name]).send().await? {
+                ids.extend(classification.products.into_iter().map(|x| x.name));
+            }
+            if ids.is_empty() {
+                return Ok(ExitCode::SUCCESS);
+            }
+        }
+
+        let products = service.product(&ids).kind(self.options.kind).send().await?;
+
+        if self.options.list {
+            // output component names for specified products, otherwise product names
+            for product in &products {
+                if !self.ids.is_empty() {
+                    for component in product.components.iter().filter(|x| x.is_active) {
+                        writeln!(f, "{}", component.name)?;
+                    }
+                } else if product.is_active {
+                    writeln!(f, "{}", product.name)?;
+                }
+            }
+        } else {
+            render_items(f, &products)?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+/// Return the last value of an option from the command line being completed.
+fn completion_arg(short: &str, long: &str) -> Option<String> {
+    let prefix = format!("{long}=");
+    let args: Vec<_> = env::args().skip_while(|x| x != "--").skip(1).collect();
+    let mut iter = args.iter();
+    let mut value = None;
+    while let Some(arg) = iter.next() {
+        if arg == short || arg == long {
+            value = iter.next().cloned();
+        } else if let Some(x) = arg.strip_prefix(&prefix) {
+            value = Some(x.to_string());
+        }
+    }
+    value
+}
+
+/// Return enterable products from the connection of the command line being completed.
+async fn enterable_products(ids: &[String]) -> anyhow::Result<Vec<Product>> {
+    let config = Config::new()?;
+    let connection = completion_arg("-c", "--connection");
+    let service = Bugzilla::config_builder(&config, connection.as_deref())?.build()?;
+    let products = service
+        .product(ids)
+        .kind(ProductType::Enterable)
+        .send()
+        .await?;
+    Ok(products)
+}
+
+/// Return completion candidates using product data.
+fn candidates<F>(ids: &[String], func: F) -> Vec<CompletionCandidate>
+where
+    F: FnOnce(Vec<Product>) -> Vec<CompletionCandidate>,
+{
+    // completion runs before the main runtime is started
+    let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    else {
+        return Default::default();
+    };
+
+    // request failures result in no candidates
+    runtime
+        .block_on(enterable_products(ids))
+        .map(func)
+        .unwrap_or_default()
+}
+
+/// Complete active product names.
+pub(super) fn complete_products() -> Vec<CompletionCandidate> {
+    candidates(&[], |products| {
+        products
+            .into_iter()
+            .filter(|x| x.is_active)
+            .map(|x| CompletionCandidate::new(x.name).help(x.description.map(Into::into)))
+            .collect()
+    })
+}
+
+/// Complete active component names, restricted to the specified product if it exists.
+pub(super) fn complete_components() -> Vec<CompletionCandidate> {
+    let ids: Vec<_> = completion_arg("-p", "--product").into_iter().collect();
+    candidates(&ids, |products| {
+        products
+            .into_iter()
+            .flat_map(|x| x.components)
+            .filter(|x| x.is_active)
+            .map(|x| CompletionCandidate::new(x.name).help(x.description.map(Into::into)))
+            .unique_by(|x| x.get_value().to_os_string())
+            .collect()
+    })
+}
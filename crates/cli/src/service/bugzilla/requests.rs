@@ -0,0 +1,126 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::requests::{DEFAULT_FLAGS, Direction, FlagRequest};
+use bugbite::time::TimeDelta;
+use bugbite::traits::RequestSend;
+use clap::Args;
+use crossterm::style::Stylize;
+use itertools::Itertools;
+use serde::Serialize;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Request options")]
+struct Options {
+    /// restrict by flag names
+    #[arg(
+        short,
+        long,
+        value_name = "NAME[,...]",
+        value_delimiter = ',',
+        default_values = DEFAULT_FLAGS,
+    )]
+    flags: Vec<String>,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+
+    /// age of stale requests
+    #[arg(short, long, value_name = "TIME", default_value = "1w")]
+    stale: TimeDelta,
+
+    /// user requests are for
+    #[arg(short, long, value_name = "USER")]
+    user: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+}
+
+/// Flag request including its stale status.
+#[derive(Serialize)]
+struct Item<'a> {
+    #[serde(flatten)]
+    request: &'a FlagRequest,
+    stale: bool,
+}
+
+/// Render the age of a request.
+fn age(request: &FlagRequest) -> String {
+    let delta = request.age();
+    if delta.num_days() > 0 {
+        format!("{}d", delta.num_days())
+    } else if delta.num_hours() > 0 {
+        format!("{}h", delta.num_hours())
+    } else {
+        format!("{}min", delta.num_minutes())
+    }
+}
+
+impl Command {
+    pub(super) async fn run<W>(&self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.requests();
+        request.flags(&self.options.flags);
+        if let Some(value) = &self.options.user {
+            request.user(value);
+        }
+        let requests = request.send().await?;
+        let stale = self.options.stale.datetime();
+
+        if self.options.json {
+            for request in &requests {
+                let item = Item {
+                    request,
+                    stale: request.created < stale,
+                };
+                writeln!(f, "{}", serde_json::to_string(&item)?)?;
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let color = f.is_terminal();
+        for (direction, requests) in &requests.iter().chunk_by(|x| x.direction) {
+            match direction {
+                Direction::Incoming => writeln!(f, "Incoming requests:")?,
+                Direction::Outgoing => writeln!(f, "Outgoing requests:")?,
+            }
+
+            for (flag, requests) in &requests.chunk_by(|x| &x.flag) {
+                let requests: Vec<_> = requests.collect();
+                writeln!(f, "  {flag} ({})", requests.len())?;
+                for request in requests {
+                    // show who made incoming requests and who outgoing requests are for
+                    let user = match direction {
+                        Direction::Incoming => request.setter.as_str(),
+                        Direction::Outgoing => request.requestee.as_deref().unwrap_or("-"),
+                    };
+                    let summary = request.summary.as_deref().unwrap_or_default();
+                    let line = format!(
+                        "{:<8} {:>6}  {user:<24} {summary}",
+                        request.bug,
+                        age(request)
+                    );
+                    if request.created < stale {
+                        if color {
+                            writeln!(f, "  ! {}", line.red())?;
+                        } else {
+                            writeln!(f, "  ! {line}")?;
+                        }
+                    } else {
+                        writeln!(f, "    {line}")?;
+                    }
+                }
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::{self, IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::MaybeStdinVec;
+use bugbite::output::{COLUMNS, Render, verbose};
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::RequestSend;
+use clap::Args;
+
+use crate::utils::confirm;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Revert options")]
+struct Options {
+    /// revert changes made at this time or later
+    #[arg(short, long, value_name = "TIME", required_unless_present = "by")]
+    since: Option<TimeDeltaOrStatic>,
+
+    /// revert changes made by user
+    #[arg(short, long, value_name = "USER")]
+    by: Option<String>,
+
+    /// skip confirmation
+    #[arg(short, long)]
+    yes: bool,
+
+    /// output changes without applying them
+    #[arg(short = 'n', long, conflicts_with = "yes")]
+    dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// bug IDs or aliases
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.revert(ids);
+        if let Some(value) = self.options.since {
+            request.since(value);
+        }
+        if let Some(value) = self.options.by {
+            request.by(value);
+        }
+        let reverts: Vec<_> = request
+            .send()
+            .await?
+            .into_iter()
+            .filter(|x| !x.changes.is_empty() || !x.skipped.is_empty())
+            .collect();
+
+        // preview changes
+        let width = COLUMNS.min(90);
+        for revert in &reverts {
+            revert.render(f, width)?;
+        }
+
+        if self.options.dry_run || reverts.iter().all(|x| x.changes.is_empty()) {
+            return Ok(ExitCode::SUCCESS);
+        } else if !self.options.yes {
+            if !io::stdin().is_terminal() {
+                anyhow::bail!("confirmation required, use --yes to revert non-interactively");
+            } else if !confirm("Revert changes?", false)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+
+        for revert in reverts.iter().filter(|x| !x.changes.is_empty()) {
+            for change in revert.request.send().await? {
+                verbose!(f, "{change}")?;
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
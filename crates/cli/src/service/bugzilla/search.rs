@@ -3,13 +3,17 @@ use std::process::ExitCode;
 
 use bugbite::args::{Csv, ExistsOrValues, MaybeStdinVec};
 use bugbite::objects::RangeOrValue;
+use bugbite::objects::bugzilla::Hours;
 use bugbite::output::render_search;
 use bugbite::query::Order;
+use bugbite::service::bugzilla::search::expression::Expression;
 use bugbite::service::bugzilla::search::*;
 use bugbite::service::bugzilla::{Bugzilla, FilterField};
 use bugbite::time::TimeDeltaOrStatic;
-use bugbite::traits::{Merge, RequestTemplate};
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
 use clap::Args;
+use tracing::warn;
+use url::Url;
 
 use crate::service::TemplateOptions;
 use crate::utils::launch_browser;
@@ -237,6 +241,18 @@ struct RangeOptions {
     /// restrict by vote count
     #[arg(long)]
     votes: Option<RangeOrValue<u64>>,
+
+    /// restrict by estimated hours
+    #[arg(long, value_name = "HOURS")]
+    estimated_time: Option<RangeOrValue<Hours>>,
+
+    /// restrict by remaining hours
+    #[arg(long, value_name = "HOURS")]
+    remaining_time: Option<RangeOrValue<Hours>>,
+
+    /// restrict by hours worked
+    #[arg(long, value_name = "HOURS")]
+    actual_time: Option<RangeOrValue<Hours>>,
 }
 
 #[derive(Args, Debug)]
@@ -285,6 +301,10 @@ struct QueryOptions {
     /// search using quicksearch syntax
     #[arg(short = 'S', long, value_name = "QUERY")]
     quicksearch: Option<String>,
+
+    /// search using boolean expressions
+    #[arg(short = 'Q', long, value_name = "EXPR")]
+    query: Option<Vec<Expression>>,
 }
 
 #[derive(Args, Debug)]
@@ -301,6 +321,10 @@ struct TimeOptions {
     /// restrict by closed time
     #[arg(long, value_name = "TIME")]
     closed: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    /// restrict by deadline
+    #[arg(long, value_name = "TIME")]
+    deadline: Option<RangeOrValue<TimeDeltaOrStatic>>,
 }
 
 #[derive(Args, Debug)]
@@ -343,6 +367,10 @@ struct UserOptions {
     /// user who reported
     #[arg(short = 'R', long, value_name = "USER[,...]")]
     reporter: Option<Vec<Csv<Match>>>,
+
+    /// user flag was requested from
+    #[arg(long, value_name = "USER[,...]")]
+    requestee: Option<Vec<Csv<Match>>>,
 }
 
 #[derive(Args, Debug)]
@@ -411,6 +439,7 @@ impl From<Params> for Parameters {
             order: value.query.order.map(|x| x.into_iter().collect()),
             paged: value.query.paged,
             quicksearch: value.query.quicksearch,
+            query: value.query.query,
 
             alias: value.attr.alias,
             attachments: value.attr.attachments,
@@ -473,10 +502,14 @@ impl From<Params> for Parameters {
 
             comments: value.range.comments,
             votes: value.range.votes,
+            estimated_time: value.range.estimated_time,
+            remaining_time: value.range.remaining_time,
+            actual_time: value.range.actual_time,
 
             created: value.time.created,
             updated: value.time.updated,
             closed: value.time.closed,
+            deadline: value.time.deadline,
 
             assignee: value
                 .user
@@ -500,6 +533,10 @@ impl From<Params> for Parameters {
                 .user
                 .reporter
                 .map(|x| x.into_iter().map(|x| x.into_inner()).collect()),
+            requestee: value
+                .user
+                .requestee
+                .map(|x| x.into_iter().map(|x| x.into_inner()).collect()),
 
             comment: value
                 .comment
@@ -532,9 +569,17 @@ pub(super) struct Options {
     #[arg(short, long)]
     browser: bool,
 
+    /// import parameters from a search URL
+    #[arg(long, value_name = "URL")]
+    from_url: Option<Url>,
+
     /// output in JSON format
     #[arg(long)]
     json: bool,
+
+    /// output time tracking summary by assignee
+    #[arg(long, conflicts_with = "browser")]
+    timesheet: bool,
 }
 
 #[derive(Args, Debug)]
@@ -563,7 +608,16 @@ impl Command {
             }
         }
 
-        // command line parameters override template
+        // URL parameters override template
+        if let Some(url) = &self.options.from_url {
+            let (params, warnings) = Parameters::from_url(url)?;
+            for warning in warnings {
+                warn!("{warning}");
+            }
+            request.params.merge(params);
+        }
+
+        // command line parameters override template and URL
         let fields = self.params.query.fields.clone();
         request.params.merge(self.params.into());
 
@@ -575,6 +629,13 @@ impl Command {
         if self.options.browser {
             let url = request.search_url()?;
             launch_browser([url])?;
+        } else if !self.template.dry_run && self.options.timesheet {
+            let timesheet = service.timesheet(request).send().await?;
+            if self.options.json {
+                writeln!(f, "{}", serde_json::to_string(&timesheet)?)?;
+            } else {
+                writeln!(f, "{timesheet}")?;
+            }
         } else if !self.template.dry_run {
             let items = request.stream();
             render_search(f, items, &fields, self.options.json).await?;
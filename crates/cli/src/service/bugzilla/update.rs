@@ -1,19 +1,23 @@
 use std::hash::Hash;
-use std::io::{IsTerminal, Write};
+use std::io::{self, IsTerminal, Write};
+use std::pin::pin;
 use std::process::ExitCode;
 use std::str::FromStr;
 use std::{fmt, fs};
 
 use anyhow::Context;
 use bugbite::args::{MaybeStdin, MaybeStdinVec};
-use bugbite::objects::bugzilla::Flag;
-use bugbite::output::verbose;
+use bugbite::objects::bugzilla::{Flag, Hours};
+use bugbite::output::{COLUMNS, Render, verbose};
 use bugbite::service::bugzilla::Bugzilla;
 use bugbite::service::bugzilla::update::*;
+use bugbite::time::TimeDeltaOrStatic;
 use bugbite::traits::{Merge, RequestSend, RequestTemplate};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use camino_tempfile::NamedUtf8TempFile;
 use clap::{Args, ValueHint};
+use clap_complete::engine::ArgValueCandidates;
+use futures_util::StreamExt;
 use itertools::Itertools;
 
 use crate::service::TemplateOptions;
@@ -119,13 +123,26 @@ struct Params {
     comment_privacy: Option<CommentPrivacy<usize>>,
 
     /// update component
-    #[arg(short = 'C', long)]
+    #[arg(
+        short = 'C',
+        long,
+        add = ArgValueCandidates::new(super::product::complete_components),
+    )]
     component: Option<String>,
 
     /// update custom field
     #[arg(long = "cf", num_args = 2, value_names = ["NAME", "VALUE"])]
     custom_fields: Option<Vec<String>>,
 
+    /// update deadline
+    #[arg(
+        long,
+        value_name = "DATE",
+        num_args = 0..=1,
+        default_missing_value = "",
+    )]
+    deadline: Option<String>,
+
     /// add/remove/set dependencies
     #[arg(
         short,
@@ -140,6 +157,10 @@ struct Params {
     #[arg(short = 'D', long, value_name = "ID", conflicts_with_all = ["status", "resolution"])]
     duplicate_of: Option<u64>,
 
+    /// update estimated hours
+    #[arg(long, value_name = "HOURS")]
+    estimated_time: Option<Hours>,
+
     /// add/remove flags
     #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
     flags: Option<Vec<Flag>>,
@@ -171,7 +192,11 @@ struct Params {
     priority: Option<String>,
 
     /// update product
-    #[arg(short, long)]
+    #[arg(
+        short,
+        long,
+        add = ArgValueCandidates::new(super::product::complete_products),
+    )]
     product: Option<String>,
 
     /// update QA contact
@@ -183,6 +208,10 @@ struct Params {
     )]
     qa: Option<String>,
 
+    /// update remaining hours
+    #[arg(long, value_name = "HOURS")]
+    remaining_time: Option<Hours>,
+
     /// update resolution
     #[arg(short, long)]
     resolution: Option<String>,
@@ -218,6 +247,10 @@ struct Params {
     /// update whiteboard
     #[arg(short, long)]
     whiteboard: Option<String>,
+
+    /// add hours worked
+    #[arg(long, value_name = "HOURS")]
+    work_time: Option<Hours>,
 }
 
 impl From<Params> for Parameters {
@@ -234,8 +267,10 @@ impl From<Params> for Parameters {
                 .comment_privacy
                 .and_then(|x| x.range_or_set.map(|value| (value, x.is_private))),
             component: value.component,
+            deadline: value.deadline,
             depends: value.depends,
             duplicate_of: value.duplicate_of,
+            estimated_time: value.estimated_time,
             flags: value.flags,
             groups: value.groups,
             keywords: value.keywords,
@@ -244,6 +279,7 @@ impl From<Params> for Parameters {
             priority: value.priority,
             product: value.product,
             qa: value.qa,
+            remaining_time: value.remaining_time,
             resolution: value.resolution,
             see_also: value.see_also,
             status: value.status,
@@ -253,6 +289,7 @@ impl From<Params> for Parameters {
             url: value.url,
             version: value.version,
             whiteboard: value.whiteboard,
+            work_time: value.work_time,
 
             custom_fields: value
                 .custom_fields
@@ -274,6 +311,36 @@ pub(super) struct Options {
         conflicts_with_all = ["comment", "comment_from"],
     )]
     reply: Option<Vec<usize>>,
+
+    /// validate product-related values
+    #[arg(long)]
+    validate: bool,
+
+    /// check for conflicting changes
+    #[arg(long)]
+    check_conflicts: bool,
+
+    /// last known change time
+    #[arg(long, value_name = "TIME", requires = "check_conflicts")]
+    last_change: Option<TimeDeltaOrStatic>,
+
+    /// read per-bug changes from file
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["reply", "check_conflicts", "ids"],
+    )]
+    changes_from: Option<Utf8PathBuf>,
+
+    /// save failed changes to file
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = ValueHint::FilePath,
+        requires = "changes_from",
+    )]
+    failures: Option<Utf8PathBuf>,
 }
 
 #[derive(Args, Debug)]
@@ -290,7 +357,10 @@ pub(super) struct Command {
     // TODO: rework stdin support once clap supports custom containers
     // See: https://github.com/clap-rs/clap/issues/3114
     /// bug IDs or aliases
-    #[clap(help_heading = "Arguments", required_unless_present = "dry_run")]
+    #[clap(
+        help_heading = "Arguments",
+        required_unless_present_any = ["dry_run", "changes_from"],
+    )]
     ids: Vec<MaybeStdinVec<String>>,
 }
 
@@ -349,6 +419,59 @@ fn edit_comment(data: &str) -> anyhow::Result<String> {
     }
 }
 
+/// Apply per-bug changes, reporting progress and saving failures for later resumption.
+pub(super) async fn update_bulk<W>(
+    request: &bulk::Request,
+    failures: Option<&Utf8Path>,
+    f: &mut W,
+) -> anyhow::Result<ExitCode>
+where
+    W: IsTerminal + Write,
+{
+    let total = request.entries.len();
+    let mut stderr = io::stderr().lock();
+    let mut failed = vec![];
+    let mut outcomes = pin!(request.stream());
+    let mut count = 0;
+
+    while let Some(outcome) = outcomes.next().await {
+        count += 1;
+        let id = &outcome.entry.id;
+        match outcome.result {
+            Ok(changes) => {
+                writeln!(stderr, "[{count}/{total}] {id}: updated")?;
+                for change in changes {
+                    verbose!(f, "{change}")?;
+                }
+            }
+            Err(e) => {
+                writeln!(stderr, "[{count}/{total}] {id}: failed: {e}")?;
+                failed.push(outcome.entry);
+            }
+        }
+    }
+
+    let updated = total - failed.len();
+    writeln!(stderr, "updated {updated} of {total} bugs")?;
+    if failed.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    // restore the original entry order for the failure report
+    let failed: Vec<_> = request
+        .entries
+        .iter()
+        .filter(|x| failed.contains(x))
+        .cloned()
+        .collect();
+    if let Some(path) = failures {
+        bulk::save(&failed, path)?;
+        writeln!(stderr, "saved failed changes: {path}")?;
+    }
+
+    Ok(ExitCode::FAILURE)
+}
+
 impl Command {
     pub(super) async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
     where
@@ -372,6 +495,16 @@ impl Command {
             request.save_template(name)?;
         }
 
+        // record bug change times before interactive editing
+        if self.options.check_conflicts {
+            if let Some(value) = &self.options.last_change {
+                request.last_change_time(value.datetime());
+            } else if !self.template.dry_run {
+                let bugs = service.get(&request.ids).send().await?;
+                request.last_change_bugs(&bugs);
+            }
+        }
+
         // interactively create reply or comment
         if let Some(mut values) = self.options.reply {
             if request.ids.len() != 1 {
@@ -386,8 +519,35 @@ impl Command {
             request.params.comment = Some(comment);
         }
 
+        if let Some(path) = &self.options.changes_from {
+            let entries = bulk::load(path)?;
+            let mut bulk_request = service.update_bulk(entries);
+            bulk_request.params = request.params;
+            bulk_request.validate(self.options.validate);
+            if self.template.dry_run {
+                let changes = bulk_request.changes();
+                write!(f, "{}", bulk::dump(&changes, bulk::Format::Toml)?)?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            return update_bulk(&bulk_request, self.options.failures.as_deref(), f).await;
+        }
+
         if !self.template.dry_run {
-            let changes = request.send().await?;
+            if self.options.check_conflicts {
+                let conflicts = request.conflicts().await?;
+                if !conflicts.is_empty() {
+                    let mut stderr = io::stderr().lock();
+                    let width = COLUMNS.min(90);
+                    for conflict in &conflicts {
+                        conflict.render(&mut stderr, width)?;
+                    }
+                    if !io::stdin().is_terminal() || !confirm("Update anyway?", false)? {
+                        anyhow::bail!("conflicting changes: {}", conflicts.iter().join("; "));
+                    }
+                }
+            }
+
+            let changes = request.validate(self.options.validate).send().await?;
             for change in changes {
                 verbose!(f, "{change}")?;
             }
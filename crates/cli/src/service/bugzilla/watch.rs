@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+use std::io::{IsTerminal, Write};
+use std::process::{Command as Process, ExitCode};
+use std::time::Duration;
+
+use anyhow::Context;
+use bugbite::service::bugzilla::Bugzilla;
+use bugbite::service::bugzilla::watch::Event;
+use bugbite::time::TimeDelta;
+use bugbite::traits::RequestTemplate;
+use clap::Args;
+use tracing::warn;
+
+/// Parse a polling interval using relative time units.
+///
+/// Bare `m` units are treated as minutes since calendar units don't apply to polling.
+fn interval(s: &str) -> Result<Duration, String> {
+    let value = match s.strip_suffix('m') {
+        Some(x) if !x.is_empty() && x.chars().all(|c| c.is_ascii_digit()) => format!("{x}min"),
+        _ => s.to_string(),
+    };
+    let delta: TimeDelta = value.parse().map_err(|e: bugbite::Error| e.to_string())?;
+    delta
+        .duration()
+        .and_then(|x| x.to_std().ok())
+        .filter(|x| !x.is_zero())
+        .ok_or_else(|| format!("invalid interval: {s}"))
+}
+
+/// Run a hook command for an event.
+fn run_hook(cmd: &str, event: &Event) -> anyhow::Result<()> {
+    let mut args = shlex::split(cmd)
+        .unwrap_or_default()
+        .into_iter()
+        .collect::<VecDeque<_>>();
+    let Some(program) = args.pop_front() else {
+        anyhow::bail!("invalid hook command: {cmd:?}");
+    };
+
+    let status = Process::new(&program)
+        .args(&args)
+        .arg(event.to_string())
+        .env("BUGBITE_EVENT", event.kind())
+        .env("BUGBITE_ID", event.id().to_string())
+        .status()
+        .with_context(|| format!("failed running hook via {program}"))?;
+    if !status.success() {
+        warn!("hook failed: {event}");
+    }
+
+    Ok(())
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Watch options")]
+struct Options {
+    /// search template to watch
+    #[arg(long, value_name = "NAME")]
+    from: String,
+
+    /// poll repeatedly using an interval
+    #[arg(short, long, value_name = "DURATION", value_parser = interval)]
+    interval: Option<Duration>,
+
+    /// state name or path
+    #[arg(long, value_name = "NAME")]
+    state: Option<String>,
+
+    /// run command for each event
+    #[arg(long, value_name = "COMMAND")]
+    exec: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Bugzilla, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut search = service.search();
+        search.load_template(&self.options.from)?;
+
+        let state = self.options.state.as_deref().unwrap_or(&self.options.from);
+        let mut request = service.watch(search);
+        request.load_state(state)?;
+
+        loop {
+            for event in request.poll().await? {
+                writeln!(f, "{event}")?;
+                if let Some(cmd) = &self.options.exec {
+                    run_hook(cmd, &event)?;
+                }
+            }
+            f.flush()?;
+            request.save_state(state)?;
+
+            let Some(value) = self.options.interval else {
+                break;
+            };
+            tokio::time::sleep(value).await;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
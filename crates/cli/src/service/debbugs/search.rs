@@ -0,0 +1,155 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::Csv;
+use bugbite::output::render_search;
+use bugbite::service::debbugs::search::*;
+use bugbite::service::debbugs::{BugField, Debbugs};
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,subject")]
+    fields: Csv<BugField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict by binary package
+    #[arg(short, long, value_name = "PKG[,...]", value_delimiter = ',')]
+    package: Option<Vec<String>>,
+
+    /// restrict by source package
+    #[arg(long, value_name = "PKG[,...]", value_delimiter = ',')]
+    source: Option<Vec<String>>,
+
+    /// restrict by maintainer
+    #[arg(short, long, value_name = "USER[,...]", value_delimiter = ',')]
+    maintainer: Option<Vec<String>>,
+
+    /// restrict by reporter
+    #[arg(short = 'R', long, value_name = "USER[,...]", value_delimiter = ',')]
+    reporter: Option<Vec<String>>,
+
+    /// restrict by severity
+    #[arg(short = 'S', long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    severity: Option<Vec<String>>,
+
+    /// restrict by status
+    #[arg(
+        short,
+        long,
+        value_parser = ["open", "forwarded", "done"],
+        hide_possible_values = true,
+    )]
+    status: Option<String>,
+
+    /// restrict by tags
+    #[arg(short, long, value_name = "TAG[,...]", value_delimiter = ',')]
+    tags: Option<Vec<String>>,
+
+    /// search archived bugs
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+    )]
+    archived: Option<bool>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            package: value.attr.package,
+            source: value.attr.source,
+            maintainer: value.attr.maintainer,
+            reporter: value.attr.reporter,
+            severity: value.attr.severity,
+            tags: value.attr.tags,
+            status: value.attr.status,
+            archived: value.attr.archived,
+            limit: value.query.limit,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Debbugs, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,53 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::output::verbose;
+use bugbite::service::gitea::Gitea;
+use bugbite::traits::RequestSend;
+use bugbite::utils::is_terminal;
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Comment options")]
+struct Options {
+    /// comment text
+    #[arg(short, long, required = true)]
+    text: MaybeStdin<String>,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// issue IDs
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<u64>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Gitea, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids: Vec<_> = self.ids.into_iter().flatten().collect();
+        let comments = service
+            .comment(ids.iter().copied())
+            .text(self.options.text.into_inner())
+            .send()
+            .await?;
+
+        for (id, comment) in ids.iter().zip(comments) {
+            if is_terminal!(f) {
+                verbose!(f, "Created comment {comment} on issue {id}")?;
+            } else {
+                writeln!(f, "{comment}")?;
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
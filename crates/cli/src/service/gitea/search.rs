@@ -0,0 +1,174 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{Csv, MaybeStdinVec};
+use bugbite::objects::RangeOrValue;
+use bugbite::output::render_search;
+use bugbite::service::gitea::search::*;
+use bugbite::service::gitea::{Gitea, IssueField};
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,title")]
+    fields: Csv<IssueField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    /// result page
+    #[arg(long)]
+    page: Option<usize>,
+
+    /// enable paging support
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+    )]
+    paged: Option<bool>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict by assignee
+    #[arg(short, long, value_name = "USER")]
+    assignee: Option<String>,
+
+    /// restrict by author
+    #[arg(short = 'A', long, value_name = "USER")]
+    author: Option<String>,
+
+    /// restrict by labels
+    #[arg(short = 'L', long, value_name = "LABEL[,...]", value_delimiter = ',')]
+    labels: Option<Vec<String>>,
+
+    /// restrict by milestones
+    #[arg(
+        short = 'M',
+        long,
+        value_name = "MILESTONE[,...]",
+        value_delimiter = ','
+    )]
+    milestones: Option<Vec<String>>,
+
+    /// restrict by state
+    #[arg(
+        short,
+        long,
+        value_parser = ["open", "closed", "all"],
+        hide_possible_values = true,
+    )]
+    state: Option<String>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Time options")]
+struct TimeOptions {
+    /// restrict by update time
+    #[arg(short, long, value_name = "TIME")]
+    updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+
+    #[clap(flatten)]
+    time: TimeOptions,
+
+    /// restrict by content
+    #[clap(value_name = "TERM", help_heading = "Arguments")]
+    title: Option<Vec<MaybeStdinVec<String>>>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignee: value.attr.assignee,
+            author: value.attr.author,
+            labels: value.attr.labels,
+            milestones: value.attr.milestones,
+            updated: value.time.updated,
+            state: value.attr.state,
+            limit: value.query.limit,
+            page: value.query.page,
+            paged: value.query.paged,
+            title: value.title.map(|x| x.into_iter().flatten().collect()),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Gitea, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,96 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::output::verbose;
+use bugbite::service::gitlab::Gitlab;
+use bugbite::service::gitlab::create::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use bugbite::utils::is_terminal;
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// set assignees
+    #[arg(short, long, value_name = "USER_ID[,...]", value_delimiter = ',')]
+    assignees: Option<Vec<u64>>,
+
+    /// mark issue as confidential
+    #[arg(long, num_args = 0, default_missing_value = "true")]
+    confidential: Option<bool>,
+
+    /// set description
+    #[arg(short = 'D', long)]
+    description: Option<String>,
+
+    /// set labels
+    #[arg(short = 'L', long, value_name = "LABEL[,...]", value_delimiter = ',')]
+    labels: Option<Vec<String>>,
+
+    /// set milestone
+    #[arg(short = 'M', long, value_name = "MILESTONE_ID")]
+    milestone: Option<u64>,
+
+    /// set title
+    #[arg(short = 'T', long)]
+    title: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignees: value.assignees,
+            confidential: value.confidential,
+            description: value.description,
+            labels: value.labels,
+            milestone: value.milestone,
+            title: value.title,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Gitlab, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.create();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            let id = request.send().await?;
+            if is_terminal!(f) {
+                verbose!(f, "Created issue {id}")?;
+            } else {
+                writeln!(f, "{id}")?;
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
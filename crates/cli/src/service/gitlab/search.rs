@@ -0,0 +1,203 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{Csv, ExistsOrValues, MaybeStdinVec};
+use bugbite::objects::RangeOrValue;
+use bugbite::output::render_search;
+use bugbite::query::Order;
+use bugbite::service::gitlab::search::*;
+use bugbite::service::gitlab::{Gitlab, IssueField};
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,title")]
+    fields: Csv<IssueField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    /// result page
+    #[arg(long)]
+    page: Option<usize>,
+
+    /// order query results
+    #[arg(short, long, value_name = "FIELD")]
+    order: Option<Order<OrderField>>,
+
+    /// enable paging support
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+    )]
+    paged: Option<bool>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict by assignee
+    #[arg(
+        short,
+        long,
+        num_args = 0..=1,
+        value_name = "USER[,...]",
+        default_missing_value = "true",
+    )]
+    assignee: Option<ExistsOrValues<String>>,
+
+    /// restrict by author
+    #[arg(short = 'A', long, value_name = "USER")]
+    author: Option<String>,
+
+    /// restrict by ID
+    #[arg(long, value_name = "ID[,...]", value_delimiter = ',')]
+    id: Option<Vec<MaybeStdinVec<u64>>>,
+
+    /// restrict by labels
+    #[arg(
+        short = 'L',
+        long,
+        num_args = 0..=1,
+        value_name = "LABEL[,...]",
+        default_missing_value = "true",
+    )]
+    labels: Option<ExistsOrValues<String>>,
+
+    /// restrict by milestone
+    #[arg(
+        short = 'M',
+        long,
+        num_args = 0..=1,
+        value_name = "MILESTONE",
+        default_missing_value = "true",
+    )]
+    milestone: Option<ExistsOrValues<String>>,
+
+    /// restrict by state
+    #[arg(
+        short,
+        long,
+        value_parser = ["opened", "closed", "all"],
+        hide_possible_values = true,
+    )]
+    state: Option<String>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Time options")]
+struct TimeOptions {
+    /// restrict by creation time
+    #[arg(short, long, value_name = "TIME")]
+    created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    /// restrict by update time
+    #[arg(short, long, value_name = "TIME")]
+    updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+
+    #[clap(flatten)]
+    time: TimeOptions,
+
+    /// restrict by title content
+    #[clap(value_name = "TERM", help_heading = "Arguments")]
+    title: Option<Vec<MaybeStdinVec<String>>>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignee: value.attr.assignee,
+            author: value.attr.author,
+            ids: value.attr.id.map(|x| x.into_iter().flatten().collect()),
+            labels: value.attr.labels,
+            milestone: value.attr.milestone,
+            created: value.time.created,
+            updated: value.time.updated,
+            state: value.attr.state,
+            limit: value.query.limit,
+            page: value.query.page,
+            order: value.query.order,
+            paged: value.query.paged,
+            title: value.title.map(|x| x.into_iter().flatten().collect()),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Gitlab, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::service::bugzilla::update::SetChange;
+use bugbite::service::gitlab::Gitlab;
+use bugbite::service::gitlab::update::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// set assignees
+    #[arg(short, long, value_name = "USER_ID[,...]", value_delimiter = ',')]
+    assignees: Option<Vec<u64>>,
+
+    /// add comment
+    #[arg(short, long)]
+    comment: Option<MaybeStdin<String>>,
+
+    /// update description
+    #[arg(short = 'D', long)]
+    description: Option<String>,
+
+    /// add/remove/set labels
+    #[arg(short = 'L', long, value_name = "LABEL[,...]", value_delimiter = ',')]
+    labels: Option<Vec<SetChange<String>>>,
+
+    /// update milestone
+    #[arg(short = 'M', long, value_name = "MILESTONE_ID")]
+    milestone: Option<u64>,
+
+    /// close or reopen
+    #[arg(short, long, value_name = "EVENT")]
+    state: Option<StateEvent>,
+
+    /// update title
+    #[arg(short = 'T', long)]
+    title: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignees: value.assignees,
+            comment: value.comment.map(|x| x.into_inner()),
+            description: value.description,
+            labels: value.labels,
+            milestone: value.milestone,
+            state: value.state,
+            title: value.title,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// issue IDs
+    #[clap(help_heading = "Arguments", required_unless_present = "dry_run")]
+    ids: Vec<MaybeStdinVec<u64>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Gitlab, _f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.update(ids);
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            request.send().await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
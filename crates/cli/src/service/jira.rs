@@ -0,0 +1,67 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::config::Config;
+use bugbite::service::jira::Jira;
+use tracing::debug;
+
+mod comment;
+mod create;
+mod get;
+mod search;
+mod transition;
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct Command {
+    #[clap(flatten)]
+    service: super::ServiceOptions,
+
+    #[command(subcommand)]
+    cmd: Subcommand,
+}
+
+impl Command {
+    pub(crate) async fn run<W>(self, config: &Config, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let service = Jira::config_builder(config, self.service.connection.as_deref())?
+            .client(self.service.into())
+            .build()?;
+        debug!("Service: {service}");
+        self.cmd.run(&service, f).await
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Subcommand {
+    /// Comment on issues
+    Comment(Box<comment::Command>),
+    /// Create issue
+    #[command(visible_alias = "c")]
+    Create(Box<create::Command>),
+    /// Get issues
+    #[command(visible_alias = "g")]
+    Get(Box<get::Command>),
+    /// Search issues
+    #[command(visible_alias = "s")]
+    Search(Box<search::Command>),
+    /// Transition issues
+    #[command(visible_alias = "t")]
+    Transition(Box<transition::Command>),
+}
+
+impl Subcommand {
+    async fn run<W>(self, service: &Jira, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        match self {
+            Self::Comment(cmd) => cmd.run(service, f).await,
+            Self::Create(cmd) => cmd.run(service, f).await,
+            Self::Get(cmd) => cmd.run(service, f).await,
+            Self::Search(cmd) => cmd.run(service, f).await,
+            Self::Transition(cmd) => cmd.run(service, f).await,
+        }
+    }
+}
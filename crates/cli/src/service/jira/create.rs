@@ -0,0 +1,106 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::output::verbose;
+use bugbite::service::jira::Jira;
+use bugbite::service::jira::create::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use bugbite::utils::is_terminal;
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// set assignee
+    #[arg(short, long, value_name = "USER")]
+    assignee: Option<String>,
+
+    /// set components
+    #[arg(long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    components: Option<Vec<String>>,
+
+    /// set description
+    #[arg(short = 'D', long)]
+    description: Option<String>,
+
+    /// set labels
+    #[arg(short = 'L', long, value_name = "LABEL[,...]", value_delimiter = ',')]
+    labels: Option<Vec<String>>,
+
+    /// set priority
+    #[arg(long)]
+    priority: Option<String>,
+
+    /// set project
+    #[arg(short, long, value_name = "KEY")]
+    project: Option<String>,
+
+    /// set summary
+    #[arg(short = 'S', long)]
+    summary: Option<String>,
+
+    /// set issue type
+    #[arg(short, long = "type", value_name = "TYPE")]
+    issue_type: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignee: value.assignee,
+            components: value.components,
+            description: value.description,
+            issue_type: value.issue_type,
+            labels: value.labels,
+            priority: value.priority,
+            project: value.project,
+            summary: value.summary,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Jira, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.create();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            let id = request.send().await?;
+            if is_terminal!(f) {
+                verbose!(f, "Created issue {id}")?;
+            } else {
+                writeln!(f, "{id}")?;
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,232 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{Csv, ExistsOrValues, MaybeStdinVec};
+use bugbite::objects::RangeOrValue;
+use bugbite::output::render_search;
+use bugbite::query::Order;
+use bugbite::service::jira::search::*;
+use bugbite::service::jira::{IssueField, Jira};
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,summary")]
+    fields: Csv<IssueField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    /// result starting position
+    #[arg(short = 'O', long)]
+    offset: Option<usize>,
+
+    /// order query results
+    #[arg(short, long, value_name = "FIELD[,...]")]
+    order: Option<Csv<Order<OrderField>>>,
+
+    /// enable paging support
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+    )]
+    paged: Option<bool>,
+
+    /// raw JQL query
+    #[arg(short, long, value_name = "QUERY")]
+    jql: Option<String>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict by assignee
+    #[arg(
+        short,
+        long,
+        num_args = 0..=1,
+        value_name = "USER[,...]",
+        default_missing_value = "true",
+    )]
+    assignee: Option<ExistsOrValues<String>>,
+
+    /// restrict by components
+    #[arg(
+        long,
+        num_args = 0..=1,
+        value_name = "VALUE[,...]",
+        default_missing_value = "true",
+    )]
+    components: Option<ExistsOrValues<String>>,
+
+    /// restrict by ID
+    #[arg(long, value_name = "ID[,...]", value_delimiter = ',')]
+    id: Option<Vec<MaybeStdinVec<String>>>,
+
+    /// restrict by labels
+    #[arg(
+        short = 'L',
+        long,
+        num_args = 0..=1,
+        value_name = "LABEL[,...]",
+        default_missing_value = "true",
+    )]
+    labels: Option<ExistsOrValues<String>>,
+
+    /// restrict by priority
+    #[arg(long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    priority: Option<Vec<String>>,
+
+    /// restrict by project
+    #[arg(short, long, value_name = "KEY[,...]", value_delimiter = ',')]
+    project: Option<Vec<String>>,
+
+    /// restrict by reporter
+    #[arg(short = 'R', long, value_name = "USER[,...]", value_delimiter = ',')]
+    reporter: Option<Vec<String>>,
+
+    /// restrict by resolution
+    #[arg(
+        long,
+        num_args = 0..=1,
+        value_name = "VALUE[,...]",
+        default_missing_value = "true",
+    )]
+    resolution: Option<ExistsOrValues<String>>,
+
+    /// restrict by status
+    #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    status: Option<Vec<String>>,
+
+    /// restrict by issue type
+    #[arg(short, long = "type", value_name = "TYPE[,...]", value_delimiter = ',')]
+    issue_type: Option<Vec<String>>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Time options")]
+struct TimeOptions {
+    /// restrict by creation time
+    #[arg(short, long, value_name = "TIME")]
+    created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    /// restrict by update time
+    #[arg(short, long, value_name = "TIME")]
+    updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    /// restrict by resolved time
+    #[arg(short = 'C', long, value_name = "TIME")]
+    resolved: Option<RangeOrValue<TimeDeltaOrStatic>>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+
+    #[clap(flatten)]
+    time: TimeOptions,
+
+    /// restrict by summary content
+    #[clap(value_name = "TERM", help_heading = "Arguments")]
+    summary: Option<Vec<MaybeStdinVec<String>>>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            assignee: value.attr.assignee,
+            components: value.attr.components,
+            ids: value.attr.id.map(|x| x.into_iter().flatten().collect()),
+            issue_type: value.attr.issue_type,
+            labels: value.attr.labels,
+            priority: value.attr.priority,
+            project: value.attr.project,
+            reporter: value.attr.reporter,
+            resolution: value.attr.resolution,
+            status: value.attr.status,
+            created: value.time.created,
+            updated: value.time.updated,
+            resolved: value.time.resolved,
+            limit: value.query.limit,
+            offset: value.query.offset,
+            order: value.query.order.map(|x| x.into_iter().collect()),
+            paged: value.query.paged,
+            jql: value.query.jql,
+            summary: value.summary.map(|x| x.into_iter().flatten().collect()),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Jira, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
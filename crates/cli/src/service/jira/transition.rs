@@ -0,0 +1,82 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::service::jira::Jira;
+use bugbite::service::jira::transition::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// add comment
+    #[arg(short, long)]
+    comment: Option<MaybeStdin<String>>,
+
+    /// set resolution
+    #[arg(short = 'R', long)]
+    resolution: Option<String>,
+
+    /// transition or target status name
+    #[arg(short, long, value_name = "NAME")]
+    status: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            comment: value.comment.map(|x| x.into_inner()),
+            resolution: value.resolution,
+            status: value.status,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// issue IDs
+    #[clap(help_heading = "Arguments", required_unless_present = "dry_run")]
+    ids: Vec<MaybeStdinVec<String>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Jira, _f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.transition(ids);
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            request.send().await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
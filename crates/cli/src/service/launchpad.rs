@@ -0,0 +1,62 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::config::Config;
+use bugbite::service::launchpad::Launchpad;
+use tracing::debug;
+
+mod comment;
+mod get;
+mod search;
+mod update;
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct Command {
+    #[clap(flatten)]
+    service: super::ServiceOptions,
+
+    #[command(subcommand)]
+    cmd: Subcommand,
+}
+
+impl Command {
+    pub(crate) async fn run<W>(self, config: &Config, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let service = Launchpad::config_builder(config, self.service.connection.as_deref())?
+            .client(self.service.into())
+            .build()?;
+        debug!("Service: {service}");
+        self.cmd.run(&service, f).await
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Subcommand {
+    /// Comment on bugs
+    Comment(Box<comment::Command>),
+    /// Get bugs
+    #[command(visible_alias = "g")]
+    Get(Box<get::Command>),
+    /// Search bugs
+    #[command(visible_alias = "s")]
+    Search(Box<search::Command>),
+    /// Update bugs
+    #[command(visible_alias = "u")]
+    Update(Box<update::Command>),
+}
+
+impl Subcommand {
+    async fn run<W>(self, service: &Launchpad, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        match self {
+            Self::Comment(cmd) => cmd.run(service, f).await,
+            Self::Get(cmd) => cmd.run(service, f).await,
+            Self::Search(cmd) => cmd.run(service, f).await,
+            Self::Update(cmd) => cmd.run(service, f).await,
+        }
+    }
+}
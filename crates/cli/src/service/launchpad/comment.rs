@@ -0,0 +1,43 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::service::launchpad::Launchpad;
+use bugbite::traits::RequestSend;
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Comment options")]
+struct Options {
+    /// comment text
+    #[arg(short, long, required = true)]
+    text: MaybeStdin<String>,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// bug IDs
+    #[clap(required = true, help_heading = "Arguments")]
+    ids: Vec<MaybeStdinVec<u64>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Launchpad, _f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        service
+            .comment(ids)
+            .text(self.options.text.into_inner())
+            .send()
+            .await?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,58 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::config::Config;
+use bugbite::service::mantis::Mantis;
+use tracing::debug;
+
+mod get;
+mod search;
+mod update;
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct Command {
+    #[clap(flatten)]
+    service: super::ServiceOptions,
+
+    #[command(subcommand)]
+    cmd: Subcommand,
+}
+
+impl Command {
+    pub(crate) async fn run<W>(self, config: &Config, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let service = Mantis::config_builder(config, self.service.connection.as_deref())?
+            .client(self.service.into())
+            .build()?;
+        debug!("Service: {service}");
+        self.cmd.run(&service, f).await
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Subcommand {
+    /// Get issues
+    #[command(visible_alias = "g")]
+    Get(Box<get::Command>),
+    /// Search issues
+    #[command(visible_alias = "s")]
+    Search(Box<search::Command>),
+    /// Update issues
+    #[command(visible_alias = "u")]
+    Update(Box<update::Command>),
+}
+
+impl Subcommand {
+    async fn run<W>(self, service: &Mantis, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        match self {
+            Self::Get(cmd) => cmd.run(service, f).await,
+            Self::Search(cmd) => cmd.run(service, f).await,
+            Self::Update(cmd) => cmd.run(service, f).await,
+        }
+    }
+}
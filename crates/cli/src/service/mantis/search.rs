@@ -0,0 +1,144 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::Csv;
+use bugbite::objects::RangeOrValue;
+use bugbite::output::render_search;
+use bugbite::service::mantis::search::*;
+use bugbite::service::mantis::{IssueField, Mantis};
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,summary")]
+    fields: Csv<IssueField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict using saved or standard filter
+    #[arg(short = 'F', long, value_name = "FILTER")]
+    filter: Option<String>,
+
+    /// restrict by handler
+    #[arg(short = 'H', long, value_name = "USER[,...]", value_delimiter = ',')]
+    handler: Option<Vec<String>>,
+
+    /// restrict by project ID
+    #[arg(short, long, value_name = "ID")]
+    project: Option<u64>,
+
+    /// restrict by reporter
+    #[arg(short = 'R', long, value_name = "USER[,...]", value_delimiter = ',')]
+    reporter: Option<Vec<String>>,
+
+    /// restrict by status
+    #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    status: Option<Vec<String>>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Time options")]
+struct TimeOptions {
+    /// restrict by update time
+    #[arg(short, long, value_name = "TIME")]
+    updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+
+    #[clap(flatten)]
+    time: TimeOptions,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            filter: value.attr.filter,
+            project: value.attr.project,
+            handler: value.attr.handler,
+            reporter: value.attr.reporter,
+            status: value.attr.status,
+            updated: value.time.updated,
+            limit: value.query.limit,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Mantis, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
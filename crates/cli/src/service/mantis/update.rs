@@ -0,0 +1,123 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::service::mantis::Mantis;
+use bugbite::service::mantis::update::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// update category
+    #[arg(short = 'C', long)]
+    category: Option<String>,
+
+    /// add note
+    #[arg(short, long)]
+    comment: Option<MaybeStdin<String>>,
+
+    /// update description
+    #[arg(short = 'D', long)]
+    description: Option<String>,
+
+    /// update handler
+    #[arg(short = 'H', long, value_name = "USER")]
+    handler: Option<String>,
+
+    /// update priority
+    #[arg(short, long)]
+    priority: Option<String>,
+
+    /// mark note private
+    #[arg(
+        short = 'P',
+        long,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL",
+    )]
+    private: Option<bool>,
+
+    /// update resolution
+    #[arg(short, long)]
+    resolution: Option<String>,
+
+    /// update severity
+    #[arg(short = 'S', long)]
+    severity: Option<String>,
+
+    /// update status
+    #[arg(short, long)]
+    status: Option<String>,
+
+    /// update summary
+    #[arg(short = 't', long)]
+    summary: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            category: value.category,
+            comment: value.comment.map(|x| x.into_inner()),
+            description: value.description,
+            handler: value.handler,
+            priority: value.priority,
+            private: value.private,
+            resolution: value.resolution,
+            severity: value.severity,
+            status: value.status,
+            summary: value.summary,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// issue IDs
+    #[clap(help_heading = "Arguments", required_unless_present = "dry_run")]
+    ids: Vec<MaybeStdinVec<u64>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Mantis, _f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.update(ids);
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            request.send().await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -7,6 +7,7 @@ use tracing::debug;
 
 mod get;
 mod search;
+mod update;
 
 #[derive(clap::Args, Debug)]
 pub(crate) struct Command {
@@ -38,6 +39,9 @@ enum Subcommand {
     /// Search issues
     #[command(visible_alias = "s")]
     Search(Box<search::Command>),
+    /// Update issues
+    #[command(visible_alias = "u")]
+    Update(Box<update::Command>),
 }
 
 impl Subcommand {
@@ -48,6 +52,7 @@ impl Subcommand {
         match self {
             Self::Get(cmd) => cmd.run(service, f).await,
             Self::Search(cmd) => cmd.run(service, f).await,
+            Self::Update(cmd) => cmd.run(service, f).await,
         }
     }
 }
@@ -16,6 +16,10 @@ struct Options {
     #[arg(short = 'C', long)]
     no_comments: bool,
 
+    /// enable watchers
+    #[arg(short = 'W', long)]
+    watchers: bool,
+
     /// open in browser
     #[arg(short, long)]
     browser: bool,
@@ -47,6 +51,7 @@ impl Command {
             let issues = service
                 .get(ids)
                 .comments(!self.options.no_comments)
+                .watchers(self.options.watchers)
                 .send()
                 .await?;
             render_items(f, &issues)?;
@@ -0,0 +1,179 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{Csv, MaybeStdinVec};
+use bugbite::objects::RangeOrValue;
+use bugbite::output::render_search;
+use bugbite::service::trac::search::*;
+use bugbite::service::trac::{TicketField, Trac};
+use bugbite::time::TimeDeltaOrStatic;
+use bugbite::traits::{Merge, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+use crate::utils::launch_browser;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Query options")]
+struct QueryOptions {
+    /// fields to output
+    #[arg(short, long, value_name = "FIELD[,...]", default_value = "id,summary")]
+    fields: Csv<TicketField>,
+
+    /// limit result count
+    #[arg(short, long)]
+    limit: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct AttributeOptions {
+    /// restrict by component
+    #[arg(short = 'C', long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    component: Option<Vec<String>>,
+
+    /// restrict by keywords
+    #[arg(short = 'K', long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    keywords: Option<Vec<String>>,
+
+    /// restrict by milestone
+    #[arg(short = 'M', long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    milestone: Option<Vec<String>>,
+
+    /// restrict by owner
+    #[arg(short, long, value_name = "USER[,...]", value_delimiter = ',')]
+    owner: Option<Vec<String>>,
+
+    /// restrict by priority
+    #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    priority: Option<Vec<String>>,
+
+    /// restrict by reporter
+    #[arg(short = 'R', long, value_name = "USER[,...]", value_delimiter = ',')]
+    reporter: Option<Vec<String>>,
+
+    /// restrict by resolution
+    #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    resolution: Option<Vec<String>>,
+
+    /// restrict by status
+    #[arg(short, long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    status: Option<Vec<String>>,
+
+    /// restrict by type
+    #[arg(
+        short = 't',
+        long = "type",
+        value_name = "VALUE[,...]",
+        value_delimiter = ','
+    )]
+    kind: Option<Vec<String>>,
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Time options")]
+struct TimeOptions {
+    /// restrict by creation time
+    #[arg(short, long, value_name = "TIME")]
+    created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    /// restrict by update time
+    #[arg(short, long, value_name = "TIME")]
+    updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+}
+
+/// Available search parameters.
+#[derive(Args, Debug)]
+struct Params {
+    #[clap(flatten)]
+    query: QueryOptions,
+
+    #[clap(flatten)]
+    attr: AttributeOptions,
+
+    #[clap(flatten)]
+    time: TimeOptions,
+
+    /// restrict by summary content
+    #[clap(value_name = "TERM", help_heading = "Arguments")]
+    summary: Option<Vec<MaybeStdinVec<String>>>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            component: value.attr.component,
+            keywords: value.attr.keywords,
+            milestone: value.attr.milestone,
+            owner: value.attr.owner,
+            priority: value.attr.priority,
+            reporter: value.attr.reporter,
+            resolution: value.attr.resolution,
+            status: value.attr.status,
+            kind: value.attr.kind,
+            created: value.time.created,
+            updated: value.time.updated,
+            limit: value.query.limit,
+            summary: value.summary.map(|x| x.into_iter().flatten().collect()),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Search options")]
+pub(super) struct Options {
+    /// open in browser
+    #[arg(short, long)]
+    browser: bool,
+
+    /// output in JSON format
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    options: Options,
+
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Trac, f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let mut request = service.search();
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        let fields = self.params.query.fields.clone();
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if self.options.browser {
+            let url = request.search_url()?;
+            launch_browser([url])?;
+        } else if !self.template.dry_run {
+            let items = request.stream();
+            render_search(f, items, &fields, self.options.json).await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -0,0 +1,138 @@
+use std::io::{IsTerminal, Write};
+use std::process::ExitCode;
+
+use bugbite::args::{MaybeStdin, MaybeStdinVec};
+use bugbite::service::bugzilla::update::SetChange;
+use bugbite::service::trac::Trac;
+use bugbite::service::trac::update::*;
+use bugbite::traits::{Merge, RequestSend, RequestTemplate};
+use clap::Args;
+
+use crate::service::TemplateOptions;
+
+#[derive(Args, Debug)]
+#[clap(next_help_heading = "Attribute options")]
+struct Params {
+    /// perform workflow action
+    #[arg(short, long)]
+    action: Option<String>,
+
+    /// add/remove/set CC users
+    #[arg(long, value_name = "USER[,...]", value_delimiter = ',')]
+    cc: Option<Vec<SetChange<String>>>,
+
+    /// add comment
+    #[arg(short, long)]
+    comment: Option<MaybeStdin<String>>,
+
+    /// update component
+    #[arg(short = 'C', long)]
+    component: Option<String>,
+
+    /// update description
+    #[arg(short = 'D', long)]
+    description: Option<String>,
+
+    /// add/remove/set keywords
+    #[arg(short = 'K', long, value_name = "VALUE[,...]", value_delimiter = ',')]
+    keywords: Option<Vec<SetChange<String>>>,
+
+    /// update milestone
+    #[arg(short = 'M', long)]
+    milestone: Option<String>,
+
+    /// update owner
+    #[arg(short, long, value_name = "USER")]
+    owner: Option<String>,
+
+    /// update priority
+    #[arg(short, long)]
+    priority: Option<String>,
+
+    /// update resolution
+    #[arg(short, long)]
+    resolution: Option<String>,
+
+    /// update severity
+    #[arg(short = 'S', long)]
+    severity: Option<String>,
+
+    /// update summary
+    #[arg(short, long)]
+    summary: Option<String>,
+
+    /// update type
+    #[arg(short = 't', long = "type", value_name = "VALUE")]
+    kind: Option<String>,
+
+    /// update version
+    #[arg(short = 'V', long)]
+    version: Option<String>,
+}
+
+impl From<Params> for Parameters {
+    fn from(value: Params) -> Self {
+        Self {
+            action: value.action,
+            cc: value.cc,
+            comment: value.comment.map(|x| x.into_inner()),
+            component: value.component,
+            description: value.description,
+            keywords: value.keywords,
+            milestone: value.milestone,
+            owner: value.owner,
+            priority: value.priority,
+            resolution: value.resolution,
+            severity: value.severity,
+            summary: value.summary,
+            kind: value.kind,
+            version: value.version,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct Command {
+    #[clap(flatten)]
+    template: TemplateOptions,
+
+    #[clap(flatten)]
+    params: Params,
+
+    // TODO: rework stdin support once clap supports custom containers
+    // See: https://github.com/clap-rs/clap/issues/3114
+    /// ticket IDs
+    #[clap(help_heading = "Arguments", required_unless_present = "dry_run")]
+    ids: Vec<MaybeStdinVec<u64>>,
+}
+
+impl Command {
+    pub(super) async fn run<W>(self, service: &Trac, _f: &mut W) -> anyhow::Result<ExitCode>
+    where
+        W: IsTerminal + Write,
+    {
+        let ids = self.ids.into_iter().flatten();
+        let mut request = service.update(ids);
+
+        // read attributes from templates
+        if let Some(names) = &self.template.from {
+            for name in names {
+                request.load_template(name)?;
+            }
+        }
+
+        // command line parameters override template
+        request.params.merge(self.params.into());
+
+        // write attributes to template
+        if let Some(name) = &self.template.to {
+            request.save_template(name)?;
+        }
+
+        if !self.template.dry_run {
+            request.send().await?;
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+}
@@ -15,10 +15,24 @@ pub(crate) enum Subcommand {
     // service subcommands
     /// bugzilla service support
     Bugzilla(bugzilla::Command),
+    /// debbugs service support
+    Debbugs(debbugs::Command),
+    /// gitea service support
+    Gitea(gitea::Command),
     /// github service support
     Github(github::Command),
+    /// gitlab service support
+    Gitlab(gitlab::Command),
+    /// jira service support
+    Jira(jira::Command),
+    /// launchpad service support
+    Launchpad(launchpad::Command),
+    /// mantis service support
+    Mantis(mantis::Command),
     /// redmine service support
     Redmine(redmine::Command),
+    /// trac service support
+    Trac(trac::Command),
 
     // regular subcommands
     /// generate shell completion
@@ -33,8 +47,15 @@ impl Subcommand {
 
         match self {
             Self::Bugzilla(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Debbugs(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Gitea(cmd) => cmd.run(config, &mut stdout).await,
             Self::Github(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Gitlab(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Jira(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Launchpad(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Mantis(cmd) => cmd.run(config, &mut stdout).await,
             Self::Redmine(cmd) => cmd.run(config, &mut stdout).await,
+            Self::Trac(cmd) => cmd.run(config, &mut stdout).await,
             Self::Completion(cmd) => cmd.run(),
             Self::Show(cmd) => cmd.run(config, &mut stdout),
         }
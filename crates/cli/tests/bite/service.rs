@@ -1,4 +1,11 @@
 use super::*;
 
 mod bugzilla;
+mod debbugs;
+mod gitea;
+mod gitlab;
+mod jira;
+mod launchpad;
+mod mantis;
 mod redmine;
+mod trac;
@@ -10,13 +10,22 @@ use super::*;
 mod attachment;
 mod comment;
 mod create;
+mod dupes;
+mod edit;
 mod fields;
+mod flag_types;
 mod get;
+mod graph;
 mod history;
+mod migrate;
+mod product;
+mod requests;
+mod revert;
 mod search;
 mod update;
 mod user;
 mod version;
+mod watch;
 
 static TEST_DATA: LazyLock<Utf8PathBuf> =
     LazyLock::new(|| crate::TEST_DATA_PATH.join("bugbite/bugzilla"));
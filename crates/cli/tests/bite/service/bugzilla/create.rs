@@ -146,6 +146,52 @@ async fn from_bug() {
         .success();
 }
 
+#[tokio::test]
+async fn check_dupes() {
+    let server = start_server_with_auth().await;
+
+    server
+        .respond_match(
+            matchers::method("GET"),
+            200,
+            TEST_DATA.join("dupes/search.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::method("POST"),
+            200,
+            TEST_DATA.join("create/creation.json"),
+        )
+        .await;
+
+    // possible duplicates require confirmation
+    cmd("bite bugzilla create --check-dupes")
+        .args(["--product", "TestProduct"])
+        .args(["--component", "TestComponent"])
+        .args(["--description", "description"])
+        .args(["--summary", "Crash when opening the settings dialog"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("Possible duplicates:"))
+        .stderr(predicate::str::contains(
+            "Error: possible duplicates: 1, 3, 2",
+        ))
+        .failure()
+        .code(1);
+
+    // no similar bugs
+    cmd("bite bugzilla create --check-dupes")
+        .args(["--product", "TestProduct"])
+        .args(["--component", "TestComponent"])
+        .args(["--description", "description"])
+        .args(["--summary", "summary"])
+        .assert()
+        .stdout(predicate::str::diff("123").trim())
+        .stderr("")
+        .success();
+}
+
 #[tokio::test]
 async fn template() {
     let server = start_server_with_auth().await;
@@ -0,0 +1,64 @@
+use wiremock::matchers;
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla dupes")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    cmd("bite bugzilla dupes")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn candidates() {
+    let server = start_server().await;
+
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1"),
+            200,
+            TEST_DATA.join("dupes/bug.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug"),
+            200,
+            TEST_DATA.join("dupes/search.json"),
+        )
+        .await;
+
+    let expected = indoc::indoc! {"
+        3        100% settings dialog opening crash
+        2         60% Settings dialog crash on startup
+    "};
+    cmd("bite bugzilla dupes 1")
+        .assert()
+        .stdout(expected)
+        .stderr("")
+        .success();
+
+    // limit candidates
+    cmd("bite bugzilla dupes 1 --limit 1")
+        .assert()
+        .stdout("3        100% settings dialog opening crash\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,118 @@
+use wiremock::matchers;
+
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["e", "edit"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite bugzilla")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    cmd("bite bugzilla edit")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn edit() {
+    let server = start_server_with_auth().await;
+
+    server
+        .respond_match(
+            matchers::method("GET"),
+            200,
+            TEST_DATA.join("get/single-bug.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::method("PUT"),
+            200,
+            TEST_DATA.join("update/summary.json"),
+        )
+        .await;
+
+    // no changes
+    cmd("bite bugzilla edit 1")
+        .env("EDITOR", "true")
+        .assert()
+        .stdout("No changes made\n")
+        .stderr("")
+        .success();
+
+    // removing all bugs aborts
+    cmd("bite bugzilla edit 1")
+        .env("EDITOR", "sed -i -e '/^[^#]/d'")
+        .assert()
+        .stdout("No changes made\n")
+        .stderr("")
+        .success();
+
+    // editor returned failure
+    cmd("bite bugzilla edit 1")
+        .env("EDITOR", "false")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("Error: failed editing bugs"))
+        .failure()
+        .code(1);
+
+    // invalid changes
+    cmd("bite bugzilla edit 1")
+        .env("EDITOR", "sed -i -e 's/^id = .*/id = \"2\"/'")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("Error: unknown bug: 2"))
+        .failure()
+        .code(1);
+
+    let editor = indoc::indoc! {r#"
+        sed -i
+            -e 's/^status = .*/status = "RESOLVED"/'
+            -e 's/^cc = .*/cc = ["person1", "person3"]/'
+            -e 's/^whiteboard = .*/whiteboard = ""/'
+            -e '/^summary = /d'
+    "#};
+
+    // minimal changes are shown without being applied
+    cmd("bite bugzilla edit 1 --dry-run")
+        .env("EDITOR", editor)
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {r#"
+            [[bugs]]
+            id = "1"
+            cc = ["+person3", "-person2"]
+            status = "RESOLVED"
+            whiteboard = ""
+        "#}))
+        .stderr("")
+        .success();
+
+    // changes are applied
+    cmd("bite bugzilla edit 1 -v")
+        .env("EDITOR", editor)
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {"
+            === Bug #123 ===
+            --- Updated fields ---
+            summary: old summary -> new summary
+        "}))
+        .stderr("[1/1] 1: updated\nupdated 1 of 1 bugs\n")
+        .success();
+}
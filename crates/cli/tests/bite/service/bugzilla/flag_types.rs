@@ -0,0 +1,58 @@
+use wiremock::matchers;
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla flag-types")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn missing_product() {
+    cmd("bite bugzilla flag-types")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure();
+}
+
+#[tokio::test]
+async fn flag_types() {
+    let server = start_server().await;
+    server
+        .respond_match(
+            matchers::path("/rest/flag_types/TestProduct/CLI"),
+            200,
+            TEST_DATA.join("flag_types/component.json"),
+        )
+        .await;
+
+    cmd("bite bugzilla flag-types TestProduct CLI")
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {"
+            Bug flags:
+              needinfo (requestee, multiple): Request more information
+              review (requestable): Code review
+            Attachment flags:
+              approval: Release approval
+        "}))
+        .stderr("")
+        .success();
+
+    // requestable flag names
+    for opt in ["-l", "--list"] {
+        cmd("bite bugzilla flag-types TestProduct CLI")
+            .arg(opt)
+            .assert()
+            .stdout("needinfo\nreview\n")
+            .stderr("")
+            .success();
+    }
+}
@@ -145,6 +145,34 @@ async fn single_bug() {
         .success();
 }
 
+#[tokio::test]
+async fn time_tracking() {
+    let server = start_server().await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1"),
+            200,
+            TEST_DATA.join("get/time-tracking.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1/comment"),
+            200,
+            TEST_DATA.join("comment/time-tracking.json"),
+        )
+        .await;
+
+    let expected = fs::read_to_string(TEST_OUTPUT.join("get/time-tracking")).unwrap();
+
+    // time tracking fields and comment work time
+    cmd("bite bugzilla get -AH 1")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
 #[tokio::test]
 async fn multiple_bugs() {
     let server = start_server().await;
@@ -0,0 +1,152 @@
+use std::fs;
+
+use wiremock::{Mock, ResponseTemplate, matchers};
+
+use super::*;
+
+/// Start a server responding with the requested bugs.
+async fn start_graph_server() -> TestServer {
+    let data = fs::read_to_string(TEST_DATA.join("graph/bugs.json")).unwrap();
+    let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+    let server = start_server().await;
+    let respond = move |req: &wiremock::Request| {
+        let mut ids: Vec<_> = req
+            .url
+            .query_pairs()
+            .filter(|(k, _)| k == "ids")
+            .map(|(_, v)| v.to_string())
+            .collect();
+        ids.push(
+            req.url
+                .path_segments()
+                .unwrap()
+                .next_back()
+                .unwrap()
+                .to_string(),
+        );
+        let bugs: Vec<_> = data["bugs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|x| ids.contains(&x["id"].to_string()))
+            .collect();
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({"bugs": bugs}))
+    };
+    Mock::given(matchers::path_regex(r"^/rest/bug/\d+$"))
+        .respond_with(respond)
+        .mount(server.mock())
+        .await;
+    server
+}
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla graph")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    cmd("bite bugzilla graph")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure();
+}
+
+#[tokio::test]
+async fn tree() {
+    let _server = start_graph_server().await;
+
+    cmd("bite bugzilla graph 1")
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {r#"
+            1 NEW Tracker
+            ├── 2 ASSIGNED Parser rewrite
+            │   └── 4 NEW Lexer support
+            │       └── 5 NEW Token cache
+            │           └── 2 ASSIGNED Parser rewrite (cycle)
+            └── 3 RESOLVED FIXED Old "parser" cleanup
+                └── 4 NEW Lexer support (shown above)
+        "#}))
+        .stderr("")
+        .success();
+
+    // hide resolved bugs
+    cmd("bite bugzilla graph 1 --hide-resolved")
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {"
+            1 NEW Tracker
+            └── 2 ASSIGNED Parser rewrite
+                └── 4 NEW Lexer support
+                    └── 5 NEW Token cache
+                        └── 2 ASSIGNED Parser rewrite (cycle)
+        "}))
+        .stderr("")
+        .success();
+
+    // depth limit and blocker relations
+    for opts in [
+        ["-d", "1", "-r", "blocks"],
+        ["--depth", "1", "--relation", "blocks"],
+    ] {
+        cmd("bite bugzilla graph 4")
+            .args(opts)
+            .assert()
+            .stdout(predicate::str::diff(indoc::indoc! {r#"
+                4 NEW Lexer support
+                ├── 2 ASSIGNED Parser rewrite ...
+                └── 3 RESOLVED FIXED Old "parser" cleanup ...
+            "#}))
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn dot() {
+    let _server = start_graph_server().await;
+
+    cmd("bite bugzilla graph 1 -d 1 -f dot")
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {r#"
+            digraph bugs {
+              1 [label="1 NEW Tracker"];
+              2 [label="2 ASSIGNED Parser rewrite"];
+              3 [label="3 RESOLVED FIXED Old \"parser\" cleanup", style=filled, fillcolor=lightgray];
+              1 -> 2;
+              1 -> 3;
+            }
+        "#}))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn json() {
+    let _server = start_graph_server().await;
+
+    let output = cmd("bite bugzilla graph 1 -d 1 --format json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(data["relation"], "depends");
+    assert_eq!(data["roots"], serde_json::json!([1]));
+    let ids: Vec<_> = data["bugs"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| x["id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(ids, [1, 2, 3]);
+    assert_eq!(data["truncated"], serde_json::json!([2, 3]));
+}
@@ -0,0 +1,114 @@
+use bugbite::test::TestServer;
+use wiremock::matchers;
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla migrate")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing target connection
+    cmd("bite bugzilla migrate 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // missing IDs
+    cmd("bite bugzilla migrate --to-connection gentoo")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn migrate() {
+    let server = start_server_with_auth().await;
+    let target = TestServer::new().await;
+
+    // same connection
+    cmd("bite bugzilla migrate 1")
+        .args(["--to-connection", server.uri()])
+        .assert()
+        .stdout("")
+        .stderr("Error: source and target connections are the same\n")
+        .failure()
+        .code(1);
+
+    server
+        .respond_match(
+            matchers::method("PUT"),
+            200,
+            TEST_DATA.join("update/summary.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1"),
+            200,
+            TEST_DATA.join("get/single-bug.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1/comment"),
+            200,
+            TEST_DATA.join("migrate/comments.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1/attachment"),
+            200,
+            TEST_DATA.join("attachment/get/bug-with-attachments.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/attachment/123"),
+            200,
+            TEST_DATA.join("migrate/attachments.json"),
+        )
+        .await;
+    target
+        .respond_match(
+            matchers::path("/rest/bug"),
+            200,
+            TEST_DATA.join("create/creation.json"),
+        )
+        .await;
+    target
+        .respond_match(
+            matchers::path("/rest/bug/123/attachment"),
+            200,
+            TEST_DATA.join("attachment/create/single.json"),
+        )
+        .await;
+
+    let target_url = target.uri().trim_end_matches('/');
+    cmd("bite bugzilla migrate 1")
+        .args(["--to-connection", target.uri()])
+        .args(["--reference", "see-also"])
+        .arg("--move")
+        .assert()
+        .stdout(format!("1 -> {target_url}/show_bug.cgi?id=123\n"))
+        .stderr("")
+        .success();
+}
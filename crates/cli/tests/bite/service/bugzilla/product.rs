@@ -0,0 +1,132 @@
+use std::fs;
+
+use wiremock::matchers;
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla product")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn products() {
+    let server = start_server().await;
+    server
+        .respond(200, TEST_DATA.join("product/products.json"))
+        .await;
+    let expected = fs::read_to_string(TEST_OUTPUT.join("product/products")).unwrap();
+
+    cmd("bite bugzilla product")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn list() {
+    let server = start_server().await;
+    server
+        .respond_match(
+            matchers::query_param("names", "Client"),
+            200,
+            TEST_DATA.join("product/client.json"),
+        )
+        .await;
+    server
+        .respond(200, TEST_DATA.join("product/products.json"))
+        .await;
+
+    // product names
+    cmd("bite bugzilla product -l")
+        .assert()
+        .stdout("Client\nServer\n")
+        .stderr("")
+        .success();
+
+    // active component names for specified products
+    cmd("bite bugzilla product -l Client")
+        .assert()
+        .stdout("CLI\n")
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn classification() {
+    let server = start_server().await;
+    server
+        .respond_match(
+            matchers::path("/rest/classification/Tools"),
+            200,
+            TEST_DATA.join("classification/single.json"),
+        )
+        .await;
+    server
+        .respond(200, TEST_DATA.join("product/products.json"))
+        .await;
+
+    cmd("bite bugzilla product -l -c Tools")
+        .assert()
+        .stdout("Client\nServer\n")
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    server
+        .respond(200, TEST_DATA.join("product/products.json"))
+        .await;
+
+    cmd("bite bugzilla product nonexistent")
+        .assert()
+        .stdout("")
+        .stderr("Error: nonexistent product: nonexistent\n")
+        .failure();
+}
+
+#[tokio::test]
+async fn completion() {
+    let server = TestServer::new().await;
+    server
+        .respond_match(
+            matchers::query_param("names", "Client"),
+            200,
+            TEST_DATA.join("product/client.json"),
+        )
+        .await;
+    server
+        .respond(200, TEST_DATA.join("product/products.json"))
+        .await;
+
+    // product names for create and update
+    for subcmd in ["create", "update"] {
+        cmd("bite -- bite bugzilla")
+            .args([subcmd, "-p", ""])
+            .env("BUGBITE_CONNECTION", server.uri())
+            .env("COMPLETE", "fish")
+            .assert()
+            .stdout("Client\tCommand line client\nServer\tServer daemon\n")
+            .stderr("")
+            .success();
+    }
+
+    // active component names restricted to the specified product
+    cmd("bite -- bite bugzilla create -p Client -C ''")
+        .env("BUGBITE_CONNECTION", server.uri())
+        .env("COMPLETE", "fish")
+        .assert()
+        .stdout("CLI\tCommand line interface\n")
+        .stderr("")
+        .success();
+}
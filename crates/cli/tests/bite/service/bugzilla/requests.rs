@@ -0,0 +1,118 @@
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla requests")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn no_user() {
+    let server = TestServer::new().await;
+
+    // avoid leaking authentication from other tests
+    cmd("bite bugzilla requests")
+        .env("BUGBITE_CONNECTION", server.uri())
+        .env_remove("BUGBITE_USER")
+        .env_remove("BUGBITE_KEY")
+        .assert()
+        .stdout("")
+        .stderr("Error: no user specified\n")
+        .failure();
+}
+
+async fn start_requests_server() -> TestServer {
+    let server = start_server().await;
+    let requestee = |req: &wiremock::Request| {
+        req.url
+            .query_pairs()
+            .any(|(_, v)| v == "requestees.login_name")
+    };
+    server
+        .respond_match(requestee, 200, TEST_DATA.join("requests/incoming.json"))
+        .await;
+    server
+        .respond(200, TEST_DATA.join("requests/outgoing.json"))
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn requests() {
+    let _server = start_requests_server().await;
+
+    // all requests are stale by default
+    cmd("bite bugzilla requests -u user@bugbite.test")
+        .assert()
+        .stdout(
+            predicate::str::is_match(
+                r"(?x)
+            ^Incoming\ requests:\n
+            \ \ needinfo\ \(2\)\n
+            \ \ !\ 10\s+\d+d\s+dev@bugbite.test\s+Crash\ on\ startup\n
+            \ \ !\ 11\s+\d+d\s+other@bugbite.test\s+Update\ documentation\n
+            \ \ review\ \(1\)\n
+            \ \ !\ 11\s+\d+d\s+dev@bugbite.test\s+Update\ documentation\n
+            Outgoing\ requests:\n
+            \ \ feedback\ \(1\)\n
+            \ \ !\ 12\s+\d+d\s+-\s+Add\ requests\ command\n
+            \ \ review\ \(1\)\n
+            \ \ !\ 12\s+\d+d\s+dev@bugbite.test\s+Add\ requests\ command\n$",
+            )
+            .unwrap(),
+        )
+        .stderr("")
+        .success();
+
+    // custom stale age and flags
+    for opts in [
+        ["-s", "1000y", "-f", "review"],
+        ["--stale", "1000y", "--flags", "review"],
+    ] {
+        cmd("bite bugzilla requests -u user@bugbite.test")
+            .args(opts)
+            .assert()
+            .stdout(
+                predicate::str::is_match(
+                    r"(?x)
+                ^Incoming\ requests:\n
+                \ \ review\ \(1\)\n
+                \ {4}11\s+\d+d\s+dev@bugbite.test\s+Update\ documentation\n
+                Outgoing\ requests:\n
+                \ \ review\ \(1\)\n
+                \ {4}12\s+\d+d\s+dev@bugbite.test\s+Add\ requests\ command\n$",
+                )
+                .unwrap(),
+            )
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn json() {
+    let _server = start_requests_server().await;
+
+    let output = cmd("bite bugzilla requests -u user@bugbite.test --json -f needinfo")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let items: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|x| serde_json::from_str(x).unwrap())
+        .collect();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["bug"], 10);
+    assert_eq!(items[0]["direction"], "incoming");
+    assert_eq!(items[0]["flag"], "needinfo");
+    assert_eq!(items[0]["requestee"], "user@bugbite.test");
+    assert_eq!(items[0]["stale"], true);
+    assert_eq!(items[1]["bug"], 11);
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite bugzilla revert --by user")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // missing restrictions
+    cmd("bite bugzilla revert 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn revert() {
+    let server = start_server_with_auth().await;
+
+    server
+        .respond_match(
+            matchers::path_regex("/history$"),
+            200,
+            TEST_DATA.join("revert/history.json"),
+        )
+        .await;
+    server
+        .respond(200, TEST_DATA.join("update/summary.json"))
+        .await;
+
+    let expected = indoc::indoc! {"
+        === Bug #1 ===
+        --- Reverted fields ---
+        status: RESOLVED -> CONFIRMED
+        resolution: -FIXED
+        cf_stage: beta -> alpha
+        cc: user3@bugbite.test -> user2@bugbite.test
+        blocks: 20 -> 10
+        keywords: +Regression
+        --- Skipped changes ---
+        flagtypes.name: review? -> review+
+        attachments.isobsolete: 0 -> 1
+        assigned_to: -user1@bugbite.test
+    "};
+
+    // preview
+    cmd("bite bugzilla revert 1 --by user1@bugbite.test --dry-run")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+
+    // confirmation required when not running interactively
+    cmd("bite bugzilla revert 1 --by user1@bugbite.test")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr(predicate::str::contains("Error: confirmation required"))
+        .failure()
+        .code(1);
+
+    // apply changes
+    cmd("bite bugzilla revert 1 --by user1@bugbite.test --yes -v")
+        .assert()
+        .stdout(predicate::str::starts_with(expected))
+        .stdout(predicate::str::contains("=== Bug #123 ==="))
+        .stderr("")
+        .success();
+}
@@ -399,3 +399,155 @@ async fn id() {
         .stderr("")
         .success();
 }
+
+#[tokio::test]
+async fn query() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("search/ids.json")).await;
+
+    // invalid
+    cmd("bite bugzilla search -Q 'product=Foo &'")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("invalid query: missing field"))
+        .failure();
+
+    // valid
+    for opt in ["-Q", "--query"] {
+        cmd("bite bugzilla search -f id")
+            .args([opt, "(product=Foo | component~bar) & !keywords=VERIFIED"])
+            .assert()
+            .stdout(predicate::str::diff(indoc::indoc! {"
+                924847
+                924852
+                924854
+                924855
+                924856
+            "}))
+            .stderr("")
+            .success();
+    }
+
+    // stored in templates
+    cmd("bite bugzilla search -n")
+        .args(["-Q", "product=Foo | product=Bar"])
+        .args(["--to", "-"])
+        .assert()
+        .stdout(predicate::str::diff(r#"query = ["product=Foo | product=Bar"]"#).trim())
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn from_url() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("search/ids.json")).await;
+
+    // invalid
+    cmd("bite bugzilla search --from-url https://bugzilla.test/show_bug.cgi?id=1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("unsupported search URL"))
+        .failure();
+
+    // valid
+    cmd("bite bugzilla search -f id")
+        .args([
+            "--from-url",
+            "https://bugzilla.test/buglist.cgi?product=Foo&f1=short_desc&o1=substring&v1=bar",
+        ])
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {"
+            924847
+            924852
+            924854
+            924855
+            924856
+        "}))
+        .stderr("")
+        .success();
+
+    // unmapped parameters trigger warnings and command line options override URL parameters
+    cmd("bite bugzilla search -n -s @all")
+        .args(["--from-url", "https://bugzilla.test/buglist.cgi?product=Foo&bug_status=__open__&f1=short_desc&o1=substring&v1=bar&columnlist=id"])
+        .args(["--to", "-"])
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {r#"
+            product = ["== Foo"]
+            status = ["@all"]
+            query = ["summary~bar"]
+        "#}))
+        .stderr(predicate::str::contains("unsupported parameter: columnlist"))
+        .success();
+}
+
+#[tokio::test]
+async fn time_tracking() {
+    let server = start_server().await;
+    server
+        .respond(200, TEST_DATA.join("search/nonexistent.json"))
+        .await;
+
+    for opt in ["--estimated-time", "--remaining-time", "--actual-time"] {
+        for value in ["1.5", ">=8", "1..2.5", "..=4"] {
+            cmd("bite bugzilla search")
+                .args([opt, value])
+                .assert()
+                .stdout("")
+                .stderr("")
+                .success();
+        }
+
+        // invalid values
+        cmd("bite bugzilla search")
+            .args([opt, "abc"])
+            .assert()
+            .stdout("")
+            .stderr(predicate::str::is_empty().not())
+            .failure();
+    }
+
+    for value in ["2024-04-01", "<2024-05", "2024-04..2024-05"] {
+        cmd("bite bugzilla search --deadline")
+            .arg(value)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn timesheet() {
+    let server = start_server().await;
+    server
+        .respond(200, TEST_DATA.join("timesheet/search.json"))
+        .await;
+
+    cmd("bite bugzilla search --timesheet -s @open")
+        .assert()
+        .stdout(predicate::str::diff(indoc::indoc! {"
+            Assignee                        Bugs  Estimated     Worked  Remaining   Done
+            user1@bugbite.test                 2      12.50       6.00       6.50    48%
+            user2@bugbite.test                 1       4.00       1.50       1.00    60%
+            Total                              3      16.50       7.50       7.50    50%
+        "}))
+        .stderr("")
+        .success();
+
+    // JSON
+    cmd("bite bugzilla search --timesheet --json")
+        .assert()
+        .stdout(predicate::str::contains(
+            r#""total":{"assignee":"Total","bugs":3,"estimated":16.5,"worked":7.5,"remaining":7.5}"#,
+        ))
+        .stderr("")
+        .success();
+
+    // browser usage conflicts
+    cmd("bite bugzilla search --timesheet --browser")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("cannot be used with"))
+        .failure();
+}
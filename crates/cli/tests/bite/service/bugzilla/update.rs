@@ -1,4 +1,4 @@
-use camino_tempfile::NamedUtf8TempFile;
+use camino_tempfile::{NamedUtf8TempFile, tempdir};
 use wiremock::matchers;
 
 use super::*;
@@ -149,6 +149,152 @@ async fn summary() {
     }
 }
 
+#[tokio::test]
+async fn conflicts() {
+    let server = start_server_with_auth().await;
+
+    server
+        .respond_match(
+            matchers::path_regex("/history$"),
+            200,
+            TEST_DATA.join("history/single-bug.json"),
+        )
+        .await;
+    server
+        .respond(200, TEST_DATA.join("update/summary.json"))
+        .await;
+
+    // last change time requires conflict checking
+    cmd("bite bugzilla update 1 --last-change 2024-03-13 --summary summary")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // conflicting changes abort non-interactive updates
+    cmd("bite bugzilla update 1 --check-conflicts --last-change 2024-03-13 --summary summary")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(indoc::indoc! {"
+            === Bug #1 ===
+            Changes made by user1@bugbite.test, 2024-03-13 14:22:39 UTC
+        "}))
+        .stderr(predicate::str::contains(
+            "summary: old summary -> new summary",
+        ))
+        .stderr(predicate::str::contains(
+            "Error: conflicting changes: bug 1: summary",
+        ))
+        .failure()
+        .code(1);
+
+    // changes to unrelated fields don't conflict
+    cmd("bite bugzilla update 1 --check-conflicts --last-change 2024-03-13 --whiteboard test")
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+
+    // changes made before the last change time don't conflict
+    cmd("bite bugzilla update 1 --check-conflicts --last-change 2024-03-16 --summary summary")
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn changes_from() {
+    let server = start_server_with_auth().await;
+    let dir = tempdir().unwrap();
+    let changes = dir.path().join("changes.csv");
+    let failures = dir.path().join("failures.csv");
+
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1"),
+            200,
+            TEST_DATA.join("update/summary.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/2"),
+            404,
+            TEST_DATA.join("errors/nonexistent-bug.json"),
+        )
+        .await;
+
+    // IDs can't be specified with a change file
+    fs::write(&changes, "id,summary\n1,new summary\n").unwrap();
+    cmd("bite bugzilla update 1 --changes-from")
+        .arg(&changes)
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("cannot be used with"))
+        .failure()
+        .code(2);
+
+    // unknown format
+    let path = dir.path().join("changes.txt");
+    fs::write(&path, "").unwrap();
+    cmd("bite bugzilla update --changes-from")
+        .arg(&path)
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("unknown change file format"))
+        .failure()
+        .code(1);
+
+    // dry run outputs per-bug changes
+    cmd("bite bugzilla update --comment test --dry-run --changes-from")
+        .arg(&changes)
+        .assert()
+        .stdout(indoc::indoc! {r#"
+            [[bugs]]
+            id = "1"
+            comment = "test"
+            summary = "new summary"
+        "#})
+        .stderr("")
+        .success();
+
+    // all updates succeed
+    cmd("bite bugzilla update --changes-from")
+        .arg(&changes)
+        .assert()
+        .stdout("")
+        .stderr(indoc::indoc! {"
+            [1/1] 1: updated
+            updated 1 of 1 bugs
+        "})
+        .success();
+
+    // failed updates are reported and saved for resumption
+    fs::write(&changes, "id,summary\n1,new summary\n2,new summary\n").unwrap();
+    cmd("bite bugzilla update --comment test --changes-from")
+        .arg(&changes)
+        .arg("--failures")
+        .arg(&failures)
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("[2/2]"))
+        .stderr(predicate::str::contains(
+            "2: failed: bugzilla: Bug #1 does not exist.",
+        ))
+        .stderr(predicate::str::contains("updated 1 of 2 bugs"))
+        .stderr(predicate::str::contains(format!(
+            "saved failed changes: {failures}"
+        )))
+        .failure()
+        .code(1);
+    let data = fs::read_to_string(&failures).unwrap();
+    assert_eq!(data, "id,summary\n2,new summary\n");
+}
+
 #[tokio::test]
 #[cfg_attr(target_os = "macos", ignore)] // requires GNU sed which isn't installed by default
 async fn reply() {
@@ -459,3 +605,52 @@ async fn comment_privacy() {
             .success();
     }
 }
+
+#[tokio::test]
+async fn time_tracking() {
+    let server = start_server_with_auth().await;
+    server
+        .respond_match(
+            matchers::body_partial_json(serde_json::json!({
+                "comment": {"body": "progress"},
+                "estimated_time": 10.0,
+                "remaining_time": 4.5,
+                "work_time": 1.5,
+            })),
+            200,
+            TEST_DATA.join("update/no-changes.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::body_partial_json(serde_json::json!({"deadline": ""})),
+            200,
+            TEST_DATA.join("update/no-changes.json"),
+        )
+        .await;
+
+    cmd("bite bugzilla update 1 -c progress --estimated-time 10 --remaining-time 4.5 --work-time 1.5")
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+
+    // option used without argument clears the deadline
+    cmd("bite bugzilla update 1 --deadline")
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+
+    // invalid values
+    cmd("bite bugzilla update 1 --deadline tomorrow")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("invalid deadline: tomorrow"))
+        .failure();
+    cmd("bite bugzilla update 1 --work-time abc")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains("invalid hours: abc"))
+        .failure();
+}
@@ -0,0 +1,125 @@
+use std::fs;
+
+use camino_tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite bugzilla watch")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    cmd("bite bugzilla watch")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn invalid_interval() {
+    for value in ["0", "5x", "m", "5", "0s", "0m", "1y"] {
+        cmd("bite bugzilla watch --from template")
+            .args(["--interval", value])
+            .assert()
+            .stdout("")
+            .stderr(predicate::str::contains("invalid value"))
+            .failure()
+            .code(2);
+    }
+}
+
+#[tokio::test]
+async fn watch() {
+    let server = start_server().await;
+    let dir = tempdir().unwrap();
+    let template = dir.path().join("template");
+    fs::write(&template, "status = [\"@open\"]").unwrap();
+    let state = dir.path().join("state.json");
+    let hooks = dir.path().join("hooks");
+
+    // nonexistent template
+    cmd("bite bugzilla watch --from nonexistent")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "failed loading template: nonexistent",
+        ))
+        .failure()
+        .code(1);
+
+    // initial poll records bugs
+    server
+        .respond(200, TEST_DATA.join("watch/initial.json"))
+        .await;
+    cmd("bite bugzilla watch")
+        .args(["--from", template.as_str()])
+        .args(["--state", state.as_str()])
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+    assert!(state.exists());
+
+    // subsequent polls output changes
+    server.reset().await;
+    let known = |req: &wiremock::Request| req.url.query_pairs().any(|(_, v)| v == "bug_id");
+    server
+        .respond_match(known, 200, TEST_DATA.join("watch/removed.json"))
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug"),
+            200,
+            TEST_DATA.join("watch/updated.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1/history"),
+            200,
+            TEST_DATA.join("watch/history.json"),
+        )
+        .await;
+    server
+        .respond_match(
+            matchers::path("/rest/bug/1/comment"),
+            200,
+            TEST_DATA.join("watch/comments.json"),
+        )
+        .await;
+    let hook = format!("sh -c 'echo $BUGBITE_EVENT $BUGBITE_ID >> {hooks}' sh");
+    cmd("bite bugzilla watch")
+        .args(["--from", template.as_str()])
+        .args(["--state", state.as_str()])
+        .args(["--exec", &hook])
+        .current_dir(dir.path())
+        .assert()
+        .stdout(indoc::indoc! {r#"
+            3: new: new bug
+            1: status: "NEW" -> "CONFIRMED" by user1@bugbite.test
+            1: comment #1 by user2@bugbite.test
+            2: status: "NEW" -> "RESOLVED" by user1@bugbite.test
+            2: resolution: "" -> "FIXED" by user1@bugbite.test
+            2: removed: second bug
+        "#})
+        .stderr("")
+        .success();
+    let hooks = fs::read_to_string(&hooks).unwrap();
+    assert_eq!(
+        hooks,
+        "new 3\nchange 1\ncomment 1\nchange 2\nchange 2\nremoved 2\n"
+    );
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite debbugs")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite debbugs get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server
+        .respond_soap("get_status", TEST_DATA.join("get/status.xml"))
+        .await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Subject      : bugbite: crashes on startup
+        Package      : bugbite
+        Source       : bugbite
+        Reporter     : John Smith <john@example.com>
+        Severity     : important
+        Status       : forwarded
+        Forwarded    : https://github.com/radhermit/bugbite/issues/1
+        Created      : 2023-11-14 22:13:20 UTC
+        Updated      : 2023-11-15 22:13:20 UTC
+        ID           : 123456
+        Tags         : patch, upstream
+        Found        : bugbite/0.0.13-1
+        Merged       : 123457
+    "};
+
+    // without comments
+    cmd("bite debbugs get 123456 -C")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    server
+        .respond_soap("get_status", TEST_DATA.join("get/nonexistent.xml"))
+        .await;
+
+    cmd("bite debbugs get 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: debbugs: nonexistent bug: 1").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn browser() {
+    let _server = start_server().await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite debbugs get 1")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
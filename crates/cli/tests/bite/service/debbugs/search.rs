@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite debbugs")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn invalid_status() {
+    cmd("bite debbugs search -p test")
+        .args(["--status", "closed"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn empty_params() {
+    let _server = start_server().await;
+
+    cmd("bite debbugs search")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: no parameters specified").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server
+        .respond_soap("get_bugs", TEST_DATA.join("search/valid"))
+        .await;
+
+    for opt in ["-v", "--verbose"] {
+        cmd("bite debbugs search -p nonexistent")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond_soap("get_bugs", TEST_DATA.join("search/bugs.xml"))
+        .await;
+    server
+        .respond_soap("get_status", TEST_DATA.join("get/status.xml"))
+        .await;
+
+    cmd("bite debbugs search -p bugbite -l 1 -f id")
+        .assert()
+        .stdout("123456\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite gitea comment")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite gitea comment -t text")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // missing text
+    cmd("bite gitea comment 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn auth_required() {
+    let _server = start_server().await;
+
+    cmd("bite gitea comment 1 -t text")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: authentication required").trim())
+        .failure();
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server
+        .respond(201, TEST_DATA.join("comment/valid.json"))
+        .await;
+
+    cmd("bite gitea comment 1 -t text")
+        .env("BUGBITE_TOKEN", "token")
+        .assert()
+        .stdout("1002\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite gitea")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn invalid_state() {
+    cmd("bite gitea search")
+        .args(["--state", "opened"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server.respond(200, TEST_DATA.join("search/valid")).await;
+
+    for opt in ["", "-v", "--verbose"] {
+        cmd("bite gitea search nonexistent")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond(200, TEST_DATA.join("search/issues.json"))
+        .await;
+
+    cmd("bite gitea search -f id")
+        .assert()
+        .stdout("1\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["u", "update"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite gitea")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite gitea update -s closed")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn invalid_labels() {
+    cmd("bite gitea update 1 -L +bug")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn auth_required() {
+    let _server = start_server().await;
+
+    cmd("bite gitea update 1 -s closed")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: authentication required").trim())
+        .failure();
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite gitlab")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite gitlab get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Title        : title
+        Assignees    : susan
+        Author       : john
+        State        : opened
+        Milestone    : v1.0
+        Created      : 2024-02-15 15:56:49 UTC
+        Updated      : 2024-02-15 16:00:26 UTC
+        ID           : 1
+        Labels       : bug, regression
+    "};
+
+    // without comments or history
+    cmd("bite gitlab get 1 -C -H")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn browser() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite gitlab get 1")
+            .arg(opt)
+            .env("BROWSER", "true")
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::LazyLock;
+
+use camino::Utf8PathBuf;
+
+use super::*;
+
+mod get;
+mod search;
+mod transition;
+
+static TEST_DATA: LazyLock<Utf8PathBuf> =
+    LazyLock::new(|| crate::TEST_DATA_PATH.join("bugbite/jira"));
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite jira")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn invalid_service_type() {
+    for opt in ["-c", "--connection"] {
+        cmd("bite jira")
+            .args([opt, "gentoo"])
+            .args(["search", "test"])
+            .assert()
+            .stdout("")
+            .stderr(predicate::str::contains("invalid service type: bugzilla"))
+            .failure();
+    }
+}
+
+#[test]
+fn unknown_connection() {
+    for opt in ["-c", "--connection"] {
+        cmd("bite jira")
+            .args([opt, "unknown"])
+            .args(["search", "test"])
+            .assert()
+            .stdout("")
+            .stderr(predicate::str::contains("unknown connection: unknown"))
+            .failure();
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite jira")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite jira get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Summary      : test issue
+        Reporter     : User 1
+        Project      : TEST
+        Type         : Bug
+        Status       : Open
+        Priority     : Major
+        Created      : 2024-01-02 03:04:05 UTC
+        Updated      : 2024-01-03 03:04:05 UTC
+        ID           : TEST-1
+        Components   : core
+        Labels       : test
+    "};
+
+    // without comments or history
+    cmd("bite jira get TEST-1 -C -H")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    server
+        .respond(404, TEST_DATA.join("errors/nonexistent.json"))
+        .await;
+
+    cmd("bite jira get TEST-1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: jira: Issue Does Not Exist").trim())
+        .failure();
+}
+
+#[tokio::test]
+async fn browser() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite jira get TEST-1")
+            .arg(opt)
+            .env("BROWSER", "true")
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite jira")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server.respond(200, TEST_DATA.join("search/valid")).await;
+
+    for opt in ["", "-v", "--verbose"] {
+        cmd("bite jira search nonexistent")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond(200, TEST_DATA.join("search/issues.json"))
+        .await;
+
+    cmd("bite jira search -f id")
+        .assert()
+        .stdout("TEST-1\nTEST-2\n")
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn jql() {
+    let server = start_server().await;
+
+    server
+        .respond(200, TEST_DATA.join("search/issues.json"))
+        .await;
+
+    cmd("bite jira search -f id")
+        .args(["--jql", "project = TEST"])
+        .assert()
+        .stdout("TEST-1\nTEST-2\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["t", "transition"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite jira")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite jira transition -s resolved")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn auth_required() {
+    let _server = start_server().await;
+
+    cmd("bite jira transition TEST-1 -s resolved")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: authentication required").trim())
+        .failure();
+}
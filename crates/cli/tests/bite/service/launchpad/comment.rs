@@ -0,0 +1,64 @@
+use wiremock::{ResponseTemplate, matchers};
+
+use super::*;
+
+#[test]
+fn help() {
+    for opt in ["-h", "--help"] {
+        cmd("bite launchpad comment")
+            .arg(opt)
+            .assert()
+            .stdout(predicate::str::is_empty().not())
+            .stderr("")
+            .success();
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite launchpad comment -t text")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // missing text
+    cmd("bite launchpad comment 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn auth_required() {
+    let _server = start_server().await;
+
+    cmd("bite launchpad comment 1 -t text")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: authentication required").trim())
+        .failure();
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    let template = ResponseTemplate::new(201);
+    server.respond_custom(matchers::any(), template).await;
+
+    cmd("bite launchpad comment 1 -t text")
+        .env("BUGBITE_TOKEN", "token")
+        .env("BUGBITE_TOKEN_SECRET", "secret")
+        .assert()
+        .stdout("")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,96 @@
+use wiremock::{ResponseTemplate, matchers};
+
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite launchpad")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite launchpad get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server
+        .respond_match(
+            matchers::path_regex("/bug_tasks$"),
+            200,
+            TEST_DATA.join("get/tasks.json"),
+        )
+        .await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Title        : crashes on startup
+        Reporter     : john
+        Information  : Public
+        Heat         : 6
+        Created      : 2024-02-15 15:56:49.123456 UTC
+        Updated      : 2024-02-16 10:00:26.654321 UTC
+        ID           : 1
+        Tags         : bugbite, crash
+        Tasks        :
+                       bugbite: Triaged, High, susan
+                       bugbite (Ubuntu): New, Undecided
+    "};
+
+    // without comments or history
+    cmd("bite launchpad get 1 -C -H")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    let template = ResponseTemplate::new(404);
+    server.respond_custom(matchers::any(), template).await;
+
+    cmd("bite launchpad get 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: launchpad: nonexistent bug: 1").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn browser() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite launchpad get 1")
+            .arg(opt)
+            .env("BROWSER", "true")
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
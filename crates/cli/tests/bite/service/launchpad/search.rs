@@ -0,0 +1,58 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite launchpad")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn invalid_created() {
+    cmd("bite launchpad search")
+        .args(["--created", "yesterday"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server.respond(200, TEST_DATA.join("search/valid")).await;
+
+    for opt in ["", "-v", "--verbose"] {
+        cmd("bite launchpad search nonexistent")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond(200, TEST_DATA.join("search/tasks.json"))
+        .await;
+
+    cmd("bite launchpad search -f id")
+        .assert()
+        .stdout("1\n2\n")
+        .stderr("")
+        .success();
+}
@@ -0,0 +1,90 @@
+use wiremock::{ResponseTemplate, matchers};
+
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite mantis")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite mantis get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/single.json")).await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Summary      : crashes on startup
+        Project      : bugbite
+        Category     : cli
+        Reporter     : reporter
+        Handler      : developer
+        Status       : assigned
+        Resolution   : open
+        Priority     : high
+        Severity     : crash
+        Created      : 2024-02-15 15:56:49 UTC
+        Updated      : 2024-02-16 10:00:26 UTC
+        ID           : 1
+        Tags         : crash, regression
+    "};
+
+    // without comments or history
+    cmd("bite mantis get 1 -C -H")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    let template = ResponseTemplate::new(404);
+    server.respond_custom(matchers::any(), template).await;
+
+    cmd("bite mantis get 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: mantis: nonexistent issue: 1").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn browser() {
+    let _server = start_server().await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite mantis get 1")
+            .arg(opt)
+            .env("BROWSER", "true")
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
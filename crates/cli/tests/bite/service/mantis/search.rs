@@ -0,0 +1,58 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite mantis")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn invalid_updated() {
+    cmd("bite mantis search")
+        .args(["--updated", "yesterday"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server.respond(200, TEST_DATA.join("search/valid")).await;
+
+    for opts in [vec![], vec!["-v"], vec!["--verbose"]] {
+        cmd("bite mantis search -p 1")
+            .args(opts)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond(200, TEST_DATA.join("search/issues.json"))
+        .await;
+
+    cmd("bite mantis search -f id")
+        .assert()
+        .stdout("3\n1\n")
+        .stderr("")
+        .success();
+}
@@ -6,6 +6,7 @@ use super::*;
 
 mod get;
 mod search;
+mod update;
 
 static TEST_DATA: LazyLock<Utf8PathBuf> =
     LazyLock::new(|| crate::TEST_DATA_PATH.join("bugbite/redmine"));
@@ -0,0 +1,68 @@
+use wiremock::{ResponseTemplate, matchers};
+
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["u", "update"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite redmine")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[tokio::test]
+async fn required_args() {
+    let _server = start_server().await;
+
+    // missing IDs
+    cmd("bite redmine update -S test")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+
+    // missing changes
+    cmd("bite redmine update 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: no parameters specified").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn auth_required() {
+    let _server = start_server().await;
+
+    cmd("bite redmine update 1 -S test")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: authentication required").trim())
+        .failure();
+}
+
+#[tokio::test]
+async fn watchers() {
+    let server = start_server_with_auth().await;
+    let template = ResponseTemplate::new(204);
+    server.respond_custom(matchers::any(), template).await;
+
+    for opt in ["-w", "--watchers"] {
+        cmd("bite redmine update 1")
+            .args([opt, "+100,-101"])
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
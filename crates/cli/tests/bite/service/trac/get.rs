@@ -0,0 +1,90 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["g", "get"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite trac")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn required_args() {
+    // missing IDs
+    cmd("bite trac get")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ))
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn single() {
+    let server = start_server().await;
+    server.respond(200, TEST_DATA.join("get/ticket.json")).await;
+
+    let expected = indoc::indoc! {"
+        ==========================================================================================
+        Summary      : crashes on startup
+        Reporter     : reporter
+        Owner        : developer
+        Created      : 2024-02-15 15:56:49 UTC
+        Updated      : 2024-02-16 10:00:26 UTC
+        Status       : assigned
+        Type         : defect
+        Component    : cli
+        Milestone    : 0.0.2
+        Priority     : major
+        Version      : 0.0.1
+        ID           : 1
+        Keywords     : crash, regression
+        CC           : user1, user2
+    "};
+
+    // without comments or history
+    cmd("bite trac get 1 -C -H")
+        .assert()
+        .stdout(predicate::str::diff(expected))
+        .stderr("")
+        .success();
+}
+
+#[tokio::test]
+async fn nonexistent() {
+    let server = start_server().await;
+    server
+        .respond(500, TEST_DATA.join("errors/nonexistent.json"))
+        .await;
+
+    cmd("bite trac get 1")
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::diff("Error: trac: nonexistent ticket: 1").trim())
+        .failure()
+        .code(1);
+}
+
+#[tokio::test]
+async fn browser() {
+    let _server = start_server().await;
+
+    for opt in ["-b", "--browser"] {
+        cmd("bite trac get 1")
+            .arg(opt)
+            .env("BROWSER", "true")
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
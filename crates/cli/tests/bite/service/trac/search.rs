@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn aliases() {
+    for subcmd in ["s", "search"] {
+        for opt in ["-h", "--help"] {
+            cmd("bite trac")
+                .arg(subcmd)
+                .arg(opt)
+                .assert()
+                .stdout(predicate::str::is_empty().not())
+                .stderr("")
+                .success();
+        }
+    }
+}
+
+#[test]
+fn invalid_created() {
+    cmd("bite trac search")
+        .args(["--created", "yesterday"])
+        .assert()
+        .stdout("")
+        .stderr(predicate::str::is_empty().not())
+        .failure()
+        .code(2);
+}
+
+#[tokio::test]
+async fn no_matches() {
+    let server = start_server().await;
+
+    server.respond(200, TEST_DATA.join("search/valid")).await;
+
+    for opt in ["", "-v", "--verbose"] {
+        cmd("bite trac search nonexistent")
+            .arg(opt)
+            .assert()
+            .stdout("")
+            .stderr("")
+            .success();
+    }
+}
+
+#[tokio::test]
+async fn ids_only() {
+    let server = start_server().await;
+
+    server
+        .respond_rpc("ticket.query", TEST_DATA.join("search/ids.json"))
+        .await;
+    server
+        .respond_rpc("system.multicall", TEST_DATA.join("search/tickets.json"))
+        .await;
+
+    cmd("bite trac search -f id")
+        .assert()
+        .stdout("2\n1\n")
+        .stderr("")
+        .success();
+}
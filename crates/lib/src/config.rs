@@ -224,6 +224,11 @@ mod tests {
             name = "gentoo"
             base = "https://bugs.gentoo.org/"
             user = "user@email.com"
+
+            [migrate.upstream]
+            copy = ["cc"]
+            set = { product = "Gentoo Linux" }
+            map.severity = { normal = "minor" }
         "#};
         fs::write("gentoo.toml", gentoo).unwrap();
 
@@ -244,5 +249,11 @@ mod tests {
             service.config().auth.user.as_deref().unwrap(),
             "user@email.com"
         );
+
+        // verify migration rules are loaded
+        let rules = &service.config().migrate["upstream"];
+        assert!(rules.copy.contains("cc"));
+        assert_eq!(rules.set["product"], "Gentoo Linux");
+        assert_eq!(rules.map["severity"]["normal"], "minor");
     }
 }
@@ -9,6 +9,8 @@ pub enum Error {
     Auth,
     #[error("{0}")]
     Config(String),
+    #[error("conflicting changes: {0}")]
+    Conflict(String),
     #[error("no parameters specified")]
     EmptyParams,
     #[error("invalid URL: {0}")]
@@ -23,8 +25,22 @@ pub enum Error {
     IO(String),
     #[error("bugzilla: {message}")]
     Bugzilla { code: i64, message: String },
+    #[error("debbugs: {0}")]
+    Debbugs(String),
+    #[error("gitea: {0}")]
+    Gitea(String),
+    #[error("gitlab: {0}")]
+    Gitlab(String),
+    #[error("jira: {0}")]
+    Jira(String),
+    #[error("launchpad: {0}")]
+    Launchpad(String),
+    #[error("mantis: {0}")]
+    Mantis(String),
     #[error("redmine: {0}")]
     Redmine(String),
+    #[error("trac: {0}")]
+    Trac(String),
     #[error("{0}")]
     Request(reqwest::Error),
     #[error("request timed out")]
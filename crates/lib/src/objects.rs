@@ -11,8 +11,15 @@ use crate::Error;
 use crate::traits::Contains;
 
 pub mod bugzilla;
+pub mod debbugs;
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
+pub mod jira;
+pub mod launchpad;
+pub mod mantis;
 pub mod redmine;
+pub mod trac;
 
 /// Raw binary data encoded as Base64.
 #[derive(DeserializeFromStr, SerializeDisplay, Default, Debug, PartialEq, Eq, Hash)]
@@ -18,7 +18,7 @@ use strum::{Display, EnumString};
 use crate::Error;
 use crate::serde::{byte_object, non_empty_str};
 use crate::service::bugzilla::{BugField, FilterField, GroupField, UNSET_VALUES};
-use crate::traits::RenderSearch;
+use crate::traits::{Api, RenderSearch};
 
 use super::{Base64, stringify};
 
@@ -147,6 +147,10 @@ pub struct Comment {
     pub is_private: bool,
     #[serde(default)]
     pub tags: IndexSet<String>,
+
+    /// Hours worked added with the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_time: Option<Hours>,
 }
 
 impl Comment {
@@ -209,10 +213,14 @@ pub enum FlagStatus {
     Remove,
 }
 
+#[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Flag {
     pub name: String,
     pub status: FlagStatus,
+    /// User a flag request is addressed to.
+    #[serde(default)]
+    pub requestee: Option<String>,
 }
 
 impl FromStr for Flag {
@@ -223,8 +231,26 @@ impl FromStr for Flag {
             return Err(Error::InvalidValue("empty flag".to_string()));
         }
 
-        let name = &s[..s.len() - 1];
-        let status = &s[s.len() - 1..];
+        // split requestee from flag requests, e.g. needinfo?(user@bugbite.test)
+        let (s, requestee) = match s.strip_suffix(')').and_then(|x| x.split_once('(')) {
+            Some((flag, requestee)) => {
+                if !flag.ends_with('?') {
+                    return Err(Error::InvalidValue(format!(
+                        "requestee only valid for flag requests: {s}"
+                    )));
+                } else if requestee.is_empty() {
+                    return Err(Error::InvalidValue(format!("empty flag requestee: {s}")));
+                }
+                (flag, Some(requestee.to_string()))
+            }
+            None => (s, None),
+        };
+
+        let Some(status_idx) = s.char_indices().last().map(|(i, _)| i) else {
+            return Err(Error::InvalidValue("empty flag".to_string()));
+        };
+        let name = &s[..status_idx];
+        let status = &s[status_idx..];
         let status = status
             .parse()
             .map_err(|_| Error::InvalidValue(format!("invalid flag status: {status}")))?;
@@ -232,13 +258,18 @@ impl FromStr for Flag {
         Ok(Self {
             name: name.to_string(),
             status,
+            requestee,
         })
     }
 }
 
 impl fmt::Display for Flag {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.name, self.status)
+        write!(f, "{}{}", self.name, self.status)?;
+        if let Some(value) = &self.requestee {
+            write!(f, "({value})")?;
+        }
+        Ok(())
     }
 }
 
@@ -284,6 +315,86 @@ pub(crate) fn alias_to_set<'de, D: Deserializer<'de>>(d: D) -> Result<IndexSet<S
     })
 }
 
+/// Time tracking value in hours.
+#[derive(Serialize, Debug, Default, PartialEq, PartialOrd, Clone, Copy)]
+#[serde(transparent)]
+pub struct Hours(f64);
+
+// Values are always finite, see Hours::new().
+impl Eq for Hours {}
+
+impl Hours {
+    /// Create a time tracking value, erroring on non-finite values.
+    pub fn new(value: f64) -> crate::Result<Self> {
+        if value.is_finite() {
+            Ok(Self(value))
+        } else {
+            Err(Error::InvalidValue(format!("invalid hours: {value}")))
+        }
+    }
+
+    /// Return the number of hours.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl FromStr for Hours {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let value = s
+            .parse()
+            .map_err(|_| Error::InvalidValue(format!("invalid hours: {s}")))?;
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for Hours {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Api for Hours {
+    fn api(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::ops::Add for Hours {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl std::iter::Sum for Hours {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+// Support deserializing hours from numbers or strings since older Bugzilla releases
+// return string values for time tracking fields.
+impl<'de> Deserialize<'de> for Hours {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Number(f64),
+            String(String),
+        }
+
+        match Value::deserialize(d)? {
+            Value::Number(value) => Self::new(value),
+            Value::String(value) => value.parse(),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
@@ -304,6 +415,9 @@ pub struct Bug {
     #[serde(rename = "last_change_time")]
     pub updated: Option<DateTime<Utc>>,
     pub deadline: Option<NaiveDate>,
+    pub estimated_time: Option<Hours>,
+    pub remaining_time: Option<Hours>,
+    pub actual_time: Option<Hours>,
     #[serde(deserialize_with = "non_empty_str")]
     pub summary: Option<String>,
     #[serde(deserialize_with = "non_empty_str")]
@@ -363,7 +477,7 @@ pub struct Bug {
     #[serde(skip)]
     pub history: Vec<Event>,
     #[serde(skip)]
-    pub custom_fields: IndexMap<BugzillaFieldName, String>,
+    pub custom_fields: IndexMap<BugzillaFieldName, CustomFieldValue>,
 }
 
 impl Bug {
@@ -378,6 +492,7 @@ impl RenderSearch<BugField> for Bug {
     fn render(&self, fields: &[BugField]) -> String {
         let field_to_string = |field: &BugField| -> String {
             match field {
+                BugField::ActualTime => format!("{:<8}", stringify!(self.actual_time)),
                 BugField::Alias => format!("{:<20}", self.alias.iter().join(",")),
                 BugField::Assignee => format!("{:<20}", stringify!(self.assigned_to)),
                 BugField::Blocks => format!("{:<20}", self.blocks.iter().join(",")),
@@ -388,6 +503,7 @@ impl RenderSearch<BugField> for Bug {
                 BugField::Deadline => stringify!(self.deadline),
                 BugField::Depends => format!("{:<20}", self.depends_on.iter().join(",")),
                 BugField::DuplicateOf => format!("{:<9}", stringify!(self.duplicate_of)),
+                BugField::EstimatedTime => format!("{:<8}", stringify!(self.estimated_time)),
                 BugField::Flags => format!("{:<20}", self.flags.iter().join(",")),
                 BugField::Id => format!("{:<9}", self.id),
                 BugField::Keywords => format!("{:<20}", self.keywords.iter().join(",")),
@@ -396,6 +512,7 @@ impl RenderSearch<BugField> for Bug {
                 BugField::Priority => format!("{:<12}", stringify!(self.priority)),
                 BugField::Product => format!("{:<20}", stringify!(self.product)),
                 BugField::Qa => format!("{:<20}", stringify!(self.qa_contact)),
+                BugField::RemainingTime => format!("{:<8}", stringify!(self.remaining_time)),
                 BugField::Resolution => format!("{:<20}", stringify!(self.resolution)),
                 BugField::SeeAlso => format!("{:<20}", self.see_also.iter().join(",")),
                 BugField::Severity => format!("{:<12}", stringify!(self.severity)),
@@ -436,7 +553,7 @@ impl RenderSearch<FilterField> for Bug {
 }
 
 /// Bugzilla field variants.
-#[derive(Serialize_repr, Deserialize_repr, Default, Debug)]
+#[derive(Serialize_repr, Deserialize_repr, Default, Debug, PartialEq, Eq, Clone, Copy)]
 #[repr(u8)]
 pub enum BugzillaFieldKind {
     /// Unknown field type
@@ -470,6 +587,17 @@ pub struct BugzillaFieldName {
     display: String,
 }
 
+impl BugzillaFieldName {
+    /// Create a field name for fields lacking metadata.
+    pub(crate) fn new<S: Into<String>>(id: S) -> Self {
+        let id = id.into();
+        Self {
+            display: id.clone(),
+            id,
+        }
+    }
+}
+
 impl PartialEq for BugzillaFieldName {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -498,7 +626,106 @@ impl PartialOrd for BugzillaFieldName {
 
 impl fmt::Display for BugzillaFieldName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.display)
+        f.pad(&self.display)
+    }
+}
+
+/// Custom field value.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum CustomFieldValue {
+    /// Single-line string or single value.
+    String(String),
+    /// Multi-line text.
+    Text(String),
+    /// Multiple values.
+    MultiValue(Vec<String>),
+    DateTime(DateTime<Utc>),
+    Date(NaiveDate),
+    BugId(u64),
+    Integer(i64),
+}
+
+impl CustomFieldValue {
+    /// Convert a raw field value using its field type.
+    ///
+    /// Returns `None` for unset values. Values of unknown field types are converted based on
+    /// their JSON type while values that fail parsing for their field type fall back to
+    /// strings.
+    pub(crate) fn new(kind: BugzillaFieldKind, value: serde_json::Value) -> Option<Self> {
+        use serde_json::Value;
+
+        let value = match (kind, value) {
+            (_, Value::Array(values)) => {
+                let values: Vec<_> = values
+                    .into_iter()
+                    .filter_map(|x| match x {
+                        Value::String(s) => Some(s),
+                        Value::Number(n) => Some(n.to_string()),
+                        _ => None,
+                    })
+                    .filter(|x| !UNSET_VALUES.contains(x))
+                    .collect();
+                if values.is_empty() {
+                    return None;
+                }
+                Self::MultiValue(values)
+            }
+            (BugzillaFieldKind::BugId, Value::Number(n)) => n
+                .as_u64()
+                .map(Self::BugId)
+                .unwrap_or_else(|| Self::String(n.to_string())),
+            (BugzillaFieldKind::Integer | BugzillaFieldKind::Unknown, Value::Number(n)) => n
+                .as_i64()
+                .map(Self::Integer)
+                .unwrap_or_else(|| Self::String(n.to_string())),
+            (_, Value::Number(n)) => Self::String(n.to_string()),
+            (_, Value::String(s)) if UNSET_VALUES.contains(&s) => return None,
+            (BugzillaFieldKind::BugId, Value::String(s)) => match s.parse() {
+                Ok(value) => Self::BugId(value),
+                Err(_) => Self::String(s),
+            },
+            (BugzillaFieldKind::Integer, Value::String(s)) => match s.parse() {
+                Ok(value) => Self::Integer(value),
+                Err(_) => Self::String(s),
+            },
+            (BugzillaFieldKind::DateTime, Value::String(s)) => {
+                let value = DateTime::parse_from_rfc3339(&s)
+                    .map(|x| x.to_utc())
+                    .or_else(|_| {
+                        NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S").map(|x| x.and_utc())
+                    });
+                match value {
+                    Ok(value) => Self::DateTime(value),
+                    Err(_) => Self::String(s),
+                }
+            }
+            (BugzillaFieldKind::Date, Value::String(s)) => {
+                match NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+                    Ok(value) => Self::Date(value),
+                    Err(_) => Self::String(s),
+                }
+            }
+            (BugzillaFieldKind::Text, Value::String(s)) => Self::Text(s),
+            (_, Value::String(s)) => Self::String(s),
+            (_, Value::Bool(value)) => Self::String(value.to_string()),
+            (_, Value::Null | Value::Object(_)) => return None,
+        };
+
+        Some(value)
+    }
+}
+
+impl fmt::Display for CustomFieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::String(value) | Self::Text(value) => value.fmt(f),
+            Self::MultiValue(values) => write!(f, "{}", values.iter().join(", ")),
+            Self::DateTime(value) => value.fmt(f),
+            Self::Date(value) => value.fmt(f),
+            Self::BugId(value) => value.fmt(f),
+            Self::Integer(value) => value.fmt(f),
+        }
     }
 }
 
@@ -510,7 +737,7 @@ pub struct BugzillaField {
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[serde(default, rename = "type")]
     pub kind: BugzillaFieldKind,
-    is_custom: bool,
+    pub(crate) is_custom: bool,
     #[serde(flatten)]
     pub name: BugzillaFieldName,
     is_mandatory: bool,
@@ -588,3 +815,267 @@ impl fmt::Display for User {
         }
     }
 }
+
+/// Product version or milestone.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ProductValue {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub sort_key: i64,
+    pub is_active: bool,
+}
+
+impl fmt::Display for ProductValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Product component.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Component {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub description: Option<String>,
+
+    /// User bugs are assigned to by default.
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub default_assigned_to: Option<String>,
+
+    /// User set as the QA contact by default.
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub default_qa_contact: Option<String>,
+
+    #[serde(default)]
+    pub sort_key: i64,
+    pub is_active: bool,
+}
+
+/// Bugzilla product.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Product {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub classification: Option<String>,
+    pub is_active: bool,
+    #[serde(default)]
+    pub has_unconfirmed: bool,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub default_milestone: Option<String>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+    #[serde(default)]
+    pub versions: Vec<ProductValue>,
+    #[serde(default)]
+    pub milestones: Vec<ProductValue>,
+}
+
+impl Product {
+    /// Return the component matching a given name.
+    pub fn component(&self, name: &str) -> Option<&Component> {
+        self.components.iter().find(|x| x.name == name)
+    }
+
+    /// Verify values are valid and active for the product.
+    pub fn validate(
+        &self,
+        component: Option<&str>,
+        version: Option<&str>,
+        target: Option<&str>,
+    ) -> crate::Result<()> {
+        let product = &self.name;
+        if !self.is_active {
+            return Err(Error::InvalidValue(format!("inactive product: {product}")));
+        }
+
+        if let Some(value) = component {
+            match self.component(value) {
+                Some(x) if x.is_active => (),
+                Some(_) => {
+                    return Err(Error::InvalidValue(format!(
+                        "inactive component for {product}: {value}"
+                    )));
+                }
+                None => {
+                    return Err(Error::InvalidValue(format!(
+                        "invalid component for {product}: {value}"
+                    )));
+                }
+            }
+        }
+
+        for (name, values, value) in [
+            ("version", &self.versions, version),
+            ("target", &self.milestones, target),
+        ] {
+            if let Some(value) = value
+                && !values.iter().any(|x| x.is_active && x.name == value)
+            {
+                return Err(Error::InvalidValue(format!(
+                    "invalid {name} for {product}: {value}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Product reference included in a classification.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ClassificationProduct {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub description: Option<String>,
+}
+
+/// Bugzilla classification grouping products.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Classification {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sort_key: i64,
+    #[serde(default)]
+    pub products: Vec<ClassificationProduct>,
+}
+
+/// Flag type available for bugs or attachments.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct FlagType {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "non_empty_str")]
+    pub description: Option<String>,
+
+    /// Flag can be requested.
+    #[serde(default)]
+    pub is_requestable: bool,
+
+    /// Flag requests can be addressed to specific users.
+    #[serde(default)]
+    pub is_requesteeble: bool,
+
+    /// Flag can be set multiple times.
+    #[serde(default)]
+    pub is_multiplicable: bool,
+
+    /// Valid flag status values.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+impl fmt::Display for FlagType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        let mut attrs = vec![];
+        if self.is_requestable {
+            if self.is_requesteeble {
+                attrs.push("requestee");
+            } else {
+                attrs.push("requestable");
+            }
+        }
+        if self.is_multiplicable {
+            attrs.push("multiple");
+        }
+        if !attrs.is_empty() {
+            write!(f, " ({})", attrs.iter().join(", "))?;
+        }
+        if let Some(value) = &self.description {
+            write!(f, ": {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag() {
+        // valid
+        for (s, name, status, requestee) in [
+            ("flag+", "flag", FlagStatus::Granted, None),
+            ("flag-", "flag", FlagStatus::Denied, None),
+            ("flag?", "flag", FlagStatus::Requested, None),
+            ("flagX", "flag", FlagStatus::Remove, None),
+            (
+                "needinfo?(user@bugbite.test)",
+                "needinfo",
+                FlagStatus::Requested,
+                Some("user@bugbite.test"),
+            ),
+        ] {
+            let flag: Flag = s.parse().unwrap();
+            assert_eq!(flag.name, name);
+            assert_eq!(flag.status, status);
+            assert_eq!(flag.requestee.as_deref(), requestee);
+            assert_eq!(flag.to_string(), s);
+        }
+
+        // invalid
+        for s in ["", "flag", "flag+(user)", "flag?()"] {
+            assert!(s.parse::<Flag>().is_err(), "{s} didn't fail");
+        }
+
+        // requestee is only serialized when set
+        let flag: Flag = "flag?".parse().unwrap();
+        assert_eq!(
+            serde_json::to_value(&flag).unwrap(),
+            serde_json::json!({"name": "flag", "status": "?"})
+        );
+        let flag: Flag = "flag?(user@bugbite.test)".parse().unwrap();
+        assert_eq!(
+            serde_json::to_value(&flag).unwrap(),
+            serde_json::json!({"name": "flag", "status": "?", "requestee": "user@bugbite.test"})
+        );
+    }
+
+    #[test]
+    fn custom_field_value() {
+        use serde_json::json;
+
+        // unset values
+        for value in [json!(null), json!("---"), json!([]), json!(["", "---"])] {
+            assert!(CustomFieldValue::new(BugzillaFieldKind::Unknown, value).is_none());
+        }
+
+        // malformed values for field types fall back to strings
+        for (kind, value, expected) in [
+            (BugzillaFieldKind::BugId, json!("abc"), "abc"),
+            (BugzillaFieldKind::BugId, json!(-1), "-1"),
+            (BugzillaFieldKind::Integer, json!("1.5"), "1.5"),
+            (BugzillaFieldKind::Integer, json!(1.5), "1.5"),
+            (BugzillaFieldKind::Date, json!("2024"), "2024"),
+            (BugzillaFieldKind::DateTime, json!("yesterday"), "yesterday"),
+        ] {
+            let value = CustomFieldValue::new(kind, value).unwrap();
+            assert_eq!(value, CustomFieldValue::String(expected.to_string()));
+        }
+
+        // values of unknown types use their raw types
+        let value = CustomFieldValue::new(BugzillaFieldKind::Unknown, json!(5)).unwrap();
+        assert_eq!(value, CustomFieldValue::Integer(5));
+        let value = CustomFieldValue::new(BugzillaFieldKind::Unknown, json!(["a", 1])).unwrap();
+        assert_eq!(value.to_string(), "a, 1");
+
+        // datetime formats
+        let value =
+            CustomFieldValue::new(BugzillaFieldKind::DateTime, json!("2024-03-14 10:00:00"));
+        assert_eq!(value.unwrap().to_string(), "2024-03-14 10:00:00 UTC");
+
+        // serialized as raw values
+        let value = CustomFieldValue::new(BugzillaFieldKind::BugId, json!("10")).unwrap();
+        assert_eq!(serde_json::to_value(&value).unwrap(), json!(10));
+    }
+}
@@ -0,0 +1,246 @@
+use chrono::prelude::*;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::service::debbugs::BugField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+/// Deserialize a string, treating empty values as nonexistent.
+fn string_opt<'de, D>(d: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(d)?;
+    match value {
+        serde_json::Value::String(s) if !s.trim().is_empty() => Ok(Some(s.trim().to_string())),
+        serde_json::Value::Number(n) => Ok(Some(n.to_string())),
+        _ => Ok(None),
+    }
+}
+
+/// Deserialize a set of space-separated values.
+fn space_separated<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromIterator<String>,
+{
+    Ok(string_opt(d)?
+        .map(|s| s.split_whitespace().map(Into::into).collect())
+        .unwrap_or_else(|| std::iter::empty().collect()))
+}
+
+/// Deserialize a list of space-separated bug IDs.
+fn bug_ids<'de, D>(d: D) -> Result<Vec<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values: Vec<String> = space_separated(d)?;
+    values
+        .iter()
+        .map(|x| x.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Deserialize a UNIX timestamp.
+fn timestamp<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = string_opt(d)?;
+    match value.map(|x| x.parse::<i64>()) {
+        Some(Ok(secs)) => Ok(DateTime::from_timestamp(secs, 0)),
+        Some(Err(e)) => Err(serde::de::Error::custom(e)),
+        None => Ok(None),
+    }
+}
+
+/// Deserialize a boolean from its integer representation.
+fn flag<'de, D>(d: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(d)?;
+    Ok(match value {
+        serde_json::Value::Bool(value) => value,
+        serde_json::Value::Number(n) => n.as_i64().unwrap_or_default() != 0,
+        serde_json::Value::String(s) => !matches!(s.trim(), "" | "0"),
+        _ => false,
+    })
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Bug {
+    #[serde(rename = "bug_num")]
+    pub id: u64,
+    #[serde(deserialize_with = "string_opt")]
+    pub subject: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    pub package: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    pub source: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    pub severity: Option<String>,
+    #[serde(rename = "pending", deserialize_with = "string_opt")]
+    pub status: Option<String>,
+    #[serde(
+        deserialize_with = "space_separated",
+        skip_serializing_if = "IndexSet::is_empty"
+    )]
+    pub tags: IndexSet<String>,
+    #[serde(rename = "originator", deserialize_with = "string_opt")]
+    pub reporter: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    pub owner: Option<String>,
+    #[serde(rename = "done", deserialize_with = "string_opt")]
+    pub closed_by: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    pub forwarded: Option<String>,
+    #[serde(
+        rename = "mergedwith",
+        deserialize_with = "bug_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub merged: Vec<u64>,
+    #[serde(deserialize_with = "bug_ids", skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<u64>,
+    #[serde(
+        rename = "blockedby",
+        deserialize_with = "bug_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub blocked_by: Vec<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub found_versions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixed_versions: Vec<String>,
+    #[serde(rename = "date", deserialize_with = "timestamp")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(rename = "last_modified", deserialize_with = "timestamp")]
+    pub updated: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "flag", skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+}
+
+/// A message from the bug log.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// Message number in the bug log.
+    pub id: u64,
+
+    /// The number of the comment local to the bug.
+    ///
+    /// The initial report is 0, followup messages start at 1.
+    pub count: usize,
+
+    pub text: String,
+    pub author: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+}
+
+impl Comment {
+    /// Create a comment from a raw bug log entry.
+    pub(crate) fn new(id: u64, count: usize, header: &str, body: &str) -> Self {
+        let mut author = None;
+        let mut created = None;
+        // only use the first occurrence of each header
+        for line in header.lines() {
+            if let Some(value) = line.strip_prefix("From:") {
+                author.get_or_insert_with(|| value.trim().to_string());
+            } else if let Some(value) = line.strip_prefix("Date:") {
+                created = created.or_else(|| {
+                    DateTime::parse_from_rfc2822(value.trim())
+                        .ok()
+                        .map(|x| x.to_utc())
+                });
+            }
+        }
+
+        Self {
+            id,
+            count,
+            text: body.to_string(),
+            author,
+            created,
+        }
+    }
+}
+
+impl RenderSearch<BugField> for Bug {
+    fn render(&self, fields: &[BugField]) -> String {
+        let field_to_string = |field: &BugField| -> String {
+            match field {
+                BugField::Created => stringify!(self.created),
+                BugField::Forwarded => format!("{:<20}", stringify!(self.forwarded)),
+                BugField::Id => format!("{:<8}", self.id),
+                BugField::Owner => format!("{:<20}", stringify!(self.owner)),
+                BugField::Package => format!("{:<20}", stringify!(self.package)),
+                BugField::Reporter => format!("{:<20}", stringify!(self.reporter)),
+                BugField::Severity => format!("{:<12}", stringify!(self.severity)),
+                BugField::Source => format!("{:<20}", stringify!(self.source)),
+                BugField::Status => format!("{:<12}", stringify!(self.status)),
+                BugField::Subject => stringify!(self.subject),
+                BugField::Tags => format!("{:<20}", self.tags.iter().join(",")),
+                BugField::Updated => stringify!(self.updated),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let value = json!({
+            "bug_num": 1,
+            "subject": "subject",
+            "package": "pkg",
+            "severity": "normal",
+            "tags": "patch upstream",
+            "mergedwith": 2,
+            "blocks": "3 4",
+            "blockedby": "",
+            "done": "",
+            "archived": 0,
+            "date": 1700000000,
+            "found_versions": ["1.0-1"],
+        });
+        let bug: Bug = serde_json::from_value(value).unwrap();
+        assert_eq!(bug.id, 1);
+        assert_ordered_eq!(&bug.tags, ["patch", "upstream"]);
+        assert_eq!(bug.merged, [2]);
+        assert_eq!(bug.blocks, [3, 4]);
+        assert!(bug.blocked_by.is_empty());
+        assert!(bug.closed_by.is_none());
+        assert!(!bug.archived);
+        assert_eq!(bug.created.unwrap().timestamp(), 1700000000);
+
+        // comment headers
+        let header = "From: John Smith <john@example.com>\nDate: Tue, 14 Nov 2023 22:13:20 +0000\n";
+        let comment = Comment::new(5, 0, header, "text");
+        assert_eq!(
+            comment.author.as_deref(),
+            Some("John Smith <john@example.com>")
+        );
+        assert_eq!(comment.created.unwrap().timestamp(), 1700000000);
+    }
+}
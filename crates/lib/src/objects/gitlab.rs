@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::prelude::*;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, serde_as, skip_serializing_none};
+
+use crate::service::gitlab::IssueField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Issue {
+    /// Issue ID local to the project.
+    #[serde(rename = "iid")]
+    pub id: u64,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub state: Option<String>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub labels: IndexSet<String>,
+    pub milestone: Option<Milestone>,
+    pub author: Option<User>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<User>,
+    #[serde(rename = "closed_at")]
+    pub closed: Option<DateTime<Utc>>,
+    #[serde(rename = "created_at")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(rename = "updated_at")]
+    pub updated: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+    #[serde(skip)]
+    pub events: Vec<Event>,
+}
+
+impl Issue {
+    pub fn updates(&self) -> impl Iterator<Item = IssueUpdate<'_>> {
+        let comments = self.comments.iter().map(IssueUpdate::Comment);
+        let events = self.events.iter().map(IssueUpdate::Event);
+        comments.chain(events).sorted()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Milestone {
+    pub id: u64,
+    pub title: String,
+}
+
+impl fmt::Display for Milestone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.title)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct User {
+    pub username: String,
+    pub name: String,
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.username)
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// Globally unique ID for the comment.
+    pub id: u64,
+
+    /// The number of the comment local to the issue.
+    ///
+    /// The description is 0, comments start at 1.
+    #[serde(default)]
+    pub count: u64,
+
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default, rename = "body")]
+    pub text: String,
+    pub author: User,
+    #[serde(rename = "created_at")]
+    pub created: DateTime<Utc>,
+}
+
+/// A set of issue changes made by a user at a specific time.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub user: User,
+    pub created: DateTime<Utc>,
+    pub changes: Vec<Change>,
+}
+
+/// An individual issue field change.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    pub field: String,
+    pub removed: Option<String>,
+    pub added: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IssueUpdate<'a> {
+    Comment(&'a Comment),
+    Event(&'a Event),
+}
+
+impl IssueUpdate<'_> {
+    fn date(&self) -> &DateTime<Utc> {
+        match self {
+            Self::Comment(comment) => &comment.created,
+            Self::Event(event) => &event.created,
+        }
+    }
+}
+
+impl Ord for IssueUpdate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date().cmp(other.date())
+    }
+}
+
+impl PartialOrd for IssueUpdate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RenderSearch<IssueField> for Issue {
+    fn render(&self, fields: &[IssueField]) -> String {
+        let field_to_string = |field: &IssueField| -> String {
+            match field {
+                IssueField::Assignees => format!("{:<20}", self.assignees.iter().join(",")),
+                IssueField::Author => format!("{:<20}", stringify!(self.author)),
+                IssueField::Closed => stringify!(self.closed),
+                IssueField::Created => stringify!(self.created),
+                IssueField::Id => format!("{:<8}", self.id),
+                IssueField::Labels => format!("{:<20}", self.labels.iter().join(",")),
+                IssueField::Milestone => format!("{:<20}", stringify!(self.milestone)),
+                IssueField::State => format!("{:<8}", stringify!(self.state)),
+                IssueField::Title => stringify!(self.title),
+                IssueField::Updated => stringify!(self.updated),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
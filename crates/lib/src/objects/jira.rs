@@ -0,0 +1,394 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::prelude::*;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnNull, DisplayFromStr, serde_as, skip_serializing_none};
+
+use crate::service::jira::IssueField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+/// Deserialize a Jira timestamp, e.g. 2024-01-02T03:04:05.000+0000.
+fn datetime<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(d)?;
+    DateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .map(|x| x.to_utc())
+        .map_err(|e| de::Error::custom(format!("invalid timestamp: {value}: {e}")))
+}
+
+/// Deserialize an optional Jira timestamp.
+fn datetime_opt<'de, D: Deserializer<'de>>(d: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "datetime")] DateTime<Utc>);
+    Option::<Wrapper>::deserialize(d).map(|o| o.map(|x| x.0))
+}
+
+/// Deserialize a rich text field as plain text.
+///
+/// API v2 returns plain strings while API v3 returns Atlassian Document Format
+/// objects that are flattened to their text content.
+fn rich_text<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(d)?;
+    Ok(value.and_then(|x| match x {
+        serde_json::Value::String(s) => Some(s),
+        serde_json::Value::Null => None,
+        node => {
+            let mut text = String::new();
+            adf_text(&node, &mut text);
+            Some(text.trim().to_string())
+        }
+    }))
+}
+
+/// Recursively extract the text content from an Atlassian Document Format node.
+fn adf_text(node: &serde_json::Value, text: &mut String) {
+    let kind = node["type"].as_str().unwrap_or_default();
+    match kind {
+        "text" => text.push_str(node["text"].as_str().unwrap_or_default()),
+        "hardBreak" => text.push('\n'),
+        "mention" | "emoji" => text.push_str(node["attrs"]["text"].as_str().unwrap_or_default()),
+        _ => {
+            if let Some(nodes) = node["content"].as_array() {
+                for node in nodes {
+                    adf_text(node, text);
+                }
+            }
+
+            // separate block level nodes
+            if matches!(kind, "paragraph" | "heading" | "codeBlock" | "listItem") {
+                text.push('\n');
+            }
+        }
+    }
+}
+
+/// Object only used for its name, e.g. issue statuses or priorities.
+#[derive(Deserialize)]
+struct Named {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Project {
+    key: String,
+}
+
+/// Raw issue data as returned by the service.
+#[derive(Deserialize)]
+struct IssueData {
+    key: String,
+    #[serde(default)]
+    fields: FieldsData,
+    changelog: Option<ChangelogData>,
+}
+
+#[serde_as]
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct FieldsData {
+    summary: Option<String>,
+    #[serde(deserialize_with = "rich_text")]
+    description: Option<String>,
+    status: Option<Named>,
+    resolution: Option<Named>,
+    priority: Option<Named>,
+    issuetype: Option<Named>,
+    project: Option<Project>,
+    assignee: Option<User>,
+    reporter: Option<User>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    labels: IndexSet<String>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    components: Vec<Named>,
+    #[serde(deserialize_with = "datetime_opt")]
+    created: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "datetime_opt")]
+    updated: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "datetime_opt")]
+    resolutiondate: Option<DateTime<Utc>>,
+    comment: Option<CommentsData>,
+}
+
+#[derive(Deserialize)]
+struct CommentsData {
+    comments: Vec<Comment>,
+}
+
+#[derive(Deserialize)]
+struct ChangelogData {
+    histories: Vec<HistoryData>,
+}
+
+#[derive(Deserialize)]
+struct HistoryData {
+    author: Option<User>,
+    #[serde(deserialize_with = "datetime")]
+    created: DateTime<Utc>,
+    items: Vec<ChangeData>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeData {
+    field: String,
+    from_string: Option<String>,
+    to_string: Option<String>,
+}
+
+impl From<IssueData> for Issue {
+    fn from(value: IssueData) -> Self {
+        let fields = value.fields;
+        let comments = fields
+            .comment
+            .map(|x| x.comments)
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut comment)| {
+                comment.count = i as u64 + 1;
+                comment
+            })
+            .collect();
+        let events = value
+            .changelog
+            .map(|x| x.histories)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|x| Event {
+                user: x.author,
+                created: x.created,
+                changes: x
+                    .items
+                    .into_iter()
+                    .map(|x| Change {
+                        field: x.field,
+                        removed: x.from_string.filter(|s| !s.is_empty()),
+                        added: x.to_string.filter(|s| !s.is_empty()),
+                    })
+                    .filter(|x| x.removed.is_some() || x.added.is_some())
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            id: value.key,
+            summary: fields.summary,
+            description: fields.description,
+            status: fields.status.map(|x| x.name),
+            resolution: fields.resolution.map(|x| x.name),
+            priority: fields.priority.map(|x| x.name),
+            issue_type: fields.issuetype.map(|x| x.name),
+            project: fields.project.map(|x| x.key),
+            assignee: fields.assignee,
+            reporter: fields.reporter,
+            labels: fields.labels,
+            components: fields.components.into_iter().map(|x| x.name).collect(),
+            created: fields.created,
+            updated: fields.updated,
+            resolved: fields.resolutiondate,
+            comments,
+            events,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(from = "IssueData")]
+pub struct Issue {
+    /// Issue key, e.g. PROJ-123.
+    pub id: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub resolution: Option<String>,
+    pub priority: Option<String>,
+    #[serde(rename = "type")]
+    pub issue_type: Option<String>,
+    pub project: Option<String>,
+    pub assignee: Option<User>,
+    pub reporter: Option<User>,
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub labels: IndexSet<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+    pub resolved: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+    #[serde(skip)]
+    pub events: Vec<Event>,
+}
+
+impl Issue {
+    pub fn updates(&self) -> impl Iterator<Item = IssueUpdate<'_>> {
+        let comments = self.comments.iter().map(IssueUpdate::Comment);
+        let events = self.events.iter().map(IssueUpdate::Event);
+        comments.chain(events).sorted()
+    }
+}
+
+/// User account.
+///
+/// Jira Server and Data Center identify users by name while Jira Cloud uses
+/// opaque account IDs.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Hash, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub name: Option<String>,
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub display_name: String,
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name.as_deref() {
+            Some(name) if self.display_name.is_empty() => write!(f, "{name}"),
+            _ => write!(f, "{}", self.display_name),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// Globally unique ID for the comment.
+    #[serde_as(deserialize_as = "DisplayFromStr")]
+    pub id: u64,
+
+    /// The number of the comment local to the issue.
+    ///
+    /// The description is 0, comments start at 1.
+    #[serde(default)]
+    pub count: u64,
+
+    #[serde(default, rename = "body", deserialize_with = "rich_text")]
+    pub text: Option<String>,
+    pub author: Option<User>,
+    #[serde(deserialize_with = "datetime")]
+    pub created: DateTime<Utc>,
+}
+
+/// A set of issue changes made by a user at a specific time.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub user: Option<User>,
+    pub created: DateTime<Utc>,
+    pub changes: Vec<Change>,
+}
+
+/// An individual issue field change.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    pub field: String,
+    pub removed: Option<String>,
+    pub added: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IssueUpdate<'a> {
+    Comment(&'a Comment),
+    Event(&'a Event),
+}
+
+impl IssueUpdate<'_> {
+    fn date(&self) -> &DateTime<Utc> {
+        match self {
+            Self::Comment(comment) => &comment.created,
+            Self::Event(event) => &event.created,
+        }
+    }
+}
+
+impl Ord for IssueUpdate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date().cmp(other.date())
+    }
+}
+
+impl PartialOrd for IssueUpdate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RenderSearch<IssueField> for Issue {
+    fn render(&self, fields: &[IssueField]) -> String {
+        let field_to_string = |field: &IssueField| -> String {
+            match field {
+                IssueField::Assignee => format!("{:<20}", stringify!(self.assignee)),
+                IssueField::Components => format!("{:<20}", self.components.iter().join(",")),
+                IssueField::Created => stringify!(self.created),
+                IssueField::Id => format!("{:<12}", self.id),
+                IssueField::Labels => format!("{:<20}", self.labels.iter().join(",")),
+                IssueField::Priority => format!("{:<12}", stringify!(self.priority)),
+                IssueField::Project => format!("{:<12}", stringify!(self.project)),
+                IssueField::Reporter => format!("{:<20}", stringify!(self.reporter)),
+                IssueField::Resolution => format!("{:<12}", stringify!(self.resolution)),
+                IssueField::Resolved => stringify!(self.resolved),
+                IssueField::Status => format!("{:<12}", stringify!(self.status)),
+                IssueField::Summary => stringify!(self.summary),
+                IssueField::Type => format!("{:<12}", stringify!(self.issue_type)),
+                IssueField::Updated => stringify!(self.updated),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        // API v2 plain text
+        let data = serde_json::json!({
+            "key": "TEST-1",
+            "fields": {
+                "summary": "summary",
+                "description": "description",
+                "created": "2024-01-02T03:04:05.000+0000",
+                "labels": null,
+            },
+        });
+        let issue: Issue = serde_json::from_value(data).unwrap();
+        assert_eq!(issue.id, "TEST-1");
+        assert_eq!(issue.description.as_deref(), Some("description"));
+        assert_eq!(
+            issue.created.unwrap().to_rfc3339(),
+            "2024-01-02T03:04:05+00:00"
+        );
+        assert!(issue.labels.is_empty());
+
+        // API v3 document format
+        let data = serde_json::json!({
+            "key": "TEST-1",
+            "fields": {
+                "description": {
+                    "type": "doc",
+                    "version": 1,
+                    "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "a"}]},
+                        {"type": "paragraph", "content": [{"type": "text", "text": "b"}]},
+                    ],
+                },
+            },
+        });
+        let issue: Issue = serde_json::from_value(data).unwrap();
+        assert_eq!(issue.description.as_deref(), Some("a\nb"));
+    }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+
+use chrono::prelude::*;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::{DefaultOnNull, serde_as, skip_serializing_none};
+
+use crate::service::launchpad::BugTaskField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+/// Deserialize a person's name from the related API link.
+///
+/// Person links use the form https://api.launchpad.net/devel/~name.
+pub(crate) fn person<'de, D>(d: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let link: Option<String> = Option::deserialize(d)?;
+    Ok(link.and_then(|x| x.rsplit_once("/~").map(|(_, name)| name.to_string())))
+}
+
+/// Deserialize a bug ID from the related API link.
+///
+/// Bug links use the form https://api.launchpad.net/devel/bugs/1.
+fn bug_link<'de, D>(d: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let link: Option<String> = Option::deserialize(d)?;
+    link.and_then(|x| x.rsplit_once('/').map(|(_, id)| id.to_string()))
+        .map(|x| x.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Deserialize a bug ID from the related API link, failing if it doesn't exist.
+fn bug_id<'de, D>(d: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    bug_link(d)?.ok_or_else(|| serde::de::Error::custom("missing bug link"))
+}
+
+/// Deserialize a bug task title, stripping the generated bug and target prefix.
+///
+/// Bug task titles use the form: Bug #1 in target: "title".
+fn task_title<'de, D>(d: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let title: Option<String> = Option::deserialize(d)?;
+    Ok(title.map(|x| {
+        x.split_once(": \"")
+            .and_then(|(_, title)| title.strip_suffix('"'))
+            .map(|x| x.to_string())
+            .unwrap_or(x)
+    }))
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Bug {
+    pub id: u64,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "owner_link", deserialize_with = "person")]
+    pub reporter: Option<String>,
+    #[serde(rename = "date_created")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(rename = "date_last_updated")]
+    pub updated: Option<DateTime<Utc>>,
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub tags: IndexSet<String>,
+    pub heat: Option<u64>,
+    pub information_type: Option<String>,
+    #[serde(rename = "duplicate_of_link", deserialize_with = "bug_link")]
+    pub duplicate_of: Option<u64>,
+    #[serde(skip_deserializing, skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<BugTask>,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+    #[serde(skip)]
+    pub events: Vec<Event>,
+}
+
+impl Bug {
+    pub fn updates(&self) -> impl Iterator<Item = BugUpdate<'_>> {
+        let comments = self.comments.iter().map(BugUpdate::Comment);
+        let events = self.events.iter().map(BugUpdate::Event);
+        comments.chain(events).sorted()
+    }
+}
+
+/// A bug's status within a specific target such as a project or package.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct BugTask {
+    #[serde(rename = "bug_link", deserialize_with = "bug_id")]
+    pub id: u64,
+    #[serde(default, deserialize_with = "task_title")]
+    pub title: Option<String>,
+    #[serde(rename = "bug_target_name")]
+    pub target: Option<String>,
+    pub status: Option<String>,
+    pub importance: Option<String>,
+    #[serde(default, rename = "assignee_link", deserialize_with = "person")]
+    pub assignee: Option<String>,
+    #[serde(default, rename = "owner_link", deserialize_with = "person")]
+    pub reporter: Option<String>,
+    #[serde(rename = "date_created")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(rename = "date_closed")]
+    pub closed: Option<DateTime<Utc>>,
+    #[serde(skip_serializing)]
+    pub(crate) self_link: Option<String>,
+    #[serde(skip_serializing)]
+    pub(crate) target_link: Option<String>,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// The number of the comment local to the bug.
+    ///
+    /// The description is 0, comments start at 1.
+    #[serde(default)]
+    pub count: u64,
+
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default, rename = "content")]
+    pub text: String,
+    #[serde(default, rename = "owner_link", deserialize_with = "person")]
+    pub author: Option<String>,
+    #[serde(rename = "date_created")]
+    pub created: DateTime<Utc>,
+}
+
+/// A set of bug changes made by a user at a specific time.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub user: String,
+    pub created: DateTime<Utc>,
+    pub changes: Vec<Change>,
+}
+
+/// An individual bug field change.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    pub field: String,
+    pub removed: Option<String>,
+    pub added: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BugUpdate<'a> {
+    Comment(&'a Comment),
+    Event(&'a Event),
+}
+
+impl BugUpdate<'_> {
+    fn date(&self) -> &DateTime<Utc> {
+        match self {
+            Self::Comment(comment) => &comment.created,
+            Self::Event(event) => &event.created,
+        }
+    }
+}
+
+impl Ord for BugUpdate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date().cmp(other.date())
+    }
+}
+
+impl PartialOrd for BugUpdate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RenderSearch<BugTaskField> for BugTask {
+    fn render(&self, fields: &[BugTaskField]) -> String {
+        let field_to_string = |field: &BugTaskField| -> String {
+            match field {
+                BugTaskField::Assignee => format!("{:<20}", stringify!(self.assignee)),
+                BugTaskField::Closed => stringify!(self.closed),
+                BugTaskField::Created => stringify!(self.created),
+                BugTaskField::Id => format!("{:<8}", self.id),
+                BugTaskField::Importance => format!("{:<10}", stringify!(self.importance)),
+                BugTaskField::Reporter => format!("{:<20}", stringify!(self.reporter)),
+                BugTaskField::Status => format!("{:<12}", stringify!(self.status)),
+                BugTaskField::Target => format!("{:<20}", stringify!(self.target)),
+                BugTaskField::Title => stringify!(self.title),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let value = json!({
+            "bug_link": "https://api.launchpad.net/devel/bugs/123",
+            "title": "Bug #123 in bugbite: \"crash: on startup\"",
+            "bug_target_name": "bugbite",
+            "status": "New",
+            "importance": "Undecided",
+            "assignee_link": null,
+            "owner_link": "https://api.launchpad.net/devel/~user",
+            "date_created": "2024-02-15T15:56:49.123456+00:00",
+            "date_closed": null,
+        });
+        let task: BugTask = serde_json::from_value(value).unwrap();
+        assert_eq!(task.id, 123);
+        assert_eq!(task.title.as_deref(), Some("crash: on startup"));
+        assert_eq!(task.reporter.as_deref(), Some("user"));
+        assert!(task.assignee.is_none());
+
+        let value = json!({
+            "id": 1,
+            "tags": null,
+            "duplicate_of_link": "https://api.launchpad.net/devel/bugs/2",
+        });
+        let bug: Bug = serde_json::from_value(value).unwrap();
+        assert!(bug.tags.is_empty());
+        assert_eq!(bug.duplicate_of, Some(2));
+    }
+}
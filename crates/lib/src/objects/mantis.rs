@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+
+use chrono::prelude::*;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::service::mantis::IssueField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Issue {
+    pub id: u64,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub project: Option<Field>,
+    pub category: Option<Field>,
+    pub reporter: Option<Person>,
+    pub handler: Option<Person>,
+    pub status: Option<Field>,
+    pub resolution: Option<Field>,
+    pub priority: Option<Field>,
+    pub severity: Option<Field>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Field>,
+    #[serde(rename = "created_at")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(rename = "updated_at")]
+    pub updated: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+    #[serde(skip)]
+    pub events: Vec<Event>,
+}
+
+impl Issue {
+    pub fn updates(&self) -> impl Iterator<Item = IssueUpdate<'_>> {
+        let comments = self.comments.iter().map(IssueUpdate::Comment);
+        let events = self.events.iter().map(IssueUpdate::Event);
+        comments.chain(events).sorted()
+    }
+}
+
+/// Named field value, e.g. a status or project.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Field {
+    id: u64,
+    name: String,
+}
+
+impl Deref for Field {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Person {
+    pub id: u64,
+    pub name: String,
+}
+
+impl fmt::Display for Person {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// The number of the comment local to the issue.
+    ///
+    /// The description is 0, notes start at 1.
+    #[serde(default)]
+    pub count: u64,
+    pub text: String,
+    #[serde(rename = "reporter")]
+    pub user: Person,
+    #[serde(rename = "created_at")]
+    pub created: DateTime<Utc>,
+}
+
+/// A set of issue changes made by a user at a specific time.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub user: String,
+    pub created: DateTime<Utc>,
+    pub changes: Vec<Change>,
+}
+
+/// An individual issue field change.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    pub field: String,
+    pub removed: Option<String>,
+    pub added: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IssueUpdate<'a> {
+    Comment(&'a Comment),
+    Event(&'a Event),
+}
+
+impl IssueUpdate<'_> {
+    fn date(&self) -> &DateTime<Utc> {
+        match self {
+            Self::Comment(comment) => &comment.created,
+            Self::Event(event) => &event.created,
+        }
+    }
+}
+
+impl Ord for IssueUpdate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date().cmp(other.date())
+    }
+}
+
+impl PartialOrd for IssueUpdate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RenderSearch<IssueField> for Issue {
+    fn render(&self, fields: &[IssueField]) -> String {
+        let field_to_string = |field: &IssueField| -> String {
+            match field {
+                IssueField::Category => format!("{:<20}", stringify!(self.category)),
+                IssueField::Created => stringify!(self.created),
+                IssueField::Handler => format!("{:<20}", stringify!(self.handler)),
+                IssueField::Id => format!("{:<8}", self.id),
+                IssueField::Priority => format!("{:<12}", stringify!(self.priority)),
+                IssueField::Project => format!("{:<20}", stringify!(self.project)),
+                IssueField::Reporter => format!("{:<20}", stringify!(self.reporter)),
+                IssueField::Resolution => format!("{:<12}", stringify!(self.resolution)),
+                IssueField::Severity => format!("{:<12}", stringify!(self.severity)),
+                IssueField::Status => format!("{:<12}", stringify!(self.status)),
+                IssueField::Summary => stringify!(self.summary),
+                IssueField::Updated => stringify!(self.updated),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
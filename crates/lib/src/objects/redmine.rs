@@ -34,6 +34,7 @@ pub struct Issue {
     pub created: Option<DateTime<Utc>>,
     #[serde(rename = "updated_on")]
     pub updated: Option<DateTime<Utc>>,
+    pub watchers: Option<Vec<Person>>,
     #[serde(skip)]
     pub comments: Vec<Comment>,
 }
@@ -94,7 +95,7 @@ pub enum CustomFieldValue {
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
 pub struct Person {
-    id: u64,
+    pub id: u64,
     name: String,
 }
 
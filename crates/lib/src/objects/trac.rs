@@ -0,0 +1,308 @@
+use std::cmp::Ordering;
+
+use chrono::prelude::*;
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::service::trac::TicketField;
+use crate::traits::RenderSearch;
+
+use super::stringify;
+
+/// Deserialize a datetime from its JSON-RPC class encoding.
+///
+/// Datetimes use the form {"__jsonclass__": ["datetime", "2024-02-15T15:56:49"]}
+/// with naive values in UTC.
+pub(crate) fn datetime<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Class {
+        #[serde(rename = "__jsonclass__")]
+        class: (String, String),
+    }
+
+    let Class {
+        class: (kind, value),
+    } = Class::deserialize(d)?;
+    if kind != "datetime" {
+        return Err(serde::de::Error::custom(format!("invalid class: {kind}")));
+    }
+    NaiveDateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|x| x.and_utc())
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a string, treating empty values as nonexistent.
+fn string_opt<'de, D>(d: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(d)?;
+    Ok(value
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty()))
+}
+
+/// Deserialize a set of values separated by commas and/or whitespace.
+fn separated<'de, D>(d: D) -> Result<IndexSet<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(string_opt(d)?
+        .map(|s| {
+            s.split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|x| !x.is_empty())
+                .map(Into::into)
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Ticket attributes as returned by the service.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Attributes {
+    #[serde(deserialize_with = "string_opt")]
+    summary: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    description: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    reporter: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    owner: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    status: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    resolution: Option<String>,
+    #[serde(rename = "type", deserialize_with = "string_opt")]
+    kind: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    component: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    milestone: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    priority: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    severity: Option<String>,
+    #[serde(deserialize_with = "string_opt")]
+    version: Option<String>,
+    #[serde(deserialize_with = "separated")]
+    keywords: IndexSet<String>,
+    #[serde(deserialize_with = "separated")]
+    cc: IndexSet<String>,
+    #[serde(rename = "_ts")]
+    ts: Option<String>,
+}
+
+/// Raw ticket data in the form: [id, created, updated, attributes].
+#[derive(Deserialize)]
+struct TicketData(
+    u64,
+    #[serde(deserialize_with = "datetime")] DateTime<Utc>,
+    #[serde(deserialize_with = "datetime")] DateTime<Utc>,
+    Attributes,
+);
+
+impl From<TicketData> for Ticket {
+    fn from(value: TicketData) -> Self {
+        let TicketData(id, created, updated, attrs) = value;
+        Self {
+            id,
+            summary: attrs.summary,
+            description: attrs.description,
+            reporter: attrs.reporter,
+            owner: attrs.owner,
+            status: attrs.status,
+            resolution: attrs.resolution,
+            kind: attrs.kind,
+            component: attrs.component,
+            milestone: attrs.milestone,
+            priority: attrs.priority,
+            severity: attrs.severity,
+            version: attrs.version,
+            keywords: attrs.keywords,
+            cc: attrs.cc,
+            created: Some(created),
+            updated: Some(updated),
+            ts: attrs.ts,
+            comments: Default::default(),
+            events: Default::default(),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+#[serde(from = "TicketData")]
+pub struct Ticket {
+    pub id: u64,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub reporter: Option<String>,
+    pub owner: Option<String>,
+    pub status: Option<String>,
+    pub resolution: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub component: Option<String>,
+    pub milestone: Option<String>,
+    pub priority: Option<String>,
+    pub severity: Option<String>,
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub keywords: IndexSet<String>,
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub cc: IndexSet<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+    /// Change timestamp used to detect mid-air collisions on update.
+    #[serde(skip)]
+    pub(crate) ts: Option<String>,
+    #[serde(skip)]
+    pub comments: Vec<Comment>,
+    #[serde(skip)]
+    pub events: Vec<Event>,
+}
+
+impl Ticket {
+    pub fn updates(&self) -> impl Iterator<Item = TicketUpdate<'_>> {
+        let comments = self.comments.iter().map(TicketUpdate::Comment);
+        let events = self.events.iter().map(TicketUpdate::Event);
+        comments.chain(events).sorted()
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Comment {
+    /// The number of the comment local to the ticket.
+    ///
+    /// The description is 0, comments start at 1.
+    pub count: u64,
+    pub text: String,
+    pub author: String,
+    pub created: DateTime<Utc>,
+}
+
+/// A set of ticket changes made by a user at a specific time.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub user: String,
+    pub created: DateTime<Utc>,
+    pub changes: Vec<Change>,
+}
+
+/// An individual ticket field change.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct Change {
+    pub field: String,
+    pub removed: Option<String>,
+    pub added: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TicketUpdate<'a> {
+    Comment(&'a Comment),
+    Event(&'a Event),
+}
+
+impl TicketUpdate<'_> {
+    fn date(&self) -> &DateTime<Utc> {
+        match self {
+            Self::Comment(comment) => &comment.created,
+            Self::Event(event) => &event.created,
+        }
+    }
+}
+
+impl Ord for TicketUpdate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date().cmp(other.date())
+    }
+}
+
+impl PartialOrd for TicketUpdate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl RenderSearch<TicketField> for Ticket {
+    fn render(&self, fields: &[TicketField]) -> String {
+        let field_to_string = |field: &TicketField| -> String {
+            match field {
+                TicketField::Component => format!("{:<20}", stringify!(self.component)),
+                TicketField::Created => stringify!(self.created),
+                TicketField::Id => format!("{:<8}", self.id),
+                TicketField::Keywords => format!("{:<20}", self.keywords.iter().join(",")),
+                TicketField::Milestone => format!("{:<20}", stringify!(self.milestone)),
+                TicketField::Owner => format!("{:<20}", stringify!(self.owner)),
+                TicketField::Priority => format!("{:<12}", stringify!(self.priority)),
+                TicketField::Reporter => format!("{:<20}", stringify!(self.reporter)),
+                TicketField::Resolution => format!("{:<12}", stringify!(self.resolution)),
+                TicketField::Status => format!("{:<12}", stringify!(self.status)),
+                TicketField::Summary => stringify!(self.summary),
+                TicketField::Type => format!("{:<12}", stringify!(self.kind)),
+                TicketField::Updated => stringify!(self.updated),
+            }
+        };
+
+        match fields {
+            [] => panic!("no fields defined"),
+            [field] => field_to_string(field).trim().to_string(),
+            fields => fields.iter().map(field_to_string).join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let value = json!([
+            1,
+            {"__jsonclass__": ["datetime", "2024-02-15T15:56:49"]},
+            {"__jsonclass__": ["datetime", "2024-02-16T10:00:26.123456"]},
+            {
+                "summary": "crashes on startup",
+                "owner": "",
+                "keywords": "crash, regression  startup",
+                "cc": "",
+                "_ts": "1708077626123456",
+            },
+        ]);
+        let ticket: Ticket = serde_json::from_value(value).unwrap();
+        assert_eq!(ticket.id, 1);
+        assert_eq!(ticket.summary.as_deref(), Some("crashes on startup"));
+        assert!(ticket.owner.is_none());
+        assert_eq!(
+            ticket.keywords,
+            ["crash", "regression", "startup"]
+                .into_iter()
+                .map(Into::into)
+                .collect::<IndexSet<String>>()
+        );
+        assert!(ticket.cc.is_empty());
+        assert_eq!(ticket.ts.as_deref(), Some("1708077626123456"));
+        assert_eq!(
+            ticket.updated.unwrap().to_rfc3339(),
+            "2024-02-16T10:00:26.123456+00:00"
+        );
+
+        // invalid datetime class
+        let value = json!([
+            1,
+            {"__jsonclass__": ["binary", "data"]},
+            {"__jsonclass__": ["datetime", "2024-02-16T10:00:26"]},
+            {},
+        ]);
+        assert!(serde_json::from_value::<Ticket>(value).is_err());
+    }
+}
@@ -13,8 +13,15 @@ use crate::traits::RenderSearch;
 use crate::utils::is_terminal;
 
 mod bugzilla;
+mod debbugs;
+mod gitea;
 mod github;
+mod gitlab;
+mod jira;
+mod launchpad;
+mod mantis;
 mod redmine;
+mod trac;
 
 pub static COLUMNS: LazyLock<usize> = LazyLock::new(|| {
     let (cols, _rows) = terminal::size().unwrap_or((90, 24));
@@ -1,6 +1,8 @@
 use std::io::{self, Write};
 
 use crate::objects::bugzilla::*;
+use crate::service::bugzilla::revert::Revert;
+use crate::service::bugzilla::update::Conflict;
 
 use super::*;
 
@@ -56,6 +58,9 @@ impl Render for Comment {
         if self.is_private {
             write!(f, " (private)")?;
         }
+        if let Some(value) = &self.work_time {
+            write!(f, " (worked {value}h)")?;
+        }
         writeln!(f, " by {}, {}", self.creator, self.created)?;
         writeln!(f, "{}", "-".repeat(width))?;
         // wrap comment text
@@ -107,6 +112,44 @@ impl Render for BugUpdate<'_> {
     }
 }
 
+impl Render for Conflict {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        writeln!(f, "=== Bug #{} ===", self.id)?;
+        for event in &self.events {
+            event.render(f, width)?;
+        }
+        Ok(())
+    }
+}
+
+impl Render for Revert {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        writeln!(f, "=== Bug #{} ===", self.id)?;
+        writeln!(f, "--- Reverted fields ---")?;
+        if self.changes.is_empty() {
+            writeln!(f, "None")?;
+        }
+        for change in &self.changes {
+            change.render(f, width)?;
+        }
+
+        if !self.skipped.is_empty() {
+            writeln!(f, "--- Skipped changes ---")?;
+            for change in &self.skipped {
+                change.render(f, width)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Render for Bug {
     fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
     where
@@ -119,6 +162,9 @@ impl Render for Bug {
         output_field!(f, "Created", &self.created, width);
         output_field!(f, "Updated", &self.updated, width);
         output_field!(f, "Deadline", &self.deadline, width);
+        output_field!(f, "Estimated", &self.estimated_time, width);
+        output_field!(f, "Worked", &self.actual_time, width);
+        output_field!(f, "Remaining", &self.remaining_time, width);
         output_field!(f, "Status", &self.status, width);
         output_field!(f, "Resolution", &self.resolution, width);
         output_field!(f, "Duplicate of", &self.duplicate_of, width);
@@ -145,12 +191,15 @@ impl Render for Bug {
             truncated_list(f, "See also", &self.see_also, width)?;
         }
 
-        // TODO: handle different custom field value types
         for (name, value) in &self.custom_fields {
-            let options = textwrap::Options::new(width - 15).subsequent_indent(&INDENT);
-            let wrapped = textwrap::wrap(value, &options);
-            let data = wrapped.iter().join("\n");
-            writeln!(f, "{name:<12} : {data}")?;
+            let name = name.to_string();
+            match value {
+                CustomFieldValue::MultiValue(values) => wrapped_csv(f, &name, values, width)?,
+                CustomFieldValue::String(value) | CustomFieldValue::Text(value) => {
+                    output_field_wrapped!(f, &name, Some(value), width)
+                }
+                value => output_field!(f, &name, Some(value), width),
+            }
         }
 
         if !self.comments.is_empty() {
@@ -179,4 +228,63 @@ impl Render for Bug {
     }
 }
 
-impl_render_display!(Attachment, Bug, Comment, Event);
+impl Render for Component {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let inactive = if self.is_active { "" } else { " (inactive)" };
+        let line = if let Some(value) = &self.description {
+            format!("{}{inactive}: {value}", self.name)
+        } else {
+            format!("{}{inactive}", self.name)
+        };
+        writeln!(f, "{}", truncate(&line, width))?;
+
+        let mut owners = vec![];
+        if let Some(value) = &self.default_assigned_to {
+            owners.push(format!("assignee: {value}"));
+        }
+        if let Some(value) = &self.default_qa_contact {
+            owners.push(format!("qa: {value}"));
+        }
+        if !owners.is_empty() {
+            let line = format!("  ({})", owners.iter().join(", "));
+            writeln!(f, "{}", truncate(&line, width))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Render for Product {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let inactive = if self.is_active { "" } else { " (inactive)" };
+        writeln!(f, "{:<12} : {}{inactive}", "Name", self.name)?;
+        output_field_wrapped!(f, "Description", &self.description, width);
+        output_field!(f, "Class", &self.classification, width);
+        output_field!(f, "Milestone", &self.default_milestone, width);
+        writeln!(f, "{:<12} : {}", "ID", self.id)?;
+
+        // only active values are shown
+        let versions = self.versions.iter().filter(|x| x.is_active);
+        wrapped_csv(f, "Versions", versions, width)?;
+        let milestones = self.milestones.iter().filter(|x| x.is_active);
+        wrapped_csv(f, "Milestones", milestones, width)?;
+
+        if !self.components.is_empty() {
+            writeln!(f, "\n{:<12} : {}", "Components", self.components.len())?;
+            writeln!(f, "{}", "-".repeat(width))?;
+            for component in &self.components {
+                component.render(f, width)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl_render_display!(Attachment, Bug, Comment, Component, Event, Product);
@@ -0,0 +1,71 @@
+use std::io::{self, Write};
+
+use crate::objects::debbugs::*;
+
+use super::*;
+
+impl Render for Comment {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if self.count != 0 {
+            write!(f, "Comment #{}", self.count)?;
+        } else {
+            write!(f, "Description")?;
+        }
+        if let Some(author) = &self.author {
+            write!(f, " by {author}")?;
+        }
+        if let Some(created) = &self.created {
+            write!(f, ", {created}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{}", "-".repeat(width))?;
+        // wrap comment text
+        let wrapped = textwrap::wrap(self.text.trim(), width);
+        writeln!(f, "{}", wrapped.iter().join("\n"))
+    }
+}
+
+impl Render for Bug {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        output_field_wrapped!(f, "Subject", &self.subject, width);
+        output_field!(f, "Package", &self.package, width);
+        output_field!(f, "Source", &self.source, width);
+        output_field!(f, "Reporter", &self.reporter, width);
+        output_field!(f, "Owner", &self.owner, width);
+        output_field!(f, "Severity", &self.severity, width);
+        output_field!(f, "Status", &self.status, width);
+        output_field!(f, "Closed by", &self.closed_by, width);
+        output_field!(f, "Forwarded", &self.forwarded, width);
+        output_field!(f, "Created", &self.created, width);
+        output_field!(f, "Updated", &self.updated, width);
+        writeln!(f, "{:<12} : {}", "ID", self.id)?;
+        wrapped_csv(f, "Tags", &self.tags, width)?;
+        wrapped_csv(f, "Found", &self.found_versions, width)?;
+        wrapped_csv(f, "Fixed", &self.fixed_versions, width)?;
+        wrapped_csv(f, "Merged", &self.merged, width)?;
+        wrapped_csv(f, "Blocks", &self.blocks, width)?;
+        wrapped_csv(f, "Blocked by", &self.blocked_by, width)?;
+        if self.archived {
+            writeln!(f, "{:<12} : yes", "Archived")?;
+        }
+
+        if !self.comments.is_empty() {
+            writeln!(f, "{:<12} : {}", "Comments", self.comments.len())?;
+        }
+
+        for comment in &self.comments {
+            writeln!(f)?;
+            comment.render(f, width)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_render_display!(Bug, Comment);
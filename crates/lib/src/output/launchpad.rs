@@ -0,0 +1,128 @@
+use std::io::{self, Write};
+
+use crate::objects::launchpad::*;
+
+use super::*;
+
+impl Render for Comment {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if self.count != 0 {
+            write!(f, "Comment #{} ", self.count)?;
+        } else {
+            write!(f, "Description ")?;
+        }
+        if let Some(author) = &self.author {
+            write!(f, "by {author}, ")?;
+        }
+        writeln!(f, "{}", self.created)?;
+        writeln!(f, "{}", "-".repeat(width))?;
+        // wrap comment text
+        let wrapped = textwrap::wrap(self.text.trim(), width);
+        writeln!(f, "{}", wrapped.iter().join("\n"))
+    }
+}
+
+impl Render for Event {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if !self.changes.is_empty() {
+            writeln!(f, "Changes made by {}, {}", self.user, self.created)?;
+            writeln!(f, "{}", "-".repeat(width))?;
+            for change in &self.changes {
+                change.render(f, width)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Render for Change {
+    fn render<W>(&self, f: &mut W, _width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let name = &self.field;
+        match (self.removed.as_deref(), self.added.as_deref()) {
+            (Some(removed), None) => writeln!(f, "{name}: -{removed}"),
+            (Some(removed), Some(added)) => writeln!(f, "{name}: {removed} -> {added}"),
+            (None, Some(added)) => writeln!(f, "{name}: +{added}"),
+            (None, None) => panic!("invalid change"),
+        }
+    }
+}
+
+impl Render for BugUpdate<'_> {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        match self {
+            BugUpdate::Comment(comment) => comment.render(f, width),
+            BugUpdate::Event(event) => event.render(f, width),
+        }
+    }
+}
+
+impl Render for BugTask {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let target = self.target.as_deref().unwrap_or_default();
+        let mut values = vec![];
+        values.extend(self.status.as_deref());
+        values.extend(self.importance.as_deref());
+        values.extend(self.assignee.as_deref());
+        let line = format!("{target}: {}", values.iter().join(", "));
+        writeln!(f, "{}", truncate(&line, width))
+    }
+}
+
+impl Render for Bug {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        output_field_wrapped!(f, "Title", &self.title, width);
+        output_field!(f, "Reporter", &self.reporter, width);
+        output_field!(f, "Information", &self.information_type, width);
+        output_field!(f, "Heat", &self.heat, width);
+        output_field!(f, "Duplicate of", &self.duplicate_of, width);
+        output_field!(f, "Created", &self.created, width);
+        output_field!(f, "Updated", &self.updated, width);
+        writeln!(f, "{:<12} : {}", "ID", self.id)?;
+        wrapped_csv(f, "Tags", &self.tags, width)?;
+
+        // render bug tasks as indented entries
+        if !self.tasks.is_empty() {
+            writeln!(f, "{:<12} :", "Tasks")?;
+            for task in &self.tasks {
+                write!(f, "{}", *INDENT)?;
+                Render::render(task, f, width - INDENT.len())?;
+            }
+        }
+
+        if !self.comments.is_empty() {
+            writeln!(f, "{:<12} : {}", "Comments", self.comments.len())?;
+        }
+
+        if !self.events.is_empty() {
+            writeln!(f, "{:<12} : {}", "Changes", self.events.len())?;
+        }
+
+        // render updates in order of occurrence
+        for update in self.updates() {
+            writeln!(f)?;
+            update.render(f, width)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_render_display!(Bug, BugTask, Comment, Event);
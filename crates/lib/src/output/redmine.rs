@@ -55,6 +55,10 @@ impl Render for Issue {
             }
         }
 
+        if let Some(values) = &self.watchers {
+            wrapped_csv(f, "Watchers", values, width)?;
+        }
+
         if !self.comments.is_empty() {
             writeln!(f, "{:<12} : {}", "Comments", self.comments.len())?;
         }
@@ -0,0 +1,111 @@
+use std::io::{self, Write};
+
+use crate::objects::trac::*;
+
+use super::*;
+
+impl Render for Comment {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if self.count != 0 {
+            write!(f, "Comment #{} ", self.count)?;
+        } else {
+            write!(f, "Description ")?;
+        }
+        if !self.author.is_empty() {
+            write!(f, "by {}, ", self.author)?;
+        }
+        writeln!(f, "{}", self.created)?;
+        writeln!(f, "{}", "-".repeat(width))?;
+        // wrap comment text
+        let wrapped = textwrap::wrap(self.text.trim(), width);
+        writeln!(f, "{}", wrapped.iter().join("\n"))
+    }
+}
+
+impl Render for Event {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if !self.changes.is_empty() {
+            writeln!(f, "Changes made by {}, {}", self.user, self.created)?;
+            writeln!(f, "{}", "-".repeat(width))?;
+            for change in &self.changes {
+                change.render(f, width)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Render for Change {
+    fn render<W>(&self, f: &mut W, _width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let name = &self.field;
+        match (self.removed.as_deref(), self.added.as_deref()) {
+            (Some(removed), None) => writeln!(f, "{name}: -{removed}"),
+            (Some(removed), Some(added)) => writeln!(f, "{name}: {removed} -> {added}"),
+            (None, Some(added)) => writeln!(f, "{name}: +{added}"),
+            (None, None) => panic!("invalid change"),
+        }
+    }
+}
+
+impl Render for TicketUpdate<'_> {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        match self {
+            TicketUpdate::Comment(comment) => comment.render(f, width),
+            TicketUpdate::Event(event) => event.render(f, width),
+        }
+    }
+}
+
+impl Render for Ticket {
+    fn render<W>(&self, f: &mut W, width: usize) -> io::Result<()>
+    where
+        W: Write,
+    {
+        output_field_wrapped!(f, "Summary", &self.summary, width);
+        output_field!(f, "Reporter", &self.reporter, width);
+        output_field!(f, "Owner", &self.owner, width);
+        output_field!(f, "Created", &self.created, width);
+        output_field!(f, "Updated", &self.updated, width);
+        output_field!(f, "Status", &self.status, width);
+        output_field!(f, "Resolution", &self.resolution, width);
+        output_field!(f, "Type", &self.kind, width);
+        output_field!(f, "Component", &self.component, width);
+        output_field!(f, "Milestone", &self.milestone, width);
+        output_field!(f, "Priority", &self.priority, width);
+        output_field!(f, "Severity", &self.severity, width);
+        output_field!(f, "Version", &self.version, width);
+        writeln!(f, "{:<12} : {}", "ID", self.id)?;
+        wrapped_csv(f, "Keywords", &self.keywords, width)?;
+        wrapped_csv(f, "CC", &self.cc, width)?;
+
+        if !self.comments.is_empty() {
+            writeln!(f, "{:<12} : {}", "Comments", self.comments.len())?;
+        }
+
+        if !self.events.is_empty() {
+            writeln!(f, "{:<12} : {}", "Changes", self.events.len())?;
+        }
+
+        // render updates in order of occurrence
+        for update in self.updates() {
+            writeln!(f)?;
+            update.render(f, width)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_render_display!(Ticket, Comment, Event);
@@ -12,8 +12,15 @@ use crate::Error;
 use crate::traits::{Merge, WebClient};
 
 pub mod bugzilla;
+pub mod debbugs;
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
+pub mod jira;
+pub mod launchpad;
+pub mod mantis;
 pub mod redmine;
+pub mod trac;
 
 static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
@@ -43,29 +50,71 @@ pub enum ServiceKind {
     #[default]
     Bugzilla,
 
+    /// Targets the SOAP interface provided by debbugs instances.
+    /// API docs: https://wiki.debian.org/DebbugsSoapInterface
+    Debbugs,
+
+    /// Targets the REST API v1 provided by Gitea and Forgejo instances.
+    /// API docs: https://docs.gitea.com/api/1.22/
+    Gitea,
+
     /// Targets the GitHub REST API version 2022-11-28.
     /// API docs: https://docs.github.com/en/rest/about-the-rest-api
     Github,
 
+    /// Targets the REST API v4 provided by GitLab instances.
+    /// API docs: https://docs.gitlab.com/api/rest/
+    Gitlab,
+
+    /// Targets the REST API v2 or v3 provided by Jira instances.
+    /// API docs: https://developer.atlassian.com/server/jira/platform/rest/
+    Jira,
+
+    /// Targets the devel version of the REST API provided by Launchpad.
+    /// API docs: https://api.launchpad.net/devel.html
+    Launchpad,
+
+    /// Targets the REST API provided by MantisBT 2.0 and up.
+    /// API docs: https://documenter.getpostman.com/view/29959/mantis-bug-tracker-rest-api/7Lt6zkP
+    Mantis,
+
     /// Targets the REST API using the JSON format.
     /// API docs: https://www.redmine.org/projects/redmine/wiki/rest_api
     Redmine,
+
+    /// Targets the JSON-RPC interface provided by the XmlRpcPlugin.
+    /// API docs: https://trac-hacks.org/wiki/XmlRpcPlugin
+    Trac,
 }
 
 #[derive(EnumAsInner, Deserialize, Serialize, Debug, Clone)]
 #[serde(tag = "type", rename_all = "kebab-case")]
 pub enum Config {
     Bugzilla(bugzilla::Config),
+    Debbugs(debbugs::Config),
+    Gitea(gitea::Config),
     Github(github::Config),
+    Gitlab(gitlab::Config),
+    Jira(jira::Config),
+    Launchpad(launchpad::Config),
+    Mantis(mantis::Config),
     Redmine(redmine::Config),
+    Trac(trac::Config),
 }
 
 impl Merge<ClientParameters> for Config {
     fn merge(&mut self, other: ClientParameters) {
         match self {
             Self::Bugzilla(config) => config.client.merge(other),
+            Self::Debbugs(config) => config.client.merge(other),
+            Self::Gitea(config) => config.client.merge(other),
             Self::Github(config) => config.client.merge(other),
+            Self::Gitlab(config) => config.client.merge(other),
+            Self::Jira(config) => config.client.merge(other),
+            Self::Launchpad(config) => config.client.merge(other),
+            Self::Mantis(config) => config.client.merge(other),
             Self::Redmine(config) => config.client.merge(other),
+            Self::Trac(config) => config.client.merge(other),
         }
     }
 }
@@ -74,8 +123,15 @@ impl Config {
     pub(super) fn new(kind: ServiceKind, base: &str) -> crate::Result<Self> {
         let service = match kind {
             ServiceKind::Bugzilla => Self::Bugzilla(bugzilla::Config::new(base)?),
+            ServiceKind::Debbugs => Self::Debbugs(debbugs::Config::new(base)?),
+            ServiceKind::Gitea => Self::Gitea(gitea::Config::new(base)?),
             ServiceKind::Github => Self::Github(github::Config::new(base)?),
+            ServiceKind::Gitlab => Self::Gitlab(gitlab::Config::new(base)?),
+            ServiceKind::Jira => Self::Jira(jira::Config::new(base)?),
+            ServiceKind::Launchpad => Self::Launchpad(launchpad::Config::new(base)?),
+            ServiceKind::Mantis => Self::Mantis(mantis::Config::new(base)?),
             ServiceKind::Redmine => Self::Redmine(redmine::Config::new(base)?),
+            ServiceKind::Trac => Self::Trac(trac::Config::new(base)?),
         };
 
         Ok(service)
@@ -86,24 +142,45 @@ impl WebClient for Config {
     fn base(&self) -> &Url {
         match self {
             Self::Bugzilla(config) => config.base(),
+            Self::Debbugs(config) => config.base(),
+            Self::Gitea(config) => config.base(),
             Self::Github(config) => config.base(),
+            Self::Gitlab(config) => config.base(),
+            Self::Jira(config) => config.base(),
+            Self::Launchpad(config) => config.base(),
+            Self::Mantis(config) => config.base(),
             Self::Redmine(config) => config.base(),
+            Self::Trac(config) => config.base(),
         }
     }
 
     fn kind(&self) -> ServiceKind {
         match self {
             Self::Bugzilla(config) => config.kind(),
+            Self::Debbugs(config) => config.kind(),
+            Self::Gitea(config) => config.kind(),
             Self::Github(config) => config.kind(),
+            Self::Gitlab(config) => config.kind(),
+            Self::Jira(config) => config.kind(),
+            Self::Launchpad(config) => config.kind(),
+            Self::Mantis(config) => config.kind(),
             Self::Redmine(config) => config.kind(),
+            Self::Trac(config) => config.kind(),
         }
     }
 
     fn name(&self) -> &str {
         match self {
             Self::Bugzilla(config) => config.name(),
+            Self::Debbugs(config) => config.name(),
+            Self::Gitea(config) => config.name(),
             Self::Github(config) => config.name(),
+            Self::Gitlab(config) => config.name(),
+            Self::Jira(config) => config.name(),
+            Self::Launchpad(config) => config.name(),
+            Self::Mantis(config) => config.name(),
             Self::Redmine(config) => config.name(),
+            Self::Trac(config) => config.name(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::fmt;
 use std::str::FromStr;
-use std::sync::{Arc, LazyLock};
+use std::sync::{Arc, LazyLock, OnceLock};
 
 use indexmap::{IndexMap, IndexSet};
 use reqwest::RequestBuilder;
@@ -12,21 +12,35 @@ use tracing::{debug, trace};
 use url::Url;
 
 use crate::Error;
-use crate::objects::bugzilla::{Bug, BugzillaField};
-use crate::traits::{Api, Merge, WebClient, WebService};
+use crate::objects::bugzilla::{
+    Bug, BugzillaField, BugzillaFieldKind, BugzillaFieldName, CustomFieldValue,
+};
+use crate::time::TimeStatic;
+use crate::traits::{Api, InjectAuth, Merge, RequestSend, WebClient, WebService};
 
 use super::{ClientParameters, ServiceKind};
 
 pub mod attachment;
+pub mod classification;
 pub mod comment;
 pub mod create;
+pub mod dupes;
+pub mod edit;
 pub mod fields;
+pub mod flag_types;
 mod get;
+pub mod graph;
 pub mod history;
+pub mod migrate;
+pub mod product;
+pub mod requests;
+pub mod revert;
 pub mod search;
+pub mod timesheet;
 pub mod update;
 pub mod user;
 pub mod version;
+pub mod watch;
 
 /// Common default values used for unset fields.
 pub(crate) static UNSET_VALUES: LazyLock<HashSet<String>> = LazyLock::new(|| {
@@ -56,6 +70,14 @@ pub struct Config {
     /// Maximum number of results that can be returned by a search request.
     #[serde(default = "default_max_search_results")]
     pub max_search_results: usize,
+
+    /// Transport used for API requests.
+    #[serde(default)]
+    pub transport: Transport,
+
+    /// Field mapping rules for migrating bugs, keyed by target connection.
+    #[serde(default)]
+    pub migrate: IndexMap<String, migrate::Rules>,
 }
 
 // TODO: replace with default field value when stabilized
@@ -77,10 +99,38 @@ impl Config {
             auth: Default::default(),
             client: Default::default(),
             max_search_results: default_max_search_results(),
+            transport: Default::default(),
+            migrate: Default::default(),
         })
     }
 }
 
+/// Supported API transports.
+#[derive(
+    Display,
+    EnumIter,
+    EnumString,
+    VariantNames,
+    DeserializeFromStr,
+    SerializeDisplay,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Transport {
+    /// Detect the transport using a REST version request.
+    Auto,
+    /// REST API provided by bugzilla-5.0 and up.
+    #[default]
+    Rest,
+    /// JSON-RPC API provided via jsonrpc.cgi, used for pre-5.0 instances.
+    JsonRpc,
+}
+
 impl WebClient for Config {
     fn base(&self) -> &Url {
         &self.base
@@ -99,7 +149,8 @@ impl WebClient for Config {
 struct Service {
     client: reqwest::Client,
     config: Config,
-    cache: ServiceCache,
+    cache: OnceLock<Option<ServiceCache>>,
+    transport: OnceLock<Transport>,
 }
 
 #[derive(Debug)]
@@ -118,6 +169,11 @@ impl ServiceBuilder {
         self
     }
 
+    pub fn key(mut self, value: &str) -> Self {
+        self.config.auth.key = Some(value.to_string());
+        self
+    }
+
     pub fn user(mut self, value: &str) -> Self {
         self.config.auth.user = Some(value.to_string());
         self
@@ -128,12 +184,18 @@ impl ServiceBuilder {
         self
     }
 
+    pub fn transport(mut self, value: Transport) -> Self {
+        self.config.transport = value;
+        self
+    }
+
     /// Create a new service.
     pub fn build(self) -> crate::Result<Bugzilla> {
         let client = self.config.client.build()?;
         Ok(Bugzilla(Arc::new(Service {
             config: self.config,
             cache: Default::default(),
+            transport: Default::default(),
             client,
         })))
     }
@@ -193,21 +255,213 @@ impl Bugzilla {
         format!("{base}/show_bug.cgi?id={id}")
     }
 
-    fn deserialize_bug(&self, mut value: serde_json::Value) -> crate::Result<Bug> {
+    /// Return the transport used for API requests.
+    ///
+    /// When configured for auto-detection, the REST API is used if its version request
+    /// succeeds, falling back to JSON-RPC for legacy instances where the REST endpoint is
+    /// missing. The detected transport is cached while other request failures are
+    /// returned, allowing detection to be retried on the next request.
+    pub async fn transport(&self) -> crate::Result<Transport> {
+        if self.config().transport != Transport::Auto {
+            return Ok(self.config().transport);
+        } else if let Some(value) = self.0.transport.get() {
+            return Ok(*value);
+        }
+
+        let url = self.config().base.join("rest/version")?;
+        let request = self.client().get(url).auth_optional(self);
+        let response = request.send().await?;
+        trace!("{response:?}");
+
+        let status = response.status();
+        let transport = if status == reqwest::StatusCode::NOT_FOUND {
+            Transport::JsonRpc
+        } else if status.is_success() {
+            // legacy instances can respond to unknown paths using HTML pages
+            if response.json::<serde_json::Value>().await.is_ok() {
+                Transport::Rest
+            } else {
+                Transport::JsonRpc
+            }
+        } else {
+            // surface other failures without caching a transport
+            self.parse_response(response).await?;
+            Transport::Rest
+        };
+
+        debug!("detected transport: {transport}");
+        Ok(*self.0.transport.get_or_init(|| transport))
+    }
+
+    /// Send a JSON-RPC method call, returning its result.
+    async fn rpc(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        auth: bool,
+    ) -> crate::Result<serde_json::Value> {
+        let serde_json::Value::Object(mut params) = params else {
+            return Err(Error::InvalidValue(format!(
+                "invalid JSON-RPC parameters: {params}"
+            )));
+        };
+
+        // legacy instances only support authentication via method parameters
+        let config = &self.config().auth;
+        if let Some(key) = config.key.as_ref() {
+            params.insert("Bugzilla_api_key".to_string(), key.as_str().into());
+        } else if let (Some(user), Some(pass)) = (&config.user, &config.password) {
+            params.insert("Bugzilla_login".to_string(), user.as_str().into());
+            params.insert("Bugzilla_password".to_string(), pass.as_str().into());
+        } else if auth {
+            return Err(Error::Auth);
+        }
+
+        let url = self.config().base.join("jsonrpc.cgi")?;
+        let data = serde_json::json!({"method": method, "params": [params], "id": 0});
+        let request = self.client().post(url).json(&data);
+        let response = request.send().await?;
+        trace!("{response:?}");
+
+        // method errors are returned in the response data, possibly with an error status
+        let status = response.error_for_status_ref().map(|_| ());
+        let Ok(mut data) = response.json::<serde_json::Value>().await else {
+            status?;
+            return Err(Error::InvalidResponse(format!("{method} request")));
+        };
+        debug!(
+            "response data:\n{}",
+            serde_json::to_string_pretty(&data).unwrap()
+        );
+
+        let error = data["error"].take();
+        if !error.is_null() {
+            let code = error["code"].as_i64().unwrap_or_default();
+            let message = if let Some(value) = error["message"].as_str() {
+                value.to_string()
+            } else {
+                format!("unknown error: {code}")
+            };
+            return Err(Error::Bugzilla { code, message });
+        }
+        status?;
+
+        Ok(data["result"].take())
+    }
+
+    /// Return the cached field metadata, requesting it on first use.
+    ///
+    /// Failures are logged and recorded so the request isn't retried for the lifetime of the
+    /// service, using an empty cache where field values fall back to being handled based on
+    /// their raw types.
+    async fn cache(&self) -> &ServiceCache {
+        static EMPTY: LazyLock<ServiceCache> = LazyLock::new(Default::default);
+
+        if let Some(value) = self.0.cache.get() {
+            return value.as_ref().unwrap_or(&EMPTY);
+        }
+
+        let cache = match self.fields().send().await {
+            Ok(fields) => {
+                let (custom_fields, fields) = fields.into_iter().partition(|x| x.is_custom);
+                Some(ServiceCache {
+                    fields,
+                    custom_fields,
+                })
+            }
+            Err(e) => {
+                debug!("failed loading fields: {e}");
+                None
+            }
+        };
+
+        self.0
+            .cache
+            .get_or_init(|| cache)
+            .as_ref()
+            .unwrap_or(&EMPTY)
+    }
+
+    /// Return the type of a custom field if its metadata has been loaded.
+    fn custom_field_kind(&self, name: &str) -> BugzillaFieldKind {
+        self.0
+            .cache
+            .get()
+            .and_then(|x| x.as_ref())
+            .and_then(|x| x.custom_fields.iter().find(|x| x.name.id == name))
+            .map(|x| x.kind)
+            .unwrap_or_default()
+    }
+
+    /// Encode custom field values into the types required for their fields.
+    async fn encode_custom_fields(
+        &self,
+        values: Option<&IndexMap<String, String>>,
+    ) -> crate::Result<Option<IndexMap<String, serde_json::Value>>> {
+        let Some(values) = values else {
+            return Ok(None);
+        };
+
+        self.cache().await;
+        let mut fields = IndexMap::new();
+        for (name, value) in values {
+            let invalid =
+                |kind| Error::InvalidValue(format!("invalid {kind} value: {name}={value}"));
+            let value = match self.custom_field_kind(name) {
+                // empty values unset fields
+                BugzillaFieldKind::MultiValue => value
+                    .split(',')
+                    .map(|x| x.trim())
+                    .filter(|x| !x.is_empty())
+                    .collect(),
+                _ if value.is_empty() => value.as_str().into(),
+                BugzillaFieldKind::BugId => {
+                    value.parse::<u64>().map_err(|_| invalid("bug ID"))?.into()
+                }
+                BugzillaFieldKind::Integer => {
+                    value.parse::<i64>().map_err(|_| invalid("integer"))?.into()
+                }
+                BugzillaFieldKind::Date => TimeStatic::from_str(value)
+                    .map_err(|_| invalid("date"))?
+                    .datetime()
+                    .format("%Y-%m-%d")
+                    .to_string()
+                    .into(),
+                BugzillaFieldKind::DateTime => TimeStatic::from_str(value)
+                    .map_err(|_| invalid("datetime"))?
+                    .datetime()
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+                    .into(),
+                _ => value.as_str().into(),
+            };
+            fields.insert(name.clone(), value);
+        }
+
+        Ok(Some(fields))
+    }
+
+    async fn deserialize_bug(&self, mut value: serde_json::Value) -> crate::Result<Bug> {
         let mut custom_fields = IndexMap::new();
         if let Some(map) = value.as_object_mut() {
-            for field in &self.0.cache.custom_fields {
-                let Some(value) = map.remove(&field.name.id) else {
-                    continue;
-                };
-
-                // TODO: handle different custom field value types
-                let serde_json::Value::String(value) = value else {
-                    continue;
-                };
-
-                if !UNSET_VALUES.contains(&value) {
-                    custom_fields.insert(field.name.clone(), value);
+            let names: Vec<_> = map
+                .keys()
+                .filter(|x| x.starts_with("cf_"))
+                .cloned()
+                .collect();
+            if !names.is_empty() {
+                let cache = self.cache().await;
+                for name in names {
+                    let value = map.remove(&name).unwrap_or_default();
+                    let (name, kind) = cache
+                        .custom_fields
+                        .iter()
+                        .find(|x| x.name.id == name)
+                        .map(|x| (x.name.clone(), x.kind))
+                        .unwrap_or_else(|| (BugzillaFieldName::new(name), Default::default()));
+                    if let Some(value) = CustomFieldValue::new(kind, value) {
+                        custom_fields.insert(name, value);
+                    }
                 }
             }
         }
@@ -215,6 +469,7 @@ impl Bugzilla {
         let mut bug: Bug = serde_json::from_value(value)
             .map_err(|e| Error::InvalidResponse(format!("failed deserializing bug: {e}")))?;
         bug.custom_fields = custom_fields;
+
         Ok(bug)
     }
 
@@ -258,6 +513,14 @@ impl Bugzilla {
         attachment::update::Request::new(self, ids)
     }
 
+    pub fn classification<I, S>(&self, ids: I) -> classification::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        classification::Request::new(self, ids)
+    }
+
     pub fn comment<I, S>(&self, ids: I) -> comment::Request
     where
         I: IntoIterator<Item = S>,
@@ -270,10 +533,32 @@ impl Bugzilla {
         create::Request::new(self)
     }
 
+    pub fn dupes<S>(&self, summary: S) -> dupes::Request
+    where
+        S: Into<String>,
+    {
+        dupes::Request::new(self, summary)
+    }
+
+    pub fn edit<I, S>(&self, ids: I) -> edit::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        edit::Request::new(self, ids)
+    }
+
     pub fn fields(&self) -> fields::Request {
         fields::Request::new(self)
     }
 
+    pub fn flag_types<S>(&self, product: S) -> flag_types::Request
+    where
+        S: fmt::Display,
+    {
+        flag_types::Request::new(self, product)
+    }
+
     pub fn get<I, S>(&self, ids: I) -> get::Request
     where
         I: IntoIterator<Item = S>,
@@ -282,6 +567,14 @@ impl Bugzilla {
         get::Request::new(self, ids)
     }
 
+    pub fn graph<I, S>(&self, ids: I) -> graph::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        graph::Request::new(self, ids)
+    }
+
     pub fn history<I, S>(&self, ids: I) -> history::Request
     where
         I: IntoIterator<Item = S>,
@@ -290,10 +583,42 @@ impl Bugzilla {
         history::Request::new(self, ids)
     }
 
+    pub fn migrate<I, S>(&self, target: &Bugzilla, ids: I) -> migrate::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        migrate::Request::new(self, target, ids)
+    }
+
+    pub fn product<I, S>(&self, ids: I) -> product::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        product::Request::new(self, ids)
+    }
+
+    pub fn requests(&self) -> requests::Request {
+        requests::Request::new(self)
+    }
+
+    pub fn revert<I, S>(&self, ids: I) -> revert::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        revert::Request::new(self, ids)
+    }
+
     pub fn search(&self) -> search::Request {
         search::Request::new(self)
     }
 
+    pub fn timesheet(&self, search: search::Request) -> timesheet::Request {
+        timesheet::Request::new(search)
+    }
+
     pub fn update<I, S>(&self, ids: I) -> update::Request
     where
         I: IntoIterator<Item = S>,
@@ -302,10 +627,21 @@ impl Bugzilla {
         update::Request::new(self, ids)
     }
 
+    pub fn update_bulk<I>(&self, entries: I) -> update::bulk::Request
+    where
+        I: IntoIterator<Item = update::bulk::Entry>,
+    {
+        update::bulk::Request::new(self, entries)
+    }
+
     pub fn version(&self) -> version::Request {
         version::Request::new(self)
     }
 
+    pub fn watch(&self, search: search::Request) -> watch::Request {
+        watch::Request::new(self, search)
+    }
+
     pub fn user_create<I, S>(&self, emails: I) -> user::create::Request
     where
         I: IntoIterator<Item = S>,
@@ -465,6 +801,8 @@ impl Api for GroupField {
 )]
 #[strum(serialize_all = "kebab-case")]
 pub enum BugField {
+    /// Hours worked on the bug.
+    ActualTime,
     /// Unique aliases for the bug.
     Alias,
     /// User the bug is assigned to.
@@ -485,6 +823,8 @@ pub enum BugField {
     Depends,
     /// Bug ID that this bug is a duplicate of.
     DuplicateOf,
+    /// Hours estimated to fix the bug.
+    EstimatedTime,
     Flags,
     Id,
     Keywords,
@@ -494,6 +834,8 @@ pub enum BugField {
     Product,
     /// User who is the QA contact.
     Qa,
+    /// Hours remaining to fix the bug.
+    RemainingTime,
     Resolution,
     /// URLs to external trackers.
     SeeAlso,
@@ -519,6 +861,7 @@ impl From<BugField> for FilterField {
 impl Api for BugField {
     fn api(&self) -> String {
         let value = match self {
+            Self::ActualTime => "actual_time",
             Self::Alias => "alias",
             Self::Assignee => "assigned_to",
             Self::Blocks => "blocks",
@@ -529,6 +872,7 @@ impl Api for BugField {
             Self::Deadline => "deadline",
             Self::Depends => "depends_on",
             Self::DuplicateOf => "dupe_of",
+            Self::EstimatedTime => "estimated_time",
             Self::Flags => "flags",
             Self::Id => "id",
             Self::Keywords => "keywords",
@@ -537,6 +881,7 @@ impl Api for BugField {
             Self::Priority => "priority",
             Self::Product => "product",
             Self::Qa => "qa_contact",
+            Self::RemainingTime => "remaining_time",
             Self::Resolution => "resolution",
             Self::SeeAlso => "see_also",
             Self::Severity => "severity",
@@ -1,9 +1,9 @@
-use serde_json::Value;
+use serde_json::{Value, json};
 use url::Url;
 
 use crate::Error;
 use crate::objects::bugzilla::Attachment;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::traits::{InjectAuth, RequestSend, WebService};
 
 #[derive(Debug)]
@@ -68,13 +68,18 @@ impl RequestSend for Request {
     type Output = Vec<Vec<Attachment>>;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let request = self
-            .service
-            .client()
-            .get(self.url()?)
-            .auth_optional(&self.service);
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let url = self.url()?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            let mut params = json!({"ids": self.ids});
+            if !self.data {
+                params["exclude_fields"] = json!(["data"]);
+            }
+            self.service.rpc("Bug.attachments", params, false).await?
+        } else {
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let data = data["bugs"].take();
         let Value::Object(data) = data else {
             panic!("invalid bugzilla attachment response");
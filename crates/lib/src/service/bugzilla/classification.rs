@@ -0,0 +1,99 @@
+use url::Url;
+
+use crate::Error;
+use crate::objects::bugzilla::Classification;
+use crate::service::bugzilla::Bugzilla;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub ids: Vec<String>,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn url(&self) -> crate::Result<Url> {
+        let id = self
+            .ids
+            .first()
+            .ok_or_else(|| Error::InvalidRequest("no IDs specified".to_string()))?;
+
+        let mut url = self
+            .service
+            .config()
+            .base
+            .join(&format!("rest/classification/{id}"))?;
+
+        for id in &self.ids[1..] {
+            let kind = if id.parse::<u64>().is_ok() {
+                "ids"
+            } else {
+                "names"
+            };
+            url.query_pairs_mut().append_pair(kind, id);
+        }
+
+        Ok(url)
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Classification>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let request = self
+            .service
+            .client()
+            .get(self.url()?)
+            .auth_optional(&self.service);
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        serde_json::from_value(data["classifications"].take()).map_err(|e| {
+            Error::InvalidResponse(format!("failed deserializing classifications: {e}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u32>::new();
+        let err = service.classification(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        server
+            .respond(200, path.join("classification/single.json"))
+            .await;
+        let classifications = service.classification(["Tools"]).send().await.unwrap();
+        assert_eq!(classifications.len(), 1);
+        let products = &classifications[0].products;
+        assert_ordered_eq!(
+            products.iter().map(|x| x.name.as_str()),
+            ["Client", "Server"]
+        );
+    }
+}
@@ -1,9 +1,9 @@
-use serde_json::Value;
+use serde_json::{Value, json};
 use url::Url;
 
 use crate::Error;
 use crate::objects::bugzilla::Comment;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::time::TimeDeltaOrStatic;
 use crate::traits::{InjectAuth, RequestSend, WebService};
 
@@ -76,13 +76,18 @@ impl RequestSend for Request {
     type Output = Vec<Vec<Comment>>;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let request = self
-            .service
-            .client()
-            .get(self.url()?)
-            .auth_optional(&self.service);
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let url = self.url()?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            let mut params = json!({"ids": self.ids});
+            if let Some(value) = self.params.created_after.as_ref() {
+                params["new_since"] = value.as_ref().into();
+            }
+            self.service.rpc("Bug.comments", params, false).await?
+        } else {
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let data = data["bugs"].take();
         let serde_json::value::Value::Object(data) = data else {
             return Err(Error::InvalidResponse("comment request".to_string()));
@@ -200,5 +205,19 @@ mod tests {
             .await
             .unwrap();
         assert_ordered_eq!(comments[0].iter().map(|x| x.id), [4]);
+
+        server.reset().await;
+
+        // JSON-RPC
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_rpc("Bug.comments", path.join("comment/rpc.json"))
+            .await;
+        let comments = service.comment([1]).creator("user1").send().await.unwrap();
+        assert_ordered_eq!(comments[0].iter().map(|x| x.id), [1, 2, 3, 7]);
     }
 }
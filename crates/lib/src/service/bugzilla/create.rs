@@ -7,13 +7,15 @@ use serde_with::skip_serializing_none;
 
 use crate::Error;
 use crate::objects::bugzilla::{Bug, Flag};
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, dupes};
 use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct Request {
     #[serde(skip)]
     service: Bugzilla,
+    #[serde(skip)]
+    validate: bool,
     #[serde(flatten)]
     pub params: Parameters,
 }
@@ -23,7 +25,26 @@ impl RequestSend for Request {
 
     async fn send(&self) -> crate::Result<Self::Output> {
         let url = self.service.config().base.join("rest/bug")?;
-        let params = self.encode()?;
+        let mut params = self.encode()?;
+        params.custom_fields = self
+            .service
+            .encode_custom_fields(self.params.custom_fields.as_ref())
+            .await?;
+
+        if self.validate {
+            let product = self
+                .service
+                .product([params.product])
+                .send()
+                .await?
+                .remove(0);
+            product.validate(
+                Some(params.component),
+                Some(params.version),
+                params.target_milestone,
+            )?;
+        }
+
         let request = self
             .service
             .client()
@@ -55,10 +76,30 @@ impl Request {
     pub(super) fn new(service: &Bugzilla) -> Self {
         Self {
             service: service.clone(),
+            validate: false,
             params: Default::default(),
         }
     }
 
+    /// Enable or disable validating product-related values before creation.
+    pub fn validate(&mut self, value: bool) -> &mut Self {
+        self.validate = value;
+        self
+    }
+
+    /// Return the duplicate check request for open bugs in the same product.
+    pub fn dupes(&self) -> crate::Result<dupes::Request> {
+        let summary =
+            self.params.summary.as_deref().ok_or_else(|| {
+                Error::InvalidRequest("missing required field: summary".to_string())
+            })?;
+        let mut request = self.service.dupes(summary);
+        if let Some(value) = &self.params.product {
+            request.product(value);
+        }
+        Ok(request)
+    }
+
     /// Encode parameters into the form required for the request.
     fn encode(&self) -> crate::Result<RequestParameters<'_>> {
         let params = RequestParameters {
@@ -99,7 +140,7 @@ impl Request {
             target_milestone: self.params.target.as_deref(),
             url: self.params.url.as_deref(),
             whiteboard: self.params.whiteboard.as_deref(),
-            custom_fields: self.params.custom_fields.as_ref(),
+            custom_fields: Default::default(),
         };
 
         // verify required fields are non-empty
@@ -461,7 +502,7 @@ struct RequestParameters<'a> {
     whiteboard: Option<&'a str>,
 
     #[serde(flatten)]
-    custom_fields: Option<&'a IndexMap<String, String>>,
+    custom_fields: Option<IndexMap<String, serde_json::Value>>,
 }
 
 #[cfg(test)]
@@ -473,6 +514,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn dupes() {
+        let service = Bugzilla::new("https://bugzilla.test").unwrap();
+
+        // missing summary
+        let err = service.create().dupes().unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "missing required field: summary");
+
+        let mut request = service.create();
+        request.summary("summary").product("TestProduct");
+        let dupes = request.dupes().unwrap();
+        assert_eq!(dupes.summary, "summary");
+        assert_eq!(dupes.product.as_deref(), Some("TestProduct"));
+    }
+
     #[tokio::test]
     async fn request() {
         let path = TESTDATA_PATH.join("bugzilla");
@@ -619,4 +676,47 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn validate() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        server
+            .respond_match(
+                wiremock::matchers::query_param("names", "Client"),
+                200,
+                path.join("product/client.json"),
+            )
+            .await;
+        server.respond(200, path.join("create/creation.json")).await;
+
+        let request = |component: &str, version: &str| {
+            let mut req = service.create();
+            req.product("Client")
+                .component(component)
+                .version(version)
+                .description("description")
+                .summary("summary")
+                .validate(true);
+            req
+        };
+
+        // valid
+        let id = request("CLI", "1.0").send().await.unwrap();
+        assert_eq!(id, 123);
+
+        // invalid
+        let err = request("TUI", "1.0").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidValue(_));
+        assert_err_re!(err, "invalid component for Client: TUI");
+        let err = request("CLI", "unspecified").send().await.unwrap_err();
+        assert_err_re!(err, "invalid version for Client: unspecified");
+    }
 }
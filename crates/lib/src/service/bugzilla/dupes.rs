@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use futures_util::TryStreamExt;
+use indexmap::IndexSet;
+use itertools::Itertools;
+
+use crate::Error;
+use crate::objects::bugzilla::Bug;
+use crate::query::Order;
+use crate::service::bugzilla::search::OrderField;
+use crate::service::bugzilla::{BugField, Bugzilla};
+use crate::traits::RequestSend;
+
+/// Words ignored when comparing summaries.
+const STOPWORDS: &[&str] = &[
+    "after", "and", "are", "but", "can", "does", "doesn", "for", "from", "has", "have", "into",
+    "not", "now", "the", "when", "while", "with", "without",
+];
+
+/// Maximum number of bugs searched for candidates.
+const SEARCH_LIMIT: usize = 100;
+
+/// Minimum similarity for bugs to be considered candidates.
+const MIN_SCORE: f64 = 0.2;
+
+/// Return the normalized keywords for a summary.
+///
+/// Keywords are lowercased alphanumeric words, skipping short and common words.
+pub fn tokens(summary: &str) -> IndexSet<String> {
+    summary
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|x| x.chars().count() > 2)
+        .map(|x| x.to_lowercase())
+        .filter(|x| !STOPWORDS.contains(&x.as_str()))
+        .collect()
+}
+
+/// Return the keyword similarity between two summaries, ranging from 0 to 1.
+pub fn similarity(tokens: &IndexSet<String>, summary: &str) -> f64 {
+    let other = self::tokens(summary);
+    let union = tokens.union(&other).count();
+    if union == 0 {
+        0.0
+    } else {
+        tokens.intersection(&other).count() as f64 / union as f64
+    }
+}
+
+/// Possible duplicate of a bug.
+#[derive(Debug, PartialEq)]
+pub struct Candidate {
+    pub bug: Bug,
+    pub score: f64,
+}
+
+impl fmt::Display for Candidate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let score = (self.score * 100.0).round();
+        let summary = self.bug.summary.as_deref().unwrap_or_default();
+        write!(f, "{:<8} {score:>3}% {summary}", self.bug.id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    service: Bugzilla,
+    pub summary: String,
+    pub product: Option<String>,
+    pub exclude: Option<u64>,
+    pub limit: usize,
+}
+
+impl Request {
+    pub(super) fn new<S: Into<String>>(service: &Bugzilla, summary: S) -> Self {
+        Self {
+            service: service.clone(),
+            summary: summary.into(),
+            product: None,
+            exclude: None,
+            limit: 5,
+        }
+    }
+
+    /// Restrict candidates to a product.
+    pub fn product<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.product = Some(value.into());
+        self
+    }
+
+    /// Skip a bug from the candidates, e.g. the bug being checked.
+    pub fn exclude(&mut self, value: u64) -> &mut Self {
+        self.exclude = Some(value);
+        self
+    }
+
+    /// Set the maximum number of candidates returned.
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.limit = value;
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Candidate>;
+
+    /// Return the open bugs with the most similar summaries, ordered by descending similarity.
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let tokens = tokens(&self.summary);
+        if tokens.is_empty() {
+            return Err(Error::InvalidRequest(format!(
+                "no summary keywords: {}",
+                self.summary
+            )));
+        }
+
+        let mut request = self.service.search();
+        request
+            .summary([format!("=| {}", tokens.iter().join(" "))])
+            .fields([BugField::Id, BugField::Summary, BugField::Status])
+            .order([Order::Descending(OrderField::Updated)])
+            .limit(SEARCH_LIMIT);
+        if let Some(value) = &self.product {
+            request.params.product = Some(vec![value.into()]);
+        }
+
+        let bugs: Vec<_> = request.stream().try_collect().await?;
+        let mut candidates: Vec<_> = bugs
+            .into_iter()
+            .filter(|bug| Some(bug.id) != self.exclude)
+            .map(|bug| {
+                let score = similarity(&tokens, bug.summary.as_deref().unwrap_or_default());
+                Candidate { bug, score }
+            })
+            .filter(|x| x.score >= MIN_SCORE)
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(Ordering::Equal)
+                .then(a.bug.id.cmp(&b.bug.id))
+        });
+        candidates.truncate(self.limit);
+        Ok(candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[test]
+    fn similarity() {
+        let tokens = tokens("Crash when opening the settings dialog");
+        assert_eq!(
+            tokens.iter().collect::<Vec<_>>(),
+            ["crash", "opening", "settings", "dialog"]
+        );
+        assert_eq!(
+            super::similarity(&tokens, "CRASH: opening settings dialog"),
+            1.0
+        );
+        assert_eq!(super::similarity(&tokens, "settings dialog crash"), 0.75);
+        assert_eq!(super::similarity(&tokens, "unrelated bug"), 0.0);
+        assert_eq!(super::similarity(&IndexSet::new(), "a"), 0.0);
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // no keywords
+        let err = service.dupes("a is").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no summary keywords: a is");
+
+        server
+            .respond_match(
+                |req: &wiremock::Request| {
+                    let values: Vec<_> = req.url.query_pairs().map(|(_, v)| v).collect();
+                    [
+                        "crash opening settings dialog",
+                        "anywords",
+                        "short_desc",
+                        "TestProduct",
+                    ]
+                    .iter()
+                    .all(|x| values.contains(&(*x).into()))
+                },
+                200,
+                path.join("dupes/search.json"),
+            )
+            .await;
+
+        let candidates = service
+            .dupes("Crash when opening the settings dialog")
+            .product("TestProduct")
+            .exclude(1)
+            .limit(2)
+            .send()
+            .await
+            .unwrap();
+        let ids: Vec<_> = candidates.iter().map(|x| x.bug.id).collect();
+        assert_eq!(ids, [3, 2]);
+        assert_eq!(candidates[0].score, 1.0);
+        assert_eq!(
+            candidates[0].to_string(),
+            "3        100% settings dialog opening crash"
+        );
+    }
+}
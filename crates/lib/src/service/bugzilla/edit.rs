@@ -0,0 +1,345 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use chrono::SecondsFormat;
+use indexmap::{IndexMap, IndexSet};
+
+use crate::Error;
+use crate::objects::bugzilla::{Bug, CustomFieldValue};
+use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::update::bulk::Entry;
+use crate::service::bugzilla::update::{Parameters, SetChange};
+use crate::traits::RequestSend;
+
+/// Convert a set of values into changes setting them.
+fn set<T: Clone>(values: &IndexSet<T>) -> Option<Vec<SetChange<T>>> {
+    Some(values.iter().cloned().map(SetChange::Set).collect())
+}
+
+/// Convert a custom field value into its editable form.
+fn custom_field(value: &CustomFieldValue) -> String {
+    match value {
+        CustomFieldValue::DateTime(value) => value.to_rfc3339_opts(SecondsFormat::Secs, true),
+        value => value.to_string(),
+    }
+}
+
+impl From<&Bug> for Parameters {
+    /// Convert a bug into its editable field values.
+    fn from(bug: &Bug) -> Self {
+        Self {
+            alias: set(&bug.alias),
+            assignee: bug.assigned_to.clone(),
+            blocks: set(&bug.blocks),
+            cc: set(&bug.cc),
+            component: bug.component.clone(),
+            deadline: bug.deadline.map(|x| x.to_string()),
+            depends: set(&bug.depends_on),
+            estimated_time: bug.estimated_time,
+            groups: set(&bug.groups),
+            keywords: set(&bug.keywords),
+            os: bug.op_sys.clone(),
+            platform: bug.platform.clone(),
+            priority: bug.priority.clone(),
+            product: bug.product.clone(),
+            qa: bug.qa_contact.clone(),
+            remaining_time: bug.remaining_time,
+            resolution: bug.resolution.clone(),
+            see_also: set(&bug.see_also),
+            severity: bug.severity.clone(),
+            status: bug.status.clone(),
+            summary: bug.summary.clone(),
+            target: bug.target.clone(),
+            url: bug.url.clone(),
+            version: bug.version.clone(),
+            whiteboard: bug.whiteboard.clone(),
+            custom_fields: Some(
+                bug.custom_fields
+                    .iter()
+                    .map(|(name, value)| (name.id.clone(), custom_field(value)))
+                    .collect(),
+            )
+            .filter(|x: &IndexMap<_, _>| !x.is_empty()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Return the changed value for a single-value field.
+///
+/// Removed values leave the field unchanged, empty values are used to clear it.
+fn value<T: PartialEq>(original: Option<T>, edited: Option<T>) -> Option<T> {
+    edited.filter(|x| original.as_ref() != Some(x))
+}
+
+/// Return the element additions and removals for a list-based field.
+///
+/// Edited values are treated as the field's desired state, explicitly removed values
+/// are dropped from it. Removed fields leave the list unchanged.
+fn changes<T>(
+    original: Option<Vec<SetChange<T>>>,
+    edited: Option<Vec<SetChange<T>>>,
+) -> Option<Vec<SetChange<T>>>
+where
+    T: FromStr + Eq + Hash + Clone,
+{
+    let edited = edited?;
+    let original: IndexSet<_> = original
+        .into_iter()
+        .flatten()
+        .filter_map(|x| match x {
+            SetChange::Add(value) | SetChange::Set(value) => Some(value),
+            SetChange::Remove(_) => None,
+        })
+        .collect();
+    let edited: IndexSet<_> = edited
+        .into_iter()
+        .filter_map(|x| match x {
+            SetChange::Add(value) | SetChange::Set(value) => Some(value),
+            SetChange::Remove(_) => None,
+        })
+        .collect();
+
+    let changes: Vec<_> = edited
+        .difference(&original)
+        .cloned()
+        .map(SetChange::Add)
+        .chain(original.difference(&edited).cloned().map(SetChange::Remove))
+        .collect();
+    Some(changes).filter(|x| !x.is_empty())
+}
+
+/// Return the minimal parameters altering the original field values into the edited values.
+///
+/// Values lacking an editable form, e.g. comments or flags, are passed through when set.
+pub fn diff(original: &Parameters, edited: &Parameters) -> Parameters {
+    let (original, edited) = (original.clone(), edited.clone());
+
+    let mut custom_fields: IndexMap<_, _> = IndexMap::new();
+    let original_fields = original.custom_fields.unwrap_or_default();
+    let mut edited_fields = edited.custom_fields.unwrap_or_default();
+    for (name, value) in original_fields {
+        if let Some(value) = self::value(Some(value), edited_fields.shift_remove(&name)) {
+            custom_fields.insert(name, value);
+        }
+    }
+    custom_fields.extend(edited_fields);
+
+    Parameters {
+        alias: changes(original.alias, edited.alias),
+        assignee: value(original.assignee, edited.assignee),
+        blocks: changes(original.blocks, edited.blocks),
+        cc: changes(original.cc, edited.cc),
+        comment: edited.comment,
+        comment_from: edited.comment_from,
+        comment_is_private: edited.comment_is_private,
+        comment_privacy: edited.comment_privacy,
+        component: value(original.component, edited.component),
+        deadline: value(original.deadline, edited.deadline),
+        depends: changes(original.depends, edited.depends),
+        duplicate_of: edited.duplicate_of,
+        estimated_time: value(original.estimated_time, edited.estimated_time),
+        flags: edited.flags,
+        groups: changes(original.groups, edited.groups),
+        keywords: changes(original.keywords, edited.keywords),
+        os: value(original.os, edited.os),
+        platform: value(original.platform, edited.platform),
+        priority: value(original.priority, edited.priority),
+        product: value(original.product, edited.product),
+        qa: value(original.qa, edited.qa),
+        remaining_time: value(original.remaining_time, edited.remaining_time),
+        resolution: value(original.resolution, edited.resolution),
+        see_also: changes(original.see_also, edited.see_also),
+        severity: value(original.severity, edited.severity),
+        status: value(original.status, edited.status),
+        summary: value(original.summary, edited.summary),
+        target: value(original.target, edited.target),
+        url: value(original.url, edited.url),
+        version: value(original.version, edited.version),
+        whiteboard: value(original.whiteboard, edited.whiteboard),
+        work_time: edited.work_time,
+        custom_fields: Some(custom_fields).filter(|x| !x.is_empty()),
+    }
+}
+
+/// Return the changes for edited entries compared to their original values.
+///
+/// Entries removed while editing are skipped and entries without changes are dropped.
+pub fn entries(original: &[Entry], edited: &[Entry]) -> crate::Result<Vec<Entry>> {
+    let original: IndexMap<_, _> = original.iter().map(|x| (&x.id, &x.params)).collect();
+    let mut seen = HashSet::new();
+    let mut entries = vec![];
+
+    for entry in edited {
+        let Some(params) = original.get(&entry.id) else {
+            return Err(Error::InvalidValue(format!("unknown bug: {}", entry.id)));
+        };
+        if !seen.insert(&entry.id) {
+            return Err(Error::InvalidValue(format!("duplicate bug: {}", entry.id)));
+        }
+
+        let params = diff(params, &entry.params);
+        if params != Parameters::default() {
+            entries.push(Entry {
+                id: entry.id.clone(),
+                params,
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub ids: Vec<String>,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Entry>;
+
+    /// Return the editable field values for the targeted bugs.
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let bugs = self.service.get(&self.ids).send().await?;
+        Ok(bugs
+            .iter()
+            .map(|bug| Entry {
+                id: bug.id.to_string(),
+                params: bug.into(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::service::bugzilla::update::bulk::{self, Format};
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u32>::new();
+        let err = service.edit(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        server.respond(200, path.join("get/single-bug.json")).await;
+
+        let entries = service.edit([1]).send().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.id, "1");
+        let params = &entry.params;
+        assert_eq!(params.summary.as_deref(), Some("new summary"));
+        assert!(params.comment.is_none());
+
+        // editable values roundtrip through TOML documents
+        let data = bulk::dump(&entries, Format::Toml).unwrap();
+        let edited = bulk::parse(&data, Format::Toml).unwrap();
+        assert_eq!(edited, entries);
+        assert!(super::entries(&entries, &edited).unwrap().is_empty());
+    }
+
+    #[test]
+    fn diff() {
+        let original = [Entry {
+            id: "1".to_string(),
+            params: Parameters {
+                assignee: Some("user1@bugbite.test".to_string()),
+                blocks: Some(vec![SetChange::Set(10), SetChange::Set(20)]),
+                cc: Some(vec![SetChange::Set("user2@bugbite.test".to_string())]),
+                estimated_time: Some("8".parse().unwrap()),
+                remaining_time: Some("8".parse().unwrap()),
+                status: Some("NEW".to_string()),
+                summary: Some("summary".to_string()),
+                whiteboard: Some("whiteboard".to_string()),
+                custom_fields: Some([("cf_stage".to_string(), "alpha".to_string())].into()),
+                ..Default::default()
+            },
+        }];
+
+        // unchanged entries are dropped
+        let edited = original[0].clone();
+        assert!(entries(&original, &[edited]).unwrap().is_empty());
+
+        // minimal changes are generated
+        let mut edited = original[0].clone();
+        let params = &mut edited.params;
+        params.assignee = None;
+        params.blocks = Some(vec![SetChange::Set(20), SetChange::Set(30)]);
+        params.cc = Some(vec![]);
+        params.summary = None;
+        params.whiteboard = Some(String::new());
+        params.keywords = Some(vec![SetChange::Set("Regression".to_string())]);
+        params.status = Some("CONFIRMED".to_string());
+        params.comment = Some("comment".to_string());
+        params.remaining_time = Some("6".parse().unwrap());
+        params.work_time = Some("2".parse().unwrap());
+        params.custom_fields = Some([("cf_stage".to_string(), "beta".to_string())].into());
+        let changes = entries(&original, &[edited]).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].id, "1");
+        let params = &changes[0].params;
+        // removed fields are unchanged while empty values clear them
+        assert!(params.assignee.is_none());
+        assert!(params.summary.is_none());
+        assert_eq!(params.whiteboard.as_deref(), Some(""));
+        assert_eq!(
+            params.blocks.as_ref().unwrap(),
+            &[SetChange::Add(30), SetChange::Remove(10)]
+        );
+        assert_eq!(
+            params.cc.as_ref().unwrap(),
+            &[SetChange::Remove("user2@bugbite.test".to_string())]
+        );
+        assert_eq!(
+            params.keywords.as_ref().unwrap(),
+            &[SetChange::Add("Regression".to_string())]
+        );
+        assert_eq!(params.status.as_deref(), Some("CONFIRMED"));
+        assert_eq!(params.comment.as_deref(), Some("comment"));
+        assert!(params.estimated_time.is_none());
+        assert_eq!(params.remaining_time, Some("6".parse().unwrap()));
+        assert_eq!(params.work_time, Some("2".parse().unwrap()));
+        assert_eq!(params.custom_fields.as_ref().unwrap()["cf_stage"], "beta");
+
+        // removed list and custom fields are unchanged
+        let mut edited = original[0].clone();
+        edited.params.blocks = None;
+        edited.params.custom_fields = None;
+        assert!(entries(&original, &[edited]).unwrap().is_empty());
+
+        // unknown and duplicate entries
+        let mut edited = original[0].clone();
+        edited.id = "2".to_string();
+        let err = entries(&original, &[edited]).unwrap_err();
+        assert_err_re!(err, "unknown bug: 2");
+        let edited = original[0].clone();
+        let err = entries(&original, &[edited.clone(), edited]).unwrap_err();
+        assert_err_re!(err, "duplicate bug: 1");
+    }
+}
@@ -1,6 +1,8 @@
+use serde_json::json;
+
 use crate::Error;
 use crate::objects::bugzilla::BugzillaField;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::traits::{RequestSend, WebService};
 
 #[derive(Debug)]
@@ -20,10 +22,14 @@ impl RequestSend for Request {
     type Output = Vec<BugzillaField>;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let url = self.service.config().base.join("rest/field/bug")?;
-        let request = self.service.client().get(url);
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            self.service.rpc("Bug.fields", json!({}), false).await?
+        } else {
+            let url = self.service.config().base.join("rest/field/bug")?;
+            let request = self.service.client().get(url);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         serde_json::from_value(data["fields"].take())
             .map_err(|e| Error::InvalidResponse(format!("failed deserializing fields: {e}")))
     }
@@ -44,5 +50,19 @@ mod tests {
         server.respond(200, path.join("fields/gentoo.json")).await;
         let fields = service.fields().send().await.unwrap();
         assert!(!fields.is_empty());
+
+        server.reset().await;
+
+        // JSON-RPC
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_rpc("Bug.fields", path.join("fields/rpc.json"))
+            .await;
+        let fields = service.fields().send().await.unwrap();
+        assert!(fields.iter().any(|x| x.name.id == "cf_stage"));
     }
 }
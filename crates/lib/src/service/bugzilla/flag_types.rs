@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::Error;
+use crate::objects::bugzilla::FlagType;
+use crate::service::bugzilla::Bugzilla;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+/// Flag types available for a product or component.
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+pub struct FlagTypes {
+    #[serde(default)]
+    pub bug: Vec<FlagType>,
+    #[serde(default)]
+    pub attachment: Vec<FlagType>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub product: String,
+    pub component: Option<String>,
+}
+
+impl Request {
+    pub(super) fn new<S>(service: &Bugzilla, product: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            product: product.to_string(),
+            component: None,
+        }
+    }
+
+    fn url(&self) -> crate::Result<Url> {
+        if self.product.is_empty() {
+            return Err(Error::InvalidRequest("no product specified".to_string()));
+        }
+
+        let mut url = self.service.config().base.join("rest/flag_types/")?;
+        url.path_segments_mut()
+            .map_err(|_| Error::InvalidValue("invalid base URL".to_string()))?
+            .pop_if_empty()
+            .push(&self.product)
+            .extend(&self.component);
+        Ok(url)
+    }
+
+    /// Restrict flag types to a component.
+    pub fn component<S>(&mut self, value: S) -> &mut Self
+    where
+        S: std::fmt::Display,
+    {
+        self.component = Some(value.to_string());
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = FlagTypes;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let request = self
+            .service
+            .client()
+            .get(self.url()?)
+            .auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self.service.parse_response(response).await?;
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing flag types: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::matchers;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // no product
+        let err = service.flag_types("").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no product specified");
+
+        server
+            .respond_match(
+                matchers::path("/rest/flag_types/Test%20Product/CLI"),
+                200,
+                path.join("flag_types/component.json"),
+            )
+            .await;
+        let flag_types = service
+            .flag_types("Test Product")
+            .component("CLI")
+            .send()
+            .await
+            .unwrap();
+        assert_ordered_eq!(
+            flag_types.bug.iter().map(|x| x.name.as_str()),
+            ["needinfo", "review"]
+        );
+        assert!(flag_types.bug[0].is_requesteeble);
+        assert!(!flag_types.bug[1].is_requesteeble);
+        assert_ordered_eq!(
+            flag_types.attachment.iter().map(|x| x.name.as_str()),
+            ["approval"]
+        );
+    }
+}
@@ -1,13 +1,20 @@
-use serde_json::Value;
+use serde_json::{Value, json};
 use url::Url;
 
 use crate::Error;
 use crate::objects::bugzilla::Bug;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::traits::{InjectAuth, RequestSend, WebService};
 
 use super::{attachment, comment, history};
 
+/// Fields included in addition to the defaults, adding personal tags.
+const INCLUDE_FIELDS: &[&str] = &["_default", "tags"];
+
+/// Fields excluded from responses, dropping the useless token that is injected for
+/// authenticated requests.
+const EXCLUDE_FIELDS: &[&str] = &["update_token"];
+
 #[derive(Debug)]
 pub struct Request {
     service: Bugzilla,
@@ -46,13 +53,9 @@ impl Request {
             url.query_pairs_mut().append_pair("ids", id);
         }
 
-        // include personal tags
         url.query_pairs_mut()
-            .append_pair("include_fields", "_default,tags");
-
-        // drop useless token that is injected for authenticated requests
-        url.query_pairs_mut()
-            .append_pair("exclude_fields", "update_token");
+            .append_pair("include_fields", &INCLUDE_FIELDS.join(","))
+            .append_pair("exclude_fields", &EXCLUDE_FIELDS.join(","));
 
         Ok(url)
     }
@@ -88,19 +91,25 @@ impl RequestSend for Request {
     type Output = Vec<Bug>;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let request = self
-            .service
-            .client()
-            .get(self.url()?)
-            .auth_optional(&self.service);
+        let url = self.url()?;
 
         // send data requests
         let attachments = self.attachments.as_ref().map(|r| r.send());
         let comments = self.comments.as_ref().map(|r| r.send());
         let history = self.history.as_ref().map(|r| r.send());
 
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            let params = json!({
+                "ids": self.ids,
+                "include_fields": INCLUDE_FIELDS,
+                "exclude_fields": EXCLUDE_FIELDS,
+            });
+            self.service.rpc("Bug.get", params, false).await?
+        } else {
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let Value::Array(data) = data["bugs"].take() else {
             return Err(Error::InvalidResponse("get request".to_string()));
         };
@@ -121,7 +130,7 @@ impl RequestSend for Request {
 
         let mut bugs = vec![];
         for value in data {
-            let mut bug = self.service.deserialize_bug(value)?;
+            let mut bug = self.service.deserialize_bug(value).await?;
             bug.attachments = attachments.next().unwrap_or_default();
             bug.comments = comments.next().unwrap_or_default();
             bug.history = history.next().unwrap_or_default();
@@ -136,6 +145,10 @@ impl RequestSend for Request {
 mod tests {
     use std::assert_matches;
 
+    use itertools::Itertools;
+    use wiremock::matchers;
+
+    use crate::objects::bugzilla::{BugzillaFieldName, CustomFieldValue};
     use crate::test::*;
 
     use super::*;
@@ -190,5 +203,125 @@ mod tests {
         let ids = [12345, 23456, 34567];
         let bugs = service.get(ids).send().await.unwrap();
         assert_ordered_eq!(bugs.iter().map(|x| x.id), ids);
+
+        server.reset().await;
+
+        // custom fields converted using their field types
+        let fields = |req: &wiremock::Request| req.url.path() == "/rest/field/bug";
+        server
+            .respond_match(fields, 200, path.join("fields/custom.json"))
+            .await;
+        server
+            .respond(200, path.join("get/custom-fields.json"))
+            .await;
+        let bug = service.get([1]).send().await.unwrap().remove(0);
+        let field = |name: &str| bug.custom_fields.get(&BugzillaFieldName::new(name));
+        assert!(field("cf_stage").is_none());
+        assert_eq!(
+            field("cf_packages").unwrap(),
+            &CustomFieldValue::MultiValue(vec!["foo".to_string(), "bar".to_string()])
+        );
+        assert_eq!(
+            field("cf_notes").unwrap(),
+            &CustomFieldValue::Text("first line\nsecond line".to_string())
+        );
+        assert_eq!(
+            field("cf_reviewed").unwrap(),
+            &CustomFieldValue::DateTime("2024-03-14T10:00:00Z".parse().unwrap())
+        );
+        assert_eq!(field("cf_parent").unwrap(), &CustomFieldValue::BugId(10));
+        assert_eq!(
+            field("cf_due").unwrap(),
+            &CustomFieldValue::Date("2024-04-01".parse().unwrap())
+        );
+        assert_eq!(field("cf_points").unwrap(), &CustomFieldValue::Integer(3));
+        // fields lacking metadata use their raw types
+        assert_eq!(
+            field("cf_unknown").unwrap(),
+            &CustomFieldValue::String("value".to_string())
+        );
+        // display names are used when available
+        let names: Vec<_> = bug
+            .custom_fields
+            .keys()
+            .map(|x| x.to_string())
+            .sorted()
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Due date",
+                "Notes",
+                "Packages",
+                "Parent bug",
+                "Points",
+                "Reviewed",
+                "cf_unknown"
+            ]
+        );
+
+        server.reset().await;
+
+        // time tracking fields
+        server
+            .respond(200, path.join("get/time-tracking.json"))
+            .await;
+        let bug = service.get([1]).send().await.unwrap().remove(0);
+        assert_eq!(bug.deadline, Some("2024-04-01".parse().unwrap()));
+        assert_eq!(bug.estimated_time, Some("10.5".parse().unwrap()));
+        assert_eq!(bug.actual_time, Some("4".parse().unwrap()));
+        assert_eq!(bug.remaining_time, Some("6.5".parse().unwrap()));
+
+        server.reset().await;
+
+        // JSON-RPC
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_match(
+                matchers::body_partial_json(json!({
+                    "method": "Bug.get",
+                    "params": [{
+                        "include_fields": ["_default", "tags"],
+                        "exclude_fields": ["update_token"],
+                    }],
+                })),
+                200,
+                path.join("get/rpc.json"),
+            )
+            .await;
+        server
+            .respond_rpc("Bug.comments", path.join("comment/rpc.json"))
+            .await;
+        server
+            .respond_match(
+                matchers::body_partial_json(json!({
+                    "method": "Bug.attachments",
+                    "params": [{"ids": ["1"], "exclude_fields": ["data"]}],
+                })),
+                200,
+                path.join("attachment/get/rpc.json"),
+            )
+            .await;
+        server
+            .respond_rpc("Bug.fields", path.join("fields/rpc.json"))
+            .await;
+        let bugs = service
+            .get([1])
+            .attachments(true)
+            .comments(true)
+            .send()
+            .await
+            .unwrap();
+        assert_ordered_eq!(bugs.iter().map(|x| x.id), [1]);
+        assert!(!bugs[0].attachments.is_empty());
+        assert_eq!(bugs[0].comments.len(), 7);
+
+        // REST endpoints aren't used
+        let requests = server.mock().received_requests().await.unwrap();
+        assert!(requests.iter().all(|x| x.url.path() == "/jsonrpc.cgi"));
     }
 }
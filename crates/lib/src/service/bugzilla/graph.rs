@@ -0,0 +1,462 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use futures_util::future::try_join_all;
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString, VariantNames};
+
+use crate::Error;
+use crate::objects::bugzilla::Bug;
+use crate::service::bugzilla::Bugzilla;
+use crate::traits::RequestSend;
+
+/// Maximum number of bugs fetched per request.
+const CHUNK_SIZE: usize = 100;
+
+/// Bug relation followed when traversing the graph.
+#[derive(
+    Display,
+    EnumIter,
+    EnumString,
+    VariantNames,
+    Deserialize,
+    Serialize,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Relation {
+    /// Bugs the bug depends on.
+    #[default]
+    Depends,
+    /// Bugs blocked by the bug.
+    Blocks,
+}
+
+/// Graph of related bugs.
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+pub struct Graph {
+    pub relation: Relation,
+    pub roots: Vec<u64>,
+    #[serde(serialize_with = "serialize_bugs")]
+    pub bugs: IndexMap<u64, Bug>,
+    /// Bugs with relations left unfetched due to depth limits.
+    #[serde(skip_serializing_if = "IndexSet::is_empty")]
+    pub truncated: IndexSet<u64>,
+}
+
+fn serialize_bugs<S>(bugs: &IndexMap<u64, Bug>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(bugs.values())
+}
+
+impl Graph {
+    /// Return the related bug IDs for a bug that exist in the graph.
+    pub fn children<'a>(&'a self, bug: &'a Bug) -> impl Iterator<Item = u64> + 'a {
+        let ids = match self.relation {
+            Relation::Depends => &bug.depends_on,
+            Relation::Blocks => &bug.blocks,
+        };
+        ids.iter()
+            .copied()
+            .filter(move |x| self.bugs.contains_key(x))
+    }
+
+    /// Return the graph edges including duplicate relations.
+    pub fn edges(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.bugs.values().flat_map(|bug| {
+            self.children(bug)
+                .chain(self.duplicate(bug))
+                .map(move |x| (bug.id, x))
+        })
+    }
+
+    /// Return the duplicate target for a bug if it exists in the graph.
+    fn duplicate(&self, bug: &Bug) -> Option<u64> {
+        bug.duplicate_of.filter(|x| self.bugs.contains_key(x))
+    }
+
+    /// Render the graph as an ASCII tree.
+    pub fn tree(&self) -> String {
+        let mut output = String::new();
+        let mut seen = HashSet::new();
+        for id in &self.roots {
+            let mut path = vec![];
+            self.tree_node(&mut output, *id, "", "", &mut path, &mut seen);
+        }
+        output
+    }
+
+    fn tree_node(
+        &self,
+        output: &mut String,
+        id: u64,
+        prefix: &str,
+        child_prefix: &str,
+        path: &mut Vec<u64>,
+        seen: &mut HashSet<u64>,
+    ) {
+        let bug = &self.bugs[&id];
+        write!(output, "{prefix}{}", label(bug)).unwrap();
+
+        // avoid infinite recursion and repeatedly rendering shared subtrees
+        let mut children: Vec<_> = self.children(bug).map(|x| (x, "")).collect();
+        children.extend(self.duplicate(bug).map(|x| (x, "duplicate of ")));
+        if path.contains(&id) {
+            writeln!(output, " (cycle)").unwrap();
+            return;
+        } else if !children.is_empty() && !seen.insert(id) {
+            writeln!(output, " (shown above)").unwrap();
+            return;
+        } else if self.truncated.contains(&id) {
+            writeln!(output, " ...").unwrap();
+        } else {
+            writeln!(output).unwrap();
+        }
+
+        path.push(id);
+        let count = children.len();
+        for (i, (child, kind)) in children.into_iter().enumerate() {
+            let (prefix, next) = if i + 1 == count {
+                (
+                    format!("{child_prefix}└── {kind}"),
+                    format!("{child_prefix}    "),
+                )
+            } else {
+                (
+                    format!("{child_prefix}├── {kind}"),
+                    format!("{child_prefix}│   "),
+                )
+            };
+            self.tree_node(output, child, &prefix, &next, path, seen);
+        }
+        path.pop();
+    }
+
+    /// Render the graph in Graphviz DOT format.
+    pub fn dot(&self) -> String {
+        let mut output = String::from("digraph bugs {\n");
+        for bug in self.bugs.values() {
+            let label = label(bug).replace('\\', "\\\\").replace('"', "\\\"");
+            write!(output, "  {} [label=\"{label}\"", bug.id).unwrap();
+            if bug.resolution.is_some() {
+                write!(output, ", style=filled, fillcolor=lightgray").unwrap();
+            }
+            writeln!(output, "];").unwrap();
+        }
+        for bug in self.bugs.values() {
+            for child in self.children(bug) {
+                writeln!(output, "  {} -> {child};", bug.id).unwrap();
+            }
+            if let Some(id) = self.duplicate(bug) {
+                writeln!(output, "  {} -> {id} [style=dashed];", bug.id).unwrap();
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+}
+
+/// Return the descriptive label for a bug.
+fn label(bug: &Bug) -> String {
+    let mut label = bug.id.to_string();
+    for value in [&bug.status, &bug.resolution, &bug.summary]
+        .into_iter()
+        .flatten()
+    {
+        write!(label, " {value}").unwrap();
+    }
+    label
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub ids: Vec<String>,
+    pub relation: Relation,
+    pub depth: Option<usize>,
+    pub resolved: bool,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+            relation: Default::default(),
+            depth: None,
+            resolved: true,
+        }
+    }
+
+    /// Set the relation to follow.
+    pub fn relation(&mut self, value: Relation) -> &mut Self {
+        self.relation = value;
+        self
+    }
+
+    /// Limit traversal depth relative to the root bugs.
+    pub fn depth(&mut self, value: usize) -> &mut Self {
+        self.depth = Some(value);
+        self
+    }
+
+    /// Enable or disable including resolved bugs.
+    ///
+    /// Root bugs are always included while relations of excluded bugs aren't
+    /// traversed.
+    pub fn resolved(&mut self, include: bool) -> &mut Self {
+        self.resolved = include;
+        self
+    }
+
+    /// Fetch bugs concurrently in chunks.
+    async fn fetch(&self, ids: &[String]) -> crate::Result<Vec<Bug>> {
+        let futures = ids
+            .chunks(CHUNK_SIZE)
+            .map(|ids| async move { self.service.get(ids).send().await });
+        let bugs = try_join_all(futures).await?;
+        Ok(bugs.into_iter().flatten().collect())
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Graph;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let mut graph = Graph {
+            relation: self.relation,
+            ..Default::default()
+        };
+        let mut seen = HashSet::new();
+        let mut ids = self.ids.clone();
+        let mut depth = 0;
+
+        while !ids.is_empty() {
+            let mut next = IndexSet::new();
+            for bug in self.fetch(&ids).await? {
+                if depth == 0 {
+                    graph.roots.push(bug.id);
+                } else if !self.resolved && bug.resolution.is_some() {
+                    continue;
+                }
+
+                // duplicate targets are followed regardless of the relation
+                let related: Vec<_> = match self.relation {
+                    Relation::Depends => &bug.depends_on,
+                    Relation::Blocks => &bug.blocks,
+                }
+                .iter()
+                .copied()
+                .chain(bug.duplicate_of)
+                .collect();
+
+                if self.depth.is_some_and(|x| depth >= x) {
+                    if !related.is_empty() {
+                        graph.truncated.insert(bug.id);
+                    }
+                } else {
+                    next.extend(related.into_iter().filter(|x| seen.insert(*x)));
+                }
+
+                seen.insert(bug.id);
+                graph.bugs.insert(bug.id, bug);
+            }
+
+            ids = next
+                .into_iter()
+                .filter(|x| !graph.bugs.contains_key(x))
+                .map(|x| x.to_string())
+                .collect();
+            depth += 1;
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use indoc::indoc;
+    use wiremock::{Mock, ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    /// Start a server responding with the requested bugs.
+    async fn server() -> TestServer {
+        let path = TESTDATA_PATH.join("bugzilla/graph/bugs.json");
+        let data = std::fs::read_to_string(path).unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        let server = TestServer::new().await;
+        let respond = move |req: &wiremock::Request| {
+            let mut ids: Vec<_> = req
+                .url
+                .query_pairs()
+                .filter(|(k, _)| k == "ids")
+                .map(|(_, v)| v.to_string())
+                .collect();
+            ids.push(
+                req.url
+                    .path_segments()
+                    .unwrap()
+                    .next_back()
+                    .unwrap()
+                    .to_string(),
+            );
+            let bugs: Vec<_> = data["bugs"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|x| ids.contains(&x["id"].to_string()))
+                .collect();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"bugs": bugs}))
+        };
+        Mock::given(matchers::path_regex(r"^/rest/bug/\d+$"))
+            .respond_with(respond)
+            .mount(server.mock())
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let server = server().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u32>::new();
+        let err = service.graph(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // dependencies including cycles
+        let graph = service.graph([1]).send().await.unwrap();
+        assert_eq!(graph.roots, [1]);
+        assert_ordered_eq!(graph.bugs.keys().copied(), [1, 2, 3, 4, 5]);
+        assert!(graph.truncated.is_empty());
+        assert_ordered_eq!(
+            graph.edges(),
+            [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (5, 2)]
+        );
+
+        // depth limit
+        let graph = service.graph([1]).depth(1).send().await.unwrap();
+        assert_ordered_eq!(graph.bugs.keys().copied(), [1, 2, 3]);
+        assert_ordered_eq!(graph.truncated.iter().copied(), [2, 3]);
+
+        // resolved bugs and their relations are skipped
+        let graph = service.graph([1]).resolved(false).send().await.unwrap();
+        assert_ordered_eq!(graph.bugs.keys().copied(), [1, 2, 4, 5]);
+
+        // blockers
+        let graph = service
+            .graph([4])
+            .relation(Relation::Blocks)
+            .send()
+            .await
+            .unwrap();
+        assert_ordered_eq!(graph.bugs.keys().copied(), [4, 2, 3, 1, 5]);
+
+        // duplicates only reachable via their duplicate relation
+        for relation in [Relation::Depends, Relation::Blocks] {
+            let graph = service.graph([6]).relation(relation).send().await.unwrap();
+            assert_ordered_eq!(graph.bugs.keys().copied(), [6, 7]);
+            assert_ordered_eq!(graph.edges(), [(6, 7)]);
+        }
+
+        // duplicate relations are truncated by depth limits
+        let graph = service.graph([6]).depth(0).send().await.unwrap();
+        assert_ordered_eq!(graph.bugs.keys().copied(), [6]);
+        assert_ordered_eq!(graph.truncated.iter().copied(), [6]);
+    }
+
+    #[tokio::test]
+    async fn render() {
+        let server = server().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+        let graph = service.graph([1]).send().await.unwrap();
+
+        assert_eq!(
+            graph.tree(),
+            indoc! {"
+                1 NEW Tracker
+                ├── 2 ASSIGNED Parser rewrite
+                │   └── 4 NEW Lexer support
+                │       └── 5 NEW Token cache
+                │           └── 2 ASSIGNED Parser rewrite (cycle)
+                └── 3 RESOLVED FIXED Old \"parser\" cleanup
+                    └── 4 NEW Lexer support (shown above)
+            "}
+        );
+
+        assert_eq!(
+            graph.dot(),
+            indoc! {r#"
+                digraph bugs {
+                  1 [label="1 NEW Tracker"];
+                  2 [label="2 ASSIGNED Parser rewrite"];
+                  3 [label="3 RESOLVED FIXED Old \"parser\" cleanup", style=filled, fillcolor=lightgray];
+                  4 [label="4 NEW Lexer support"];
+                  5 [label="5 NEW Token cache"];
+                  1 -> 2;
+                  1 -> 3;
+                  2 -> 4;
+                  3 -> 4;
+                  4 -> 5;
+                  5 -> 2;
+                }
+            "#}
+        );
+
+        // truncated bugs
+        let graph = service.graph([1]).depth(1).send().await.unwrap();
+        assert_eq!(
+            graph.tree(),
+            indoc! {"
+                1 NEW Tracker
+                ├── 2 ASSIGNED Parser rewrite ...
+                └── 3 RESOLVED FIXED Old \"parser\" cleanup ...
+            "}
+        );
+
+        // duplicates
+        let graph = service.graph([6]).send().await.unwrap();
+        assert_eq!(
+            graph.tree(),
+            indoc! {"
+                6 RESOLVED DUPLICATE Crash on startup
+                └── duplicate of 7 NEW Startup crash
+            "}
+        );
+        assert_eq!(
+            graph.dot(),
+            indoc! {r#"
+                digraph bugs {
+                  6 [label="6 RESOLVED DUPLICATE Crash on startup", style=filled, fillcolor=lightgray];
+                  7 [label="7 NEW Startup crash"];
+                  6 -> 7 [style=dashed];
+                }
+            "#}
+        );
+    }
+}
@@ -1,9 +1,9 @@
-use serde_json::Value;
+use serde_json::{Value, json};
 use url::Url;
 
 use crate::Error;
 use crate::objects::bugzilla::Event;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::time::TimeDeltaOrStatic;
 use crate::traits::{InjectAuth, RequestSend, WebService};
 
@@ -71,13 +71,18 @@ impl RequestSend for Request {
     type Output = Vec<Vec<Event>>;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let request = self
-            .service
-            .client()
-            .get(self.url()?)
-            .auth_optional(&self.service);
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let url = self.url()?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            let mut params = json!({"ids": self.ids});
+            if let Some(value) = self.params.created_after.as_ref() {
+                params["new_since"] = value.as_ref().into();
+            }
+            self.service.rpc("Bug.history", params, false).await?
+        } else {
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let Value::Array(bugs) = data["bugs"].take() else {
             return Err(Error::InvalidResponse("history request".to_string()));
         };
@@ -178,5 +183,19 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(changes[0].len(), 3);
+
+        server.reset().await;
+
+        // JSON-RPC
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_rpc("Bug.history", path.join("history/rpc.json"))
+            .await;
+        let changes = service.history([1]).send().await.unwrap();
+        assert_eq!(changes[0].len(), 3);
     }
 }
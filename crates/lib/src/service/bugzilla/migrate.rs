@@ -0,0 +1,668 @@
+use std::fmt;
+use std::fs;
+
+use camino::Utf8Path;
+use indexmap::{IndexMap, IndexSet};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString, VariantNames};
+
+use crate::Error;
+use crate::objects::bugzilla::{Bug, CustomFieldValue};
+use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::attachment::create::Attachment;
+use crate::service::bugzilla::create;
+use crate::service::bugzilla::update::SetChange;
+use crate::traits::{RequestSend, WebClient};
+
+/// Fields copied to migrated bugs by default.
+const DEFAULT_FIELDS: &[&str] = &[
+    "summary",
+    "product",
+    "component",
+    "version",
+    "platform",
+    "os",
+    "priority",
+    "severity",
+    "keywords",
+    "url",
+    "whiteboard",
+];
+
+/// Fields using multiple values.
+const LIST_FIELDS: &[&str] = &["alias", "cc", "groups", "keywords", "see_also"];
+
+/// Field mapping rules for migrating bugs to a target connection.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Additional fields to copy, e.g. cc or custom fields.
+    #[serde(default)]
+    pub copy: IndexSet<String>,
+
+    /// Fields to skip copying.
+    #[serde(default)]
+    pub skip: IndexSet<String>,
+
+    /// Value mappings for copied fields with empty values dropping them.
+    #[serde(default)]
+    pub map: IndexMap<String, IndexMap<String, String>>,
+
+    /// Static field values overriding copied values.
+    #[serde(default)]
+    pub set: IndexMap<String, String>,
+}
+
+impl Rules {
+    /// Return the creation parameters for a bug's mapped field values.
+    fn params(&self, bug: &Bug) -> crate::Result<create::Parameters> {
+        let mut values = IndexMap::new();
+        for (name, value) in fields(bug) {
+            if !(DEFAULT_FIELDS.contains(&name.as_str()) || self.copy.contains(&name))
+                || self.skip.contains(&name)
+            {
+                continue;
+            }
+
+            let value: Vec<_> = match self.map.get(&name) {
+                Some(map) => value
+                    .into_iter()
+                    .map(|x| map.get(&x).cloned().unwrap_or(x))
+                    .filter(|x| !x.is_empty())
+                    .collect(),
+                None => value,
+            };
+            if !value.is_empty() {
+                values.insert(name, value);
+            }
+        }
+
+        for (name, value) in &self.set {
+            let value = if LIST_FIELDS.contains(&name.as_str()) {
+                value.split(',').map(|x| x.trim().to_string()).collect()
+            } else {
+                vec![value.clone()]
+            };
+            values.insert(name.clone(), value);
+        }
+
+        let mut params = create::Parameters::default();
+        for (name, value) in values {
+            let single = value[0].clone();
+            match name.as_str() {
+                "alias" => params.alias = Some(value),
+                "assignee" => params.assignee = Some(single),
+                "cc" => params.cc = Some(value),
+                "component" => params.component = Some(single),
+                "groups" => params.groups = Some(value),
+                "keywords" => params.keywords = Some(value),
+                "os" => params.os = Some(single),
+                "platform" => params.platform = Some(single),
+                "priority" => params.priority = Some(single),
+                "product" => params.product = Some(single),
+                "qa" => params.qa = Some(single),
+                "see_also" => params.see_also = Some(value),
+                "severity" => params.severity = Some(single),
+                "summary" => params.summary = Some(single),
+                "target" => params.target = Some(single),
+                "url" => params.url = Some(single),
+                "version" => params.version = Some(single),
+                "whiteboard" => params.whiteboard = Some(single),
+                _ if name.starts_with("cf_") => {
+                    params
+                        .custom_fields
+                        .get_or_insert_with(Default::default)
+                        .insert(name, value.join(","));
+                }
+                _ => {
+                    return Err(Error::InvalidValue(format!(
+                        "unknown migration field: {name}"
+                    )));
+                }
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+/// Return the migratable field values for a bug.
+fn fields(bug: &Bug) -> IndexMap<String, Vec<String>> {
+    let single = |name: &str, value: &Option<String>| {
+        value.as_ref().map(|x| (name.to_string(), vec![x.clone()]))
+    };
+    let list = |name: &str, values: &IndexSet<String>| {
+        Some((name.to_string(), values.iter().cloned().collect()))
+    };
+
+    [
+        single("summary", &bug.summary),
+        single("product", &bug.product),
+        single("component", &bug.component),
+        single("version", &bug.version),
+        single("platform", &bug.platform),
+        single("os", &bug.op_sys),
+        single("priority", &bug.priority),
+        single("severity", &bug.severity),
+        list("keywords", &bug.keywords),
+        single("url", &bug.url),
+        single("whiteboard", &bug.whiteboard),
+        list("alias", &bug.alias),
+        single("assignee", &bug.assigned_to),
+        list("cc", &bug.cc),
+        list("groups", &bug.groups),
+        single("qa", &bug.qa_contact),
+        list("see_also", &bug.see_also),
+        single("target", &bug.target),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(bug.custom_fields.iter().map(|(name, value)| {
+        let values = match value {
+            CustomFieldValue::MultiValue(values) => values.clone(),
+            value => vec![value.to_string()],
+        };
+        (name.id.clone(), values)
+    }))
+    .filter(|(_, values)| !values.is_empty())
+    .collect()
+}
+
+/// Cross-reference variants linking source and migrated bugs.
+#[derive(
+    Display, EnumIter, EnumString, VariantNames, Debug, Default, PartialEq, Eq, Clone, Copy,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Reference {
+    /// Add a comment to the source bug.
+    #[default]
+    Comment,
+    /// Add the bug URLs to the see also fields of both bugs.
+    SeeAlso,
+}
+
+/// Bug migrated to another connection.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Migration {
+    /// Source bug ID.
+    pub id: u64,
+    /// Migrated bug ID.
+    pub target: u64,
+    /// Migrated bug URL.
+    pub url: String,
+    /// Migrated attachment IDs.
+    pub attachments: Vec<u64>,
+}
+
+impl fmt::Display for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.id, self.url)
+    }
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    target: Bugzilla,
+    pub ids: Vec<String>,
+    pub rules: Rules,
+    pub reference: Reference,
+    pub close: Option<(String, Option<String>)>,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, target: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        // use configured rules for the target connection
+        let rules = service
+            .config()
+            .migrate
+            .get(target.name())
+            .cloned()
+            .unwrap_or_default();
+
+        Self {
+            service: service.clone(),
+            target: target.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+            rules,
+            reference: Default::default(),
+            close: None,
+        }
+    }
+
+    /// Set the field mapping rules.
+    pub fn rules(&mut self, value: Rules) -> &mut Self {
+        self.rules = value;
+        self
+    }
+
+    /// Set the cross-reference type.
+    pub fn reference(&mut self, value: Reference) -> &mut Self {
+        self.reference = value;
+        self
+    }
+
+    /// Close source bugs using a given status and optional resolution after migrating.
+    pub fn close<S: Into<String>>(&mut self, status: S, resolution: Option<S>) -> &mut Self {
+        self.close = Some((status.into(), resolution.map(Into::into)));
+        self
+    }
+
+    /// Create a quoted transcript of a bug's public comments.
+    fn transcript(&self, bug: &Bug) -> String {
+        let mut data = vec![format!("Migrated from {}", self.service.item_url(bug.id))];
+        for comment in bug.comments.iter().filter(|x| !x.is_private) {
+            let created = comment.created.format("%Y-%m-%d %H:%M:%S UTC");
+            let header = if comment.count == 0 {
+                format!("Description by {} on {created}:", comment.creator)
+            } else {
+                format!(
+                    "Comment #{} by {} on {created}:",
+                    comment.count, comment.creator
+                )
+            };
+            let text = comment
+                .text
+                .lines()
+                .map(|x| format!("> {x}").trim_end().to_string())
+                .join("\n");
+            data.push(format!("{header}\n{text}"));
+        }
+        data.join("\n\n")
+    }
+
+    /// Return the ID of a previously migrated bug referenced by a source bug.
+    fn migrated(&self, bug: &Bug) -> Option<u64> {
+        let prefix = self.target.item_url("");
+        let comments = bug
+            .comments
+            .iter()
+            .filter_map(|x| x.text.strip_prefix("Migrated to "));
+        bug.see_also
+            .iter()
+            .map(|x| x.as_str())
+            .chain(comments)
+            .find_map(|x| x.trim().strip_prefix(&prefix)?.parse().ok())
+    }
+
+    /// Copy the public, non-obsolete attachments of a bug to its migrated bug.
+    ///
+    /// Attachments already existing on the migrated bug with matching names and
+    /// descriptions are skipped.
+    async fn attachments(
+        &self,
+        bug: &Bug,
+        target: u64,
+        existing: bool,
+        dir: &Utf8Path,
+    ) -> crate::Result<Vec<u64>> {
+        let mut attachments: Vec<_> = bug
+            .attachments
+            .iter()
+            .filter(|x| !x.is_private && !x.is_obsolete && !x.is_deleted())
+            .collect();
+        if existing && !attachments.is_empty() {
+            let mut request = self.target.attachment_get_item([target]);
+            request.data(false);
+            let copied: IndexSet<_> = request
+                .send()
+                .await?
+                .into_iter()
+                .flatten()
+                .map(|x| (x.file_name, x.summary))
+                .collect();
+            attachments.retain(|x| !copied.contains(&(x.file_name.clone(), x.summary.clone())));
+        }
+        let ids: Vec<_> = attachments.iter().map(|x| x.id).collect();
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut attachments = vec![];
+        for attachment in self.service.attachment_get(ids).send().await? {
+            let path = dir.join(attachment.id.to_string());
+            fs::create_dir_all(&path)
+                .and_then(|_| fs::write(path.join("data"), attachment.as_ref()))
+                .map_err(|e| Error::IO(format!("failed saving attachment: {e}")))?;
+            let comment = format!(
+                "Migrated from attachment {} by {}",
+                attachment.id, attachment.creator
+            );
+            attachments.push(
+                Attachment::new(path.join("data"))
+                    .name(Some(&attachment.file_name))
+                    .description(Some(&attachment.summary))
+                    .mime_type(Some(&attachment.content_type))
+                    .is_patch(Some(attachment.is_patch))
+                    .comment(Some(comment)),
+            );
+        }
+
+        let ids = self
+            .target
+            .attachment_create([target])
+            .attachments(attachments)
+            .send()
+            .await?;
+        Ok(ids.into_iter().flatten().collect())
+    }
+
+    /// Migrate a bug, returning the migrated bug.
+    ///
+    /// The source bug is cross-referenced directly after creating the migrated bug so
+    /// retrying failed migrations reuses it instead of creating duplicates.
+    async fn migrate(&self, bug: &Bug, dir: &Utf8Path) -> crate::Result<Migration> {
+        let existing = self.migrated(bug);
+        let target = match existing {
+            Some(id) => id,
+            None => {
+                let mut request = self.target.create();
+                request.params = self.rules.params(bug)?;
+                request.params.description = Some(self.transcript(bug));
+                if self.reference == Reference::SeeAlso {
+                    request
+                        .params
+                        .see_also
+                        .get_or_insert_with(Default::default)
+                        .push(self.service.item_url(bug.id));
+                }
+                request.send().await?
+            }
+        };
+        let url = self.target.item_url(target);
+
+        // cross-reference and optionally close the source bug
+        let mut request = self.service.update([bug.id]);
+        if existing.is_none() {
+            match self.reference {
+                Reference::Comment => request.params.comment = Some(format!("Migrated to {url}")),
+                Reference::SeeAlso => {
+                    request.params.see_also = Some(vec![SetChange::Add(url.clone())])
+                }
+            }
+        }
+        if let Some((status, resolution)) = &self.close
+            && bug.status.as_ref() != Some(status)
+        {
+            request.params.status = Some(status.clone());
+            request.params.resolution = resolution.clone();
+        }
+        if request.params != Default::default() {
+            request.send().await?;
+        }
+
+        let attachments = self
+            .attachments(bug, target, existing.is_some(), dir)
+            .await?;
+
+        Ok(Migration {
+            id: bug.id,
+            target,
+            url,
+            attachments,
+        })
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Migration>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.service == self.target {
+            return Err(Error::InvalidRequest(
+                "source and target connections are the same".to_string(),
+            ));
+        }
+
+        let bugs = self
+            .service
+            .get(&self.ids)
+            .comments(true)
+            .attachments(true)
+            .send()
+            .await?;
+
+        // temporary directory used for attachment files
+        let temp_dir = tempfile::tempdir()
+            .map_err(|e| Error::IO(format!("failed acquiring temporary dir: {e}")))?;
+        let temp_dir_path = Utf8Path::from_path(temp_dir.path())
+            .ok_or_else(|| Error::IO("non-unicode temporary dir path".to_string()))?;
+
+        let mut migrations = vec![];
+        for bug in &bugs {
+            migrations.push(self.migrate(bug, temp_dir_path).await?);
+        }
+
+        Ok(migrations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use serde_json::Value;
+
+    use crate::test::*;
+
+    use super::*;
+
+    /// Return a request matcher for a given method and path.
+    fn matcher(method: &'static str, path: &'static str) -> impl Fn(&wiremock::Request) -> bool {
+        move |req: &wiremock::Request| req.method.as_str() == method && req.url.path() == path
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let source_server = TestServer::new().await;
+        let target_server = TestServer::new().await;
+        let builder = |uri: &str| {
+            Bugzilla::builder(uri)
+                .unwrap()
+                .user("user")
+                .password("pass")
+                .build()
+                .unwrap()
+        };
+        let source = builder(source_server.uri());
+        let target = builder(target_server.uri());
+
+        // same connection
+        let err = source.migrate(&source, [1]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "source and target connections are the same");
+
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/1"),
+                200,
+                path.join("get/single-bug.json"),
+            )
+            .await;
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/1/comment"),
+                200,
+                path.join("migrate/comments.json"),
+            )
+            .await;
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/1/attachment"),
+                200,
+                path.join("attachment/get/bug-with-attachments.json"),
+            )
+            .await;
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/attachment/123"),
+                200,
+                path.join("migrate/attachments.json"),
+            )
+            .await;
+        let target_url = format!(
+            "{}/show_bug.cgi?id=123",
+            target.base().as_str().trim_end_matches('/')
+        );
+        let comment = format!("Migrated to {target_url}");
+        let updated = move |req: &wiremock::Request| {
+            let body: Value = serde_json::from_slice(&req.body).unwrap_or_default();
+            req.method.as_str() == "PUT"
+                && body["comment"]["body"] == comment
+                && body["status"] == "RESOLVED"
+                && body["resolution"] == "MOVED"
+        };
+        source_server
+            .respond_match(updated, 200, path.join("update/summary.json"))
+            .await;
+
+        let source_url = source.item_url(1);
+        let created = move |req: &wiremock::Request| {
+            let body: Value = serde_json::from_slice(&req.body).unwrap_or_default();
+            let description = indoc::formatdoc! {"
+                Migrated from {source_url}
+
+                Description by user1@bugbite.test on 2024-03-13 14:02:53 UTC:
+                > description
+                >
+                > more details
+
+                Comment #2 by user2@bugbite.test on 2024-03-14 09:10:05 UTC:
+                > public comment"};
+            matcher("POST", "/rest/bug")(req)
+                && body["summary"] == "new summary"
+                && body["product"] == "upstream"
+                && body["severity"] == "minor"
+                && body["cc"] == serde_json::json!(["person1"])
+                && body["whiteboard"].is_null()
+                && body["description"] == description
+        };
+        target_server
+            .respond_match(created, 200, path.join("create/creation.json"))
+            .await;
+        target_server
+            .respond_match(
+                matcher("POST", "/rest/bug/123/attachment"),
+                200,
+                path.join("attachment/create/single.json"),
+            )
+            .await;
+
+        let rules = Rules {
+            copy: ["cc".to_string()].into(),
+            skip: ["whiteboard".to_string()].into(),
+            map: [
+                (
+                    "severity".to_string(),
+                    [("normal".to_string(), "minor".to_string())].into(),
+                ),
+                (
+                    "cc".to_string(),
+                    [("person2".to_string(), String::new())].into(),
+                ),
+            ]
+            .into(),
+            set: [("product".to_string(), "upstream".to_string())].into(),
+        };
+        let migrations = source
+            .migrate(&target, [1])
+            .rules(rules)
+            .close("RESOLVED", Some("MOVED"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            migrations,
+            [Migration {
+                id: 1,
+                target: 123,
+                url: target_url.clone(),
+                attachments: vec![123, 123],
+            }]
+        );
+        assert_eq!(migrations[0].to_string(), format!("1 -> {target_url}"));
+
+        // unknown fields
+        let rules = Rules {
+            set: [("unknown".to_string(), "value".to_string())].into(),
+            ..Default::default()
+        };
+        let err = source
+            .migrate(&target, [1])
+            .rules(rules)
+            .send()
+            .await
+            .unwrap_err();
+        assert_matches!(err, Error::InvalidValue(_));
+        assert_err_re!(err, "unknown migration field: unknown");
+
+        // retries reuse referenced bugs, skipping existing attachments
+        source_server.reset().await;
+        target_server.reset().await;
+        let dir = tempfile::tempdir().unwrap();
+        let comments = Utf8Path::from_path(dir.path())
+            .unwrap()
+            .join("comments.json");
+        let data = fs::read_to_string(path.join("migrate/comments.json")).unwrap();
+        let mut data: Value = serde_json::from_str(&data).unwrap();
+        let values = data["bugs"]["1"]["comments"].as_array_mut().unwrap();
+        let mut comment = values.last().unwrap().clone();
+        comment["count"] = 3.into();
+        comment["text"] = format!("Migrated to {target_url}").into();
+        values.push(comment);
+        fs::write(&comments, data.to_string()).unwrap();
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/1"),
+                200,
+                path.join("get/single-bug.json"),
+            )
+            .await;
+        source_server
+            .respond_match(matcher("GET", "/rest/bug/1/comment"), 200, &comments)
+            .await;
+        source_server
+            .respond_match(
+                matcher("GET", "/rest/bug/1/attachment"),
+                200,
+                path.join("attachment/get/bug-with-attachments.json"),
+            )
+            .await;
+        let closed = move |req: &wiremock::Request| {
+            let body: Value = serde_json::from_slice(&req.body).unwrap_or_default();
+            req.method.as_str() == "PUT"
+                && body["comment"].is_null()
+                && body["status"] == "RESOLVED"
+        };
+        source_server
+            .respond_match(closed, 200, path.join("update/summary.json"))
+            .await;
+        target_server
+            .respond_match(
+                matcher("GET", "/rest/bug/123/attachment"),
+                200,
+                path.join("attachment/get/bug-with-attachments.json"),
+            )
+            .await;
+        let migrations = source
+            .migrate(&target, [1])
+            .close("RESOLVED", Some("MOVED"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            migrations,
+            [Migration {
+                id: 1,
+                target: 123,
+                url: target_url.clone(),
+                attachments: vec![],
+            }]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString, VariantNames};
+use url::Url;
+
+use crate::Error;
+use crate::objects::bugzilla::Product;
+use crate::service::bugzilla::Bugzilla;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+/// Product variants relative to the current user.
+#[derive(
+    Display,
+    EnumIter,
+    EnumString,
+    VariantNames,
+    Deserialize,
+    Serialize,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ProductType {
+    /// Products the user can search or enter bugs in.
+    #[default]
+    Accessible,
+    /// Products the user can enter bugs in.
+    Enterable,
+    /// Products the user can search in.
+    Selectable,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub ids: Vec<String>,
+    pub kind: ProductType,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+            kind: Default::default(),
+        }
+    }
+
+    fn url(&self) -> crate::Result<Url> {
+        let mut url = self.service.config().base.join("rest/product")?;
+
+        if self.ids.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("type", &self.kind.to_string());
+        }
+
+        for id in &self.ids {
+            let kind = if id.parse::<u64>().is_ok() {
+                "ids"
+            } else {
+                "names"
+            };
+            url.query_pairs_mut().append_pair(kind, id);
+        }
+
+        Ok(url)
+    }
+
+    /// Set the product variant to query when no IDs are specified.
+    pub fn kind(&mut self, value: ProductType) -> &mut Self {
+        self.kind = value;
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Product>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let request = self
+            .service
+            .client()
+            .get(self.url()?)
+            .auth_optional(&self.service);
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        let mut products: Vec<Product> = serde_json::from_value(data["products"].take())
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing products: {e}")))?;
+
+        // Bugzilla silently skips nonexistent products
+        for id in &self.ids {
+            if !products
+                .iter()
+                .any(|x| &x.name == id || &x.id.to_string() == id)
+            {
+                return Err(Error::InvalidValue(format!("nonexistent product: {id}")));
+            }
+        }
+
+        products.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(products)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::matchers;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // accessible products by default
+        server
+            .respond_match(
+                matchers::query_param("type", "accessible"),
+                200,
+                path.join("product/products.json"),
+            )
+            .await;
+        let products = service.product(Vec::<u64>::new()).send().await.unwrap();
+        assert_ordered_eq!(
+            products.iter().map(|x| x.name.as_str()),
+            ["Client", "Server"]
+        );
+
+        // specific products
+        server.reset().await;
+        server
+            .respond(200, path.join("product/products.json"))
+            .await;
+        let products = service.product(["Client", "2"]).send().await.unwrap();
+        assert_eq!(products.len(), 2);
+        let product = &products[0];
+        let component = product.component("CLI").unwrap();
+        assert_eq!(
+            component.default_assigned_to.as_deref(),
+            Some("cli@bugbite.test")
+        );
+        assert!(component.default_qa_contact.is_none());
+
+        // nonexistent products
+        let err = service.product(["Nonexistent"]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidValue(_));
+        assert_err_re!(err, "nonexistent product: Nonexistent");
+    }
+
+    #[tokio::test]
+    async fn validate() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+        server
+            .respond(200, path.join("product/products.json"))
+            .await;
+        let product = service.product(["Client"]).send().await.unwrap().remove(0);
+
+        // valid
+        product.validate(None, None, None).unwrap();
+        product
+            .validate(Some("CLI"), Some("1.0"), Some("2.0"))
+            .unwrap();
+
+        // invalid
+        for (component, version, target, msg) in [
+            (
+                Some("GUI"),
+                None,
+                None,
+                "inactive component for Client: GUI",
+            ),
+            (Some("TUI"), None, None, "invalid component for Client: TUI"),
+            (None, Some("0.1"), None, "invalid version for Client: 0.1"),
+            (None, Some("0.9"), None, "invalid version for Client: 0.9"),
+            (None, None, Some("3.0"), "invalid target for Client: 3.0"),
+        ] {
+            let err = product.validate(component, version, target).unwrap_err();
+            assert_err_re!(err, msg);
+        }
+    }
+}
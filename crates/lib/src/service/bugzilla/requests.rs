@@ -0,0 +1,297 @@
+use chrono::prelude::*;
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::{Display, EnumString, VariantNames};
+
+use crate::Error;
+use crate::objects::bugzilla::{Bug, FlagStatus};
+use crate::service::bugzilla::{BugField, Bugzilla};
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+/// Flags queried by default.
+pub const DEFAULT_FLAGS: [&str; 3] = ["needinfo", "review", "feedback"];
+
+/// Flag request direction relative to the user.
+#[derive(
+    Display,
+    EnumString,
+    VariantNames,
+    Deserialize,
+    Serialize,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Direction {
+    /// Requests addressed to the user.
+    Incoming,
+    /// Requests made by the user.
+    Outgoing,
+}
+
+/// Pending flag request.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct FlagRequest {
+    pub bug: u64,
+    pub summary: Option<String>,
+    pub direction: Direction,
+    pub flag: String,
+    pub setter: String,
+    pub requestee: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+impl FlagRequest {
+    /// Return the time elapsed since the request was made.
+    pub fn age(&self) -> chrono::TimeDelta {
+        Utc::now() - self.created
+    }
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub user: Option<String>,
+    pub flags: Vec<String>,
+}
+
+impl Request {
+    pub(super) fn new(service: &Bugzilla) -> Self {
+        Self {
+            service: service.clone(),
+            user: None,
+            flags: DEFAULT_FLAGS.iter().map(|x| x.to_string()).collect(),
+        }
+    }
+
+    /// Query requests for a user, defaults to the configured user.
+    pub fn user<S>(&mut self, value: S) -> &mut Self
+    where
+        S: std::fmt::Display,
+    {
+        self.user = Some(value.to_string());
+        self
+    }
+
+    /// Restrict requests to the given flag names.
+    pub fn flags<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        self.flags = values.into_iter().map(|x| x.to_string()).collect();
+        self
+    }
+
+    /// Return the user to query requests for.
+    ///
+    /// The `@me` alias and unspecified users resolve to the configured user, falling back
+    /// to querying the current user when only an API key is configured.
+    async fn resolve_user(&self) -> crate::Result<String> {
+        let user = self.user.as_deref().unwrap_or("@me");
+        let user = self.service.replace_user_alias(user);
+        if user != "@me" {
+            return Ok(user.to_string());
+        } else if self.service.config().auth.key.is_none() {
+            return Err(Error::InvalidRequest("no user specified".to_string()));
+        }
+
+        let url = self.service.config().base.join("rest/whoami")?;
+        let request = self.service.client().get(url).auth(&self.service)?;
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        let Value::String(name) = data["name"].take() else {
+            return Err(Error::InvalidResponse("whoami request".to_string()));
+        };
+        Ok(name)
+    }
+
+    /// Extract matching flag requests from bugs.
+    fn requests(&self, bugs: Vec<Bug>, user: &str, direction: Direction) -> Vec<FlagRequest> {
+        let mut requests = vec![];
+        for bug in bugs {
+            for flag in &bug.flags {
+                if flag.flag.status != FlagStatus::Requested
+                    || !self.flags.iter().any(|x| x == &flag.flag.name)
+                {
+                    continue;
+                }
+
+                let matches = match direction {
+                    Direction::Incoming => flag.flag.requestee.as_deref() == Some(user),
+                    Direction::Outgoing => flag.setter == user,
+                };
+
+                if matches {
+                    requests.push(FlagRequest {
+                        bug: bug.id,
+                        summary: bug.summary.clone(),
+                        direction,
+                        flag: flag.flag.name.clone(),
+                        setter: flag.setter.clone(),
+                        requestee: flag.flag.requestee.clone(),
+                        created: flag.created,
+                    });
+                }
+            }
+        }
+        requests
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<FlagRequest>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.flags.is_empty() {
+            return Err(Error::InvalidRequest("no flags specified".to_string()));
+        }
+
+        let user = &self.resolve_user().await?;
+
+        let fields = [BugField::Id, BugField::Summary, BugField::Flags];
+        let incoming = self
+            .service
+            .search()
+            .requestee([user])
+            .fields(fields)
+            .stream();
+        let outgoing = self
+            .service
+            .search()
+            .flagger([user])
+            .fields(fields)
+            .stream();
+        let (incoming, outgoing): (Vec<_>, Vec<_>) =
+            futures_util::try_join!(incoming.try_collect(), outgoing.try_collect())?;
+
+        let mut requests = self.requests(incoming, user, Direction::Incoming);
+        requests.extend(self.requests(outgoing, user, Direction::Outgoing));
+        requests.sort_by(|a, b| {
+            (a.direction, &a.flag, a.created).cmp(&(b.direction, &b.flag, b.created))
+        });
+        Ok(requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+
+        // no user
+        let service = Bugzilla::new(server.uri()).unwrap();
+        let err = service.requests().send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no user specified");
+
+        // no flags
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user@bugbite.test")
+            .build()
+            .unwrap();
+        let err = service
+            .requests()
+            .flags(Vec::<String>::new())
+            .send()
+            .await
+            .unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no flags specified");
+
+        let requestee = |req: &wiremock::Request| {
+            req.url
+                .query_pairs()
+                .any(|(_, v)| v == "requestees.login_name")
+        };
+        server
+            .respond_match(requestee, 200, path.join("requests/incoming.json"))
+            .await;
+        server
+            .respond(200, path.join("requests/outgoing.json"))
+            .await;
+
+        // default flags
+        let requests = service.requests().send().await.unwrap();
+        assert_ordered_eq!(
+            requests
+                .iter()
+                .map(|x| (x.direction, x.flag.as_str(), x.bug)),
+            [
+                (Direction::Incoming, "needinfo", 10),
+                (Direction::Incoming, "needinfo", 11),
+                (Direction::Incoming, "review", 11),
+                (Direction::Outgoing, "feedback", 12),
+                (Direction::Outgoing, "review", 12),
+            ]
+        );
+
+        // custom flags and user
+        let requests = service
+            .requests()
+            .user("other@bugbite.test")
+            .flags(["needinfo", "feedback"])
+            .send()
+            .await
+            .unwrap();
+        assert_ordered_eq!(
+            requests
+                .iter()
+                .map(|x| (x.direction, x.flag.as_str(), x.bug)),
+            [
+                (Direction::Incoming, "feedback", 11),
+                (Direction::Outgoing, "needinfo", 13),
+            ]
+        );
+
+        // user alias
+        let requests = service.requests().user("@me").send().await.unwrap();
+        assert_eq!(requests.len(), 5);
+
+        // current user is queried when only an API key is configured
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .key("key")
+            .build()
+            .unwrap();
+        server.reset().await;
+        let template = ResponseTemplate::new(200)
+            .set_body_json(serde_json::json!({"id": 1, "name": "user@bugbite.test"}));
+        server
+            .respond_custom(matchers::path("/rest/whoami"), template)
+            .await;
+        server
+            .respond_match(requestee, 200, path.join("requests/incoming.json"))
+            .await;
+        server
+            .respond(200, path.join("requests/outgoing.json"))
+            .await;
+        for user in [None, Some("@me")] {
+            let mut request = service.requests();
+            if let Some(value) = user {
+                request.user(value);
+            }
+            let requests = request.send().await.unwrap();
+            assert_eq!(requests.len(), 5);
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use indexmap::IndexMap;
+
+use crate::Error;
+use crate::objects::bugzilla::{Change, Event};
+use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::update::{self, SetChange};
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::RequestSend;
+
+/// Inverse changes reverting part of a bug's history.
+#[derive(Debug)]
+pub struct Revert {
+    pub id: String,
+    /// Changes applied by the update.
+    pub changes: Vec<Change>,
+    /// Changes that can't be reverted.
+    pub skipped: Vec<Change>,
+    /// Update request applying the changes, failing if the reverted fields were changed
+    /// after the reverted events.
+    pub request: update::Request,
+}
+
+/// Split a history value for list-based fields into its elements.
+fn split(value: Option<&str>) -> impl Iterator<Item = &str> {
+    value
+        .into_iter()
+        .flat_map(|x| x.split(','))
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+}
+
+/// Accumulated field state for the reverted events.
+#[derive(Default)]
+struct Fields {
+    /// Original and current values for single-value fields.
+    values: IndexMap<String, (Option<String>, Option<String>)>,
+    /// Original and current presence of elements for list-based fields.
+    lists: IndexMap<String, IndexMap<String, (bool, bool)>>,
+    skipped: Vec<Change>,
+}
+
+impl Fields {
+    fn new(events: &[Event]) -> Self {
+        let mut fields = Self::default();
+
+        // the earliest change for each value determines its original state
+        for change in events.iter().flat_map(|x| &x.changes) {
+            let name = change.field_name.as_str();
+            match name {
+                _ if change.attachment_id.is_some() => fields.skipped.push(change.clone()),
+                "alias" | "blocks" | "cc" | "depends_on" | "keywords" | "see_also" => {
+                    let values = fields.lists.entry(name.to_string()).or_default();
+                    for value in split(change.removed.as_deref()) {
+                        values.entry(value.to_string()).or_insert((true, false)).1 = false;
+                    }
+                    for value in split(change.added.as_deref()) {
+                        values.entry(value.to_string()).or_insert((false, true)).1 = true;
+                    }
+                }
+                "assigned_to" | "component" | "op_sys" | "platform" | "priority" | "product"
+                | "qa_contact" | "resolution" | "severity" | "status" | "summary"
+                | "target_milestone" | "url" | "version" | "whiteboard" => fields.value(change),
+                _ if name.starts_with("cf_") => fields.value(change),
+                _ => fields.skipped.push(change.clone()),
+            }
+        }
+
+        fields
+    }
+
+    fn value(&mut self, change: &Change) {
+        let (_, current) = self
+            .values
+            .entry(change.field_name.clone())
+            .or_insert_with(|| (change.removed.clone(), None));
+        current.clone_from(&change.added);
+    }
+
+    /// Convert the field state into inverse changes and their update parameters.
+    fn revert(mut self) -> crate::Result<(Vec<Change>, Vec<Change>, update::Parameters)> {
+        let mut changes = vec![];
+        let mut params = update::Parameters::default();
+        let reopened = self
+            .values
+            .get("status")
+            .is_some_and(|(original, current)| original != current);
+
+        for (name, (original, current)) in self.values {
+            if original == current {
+                continue;
+            }
+
+            let change = Change {
+                field_name: name,
+                removed: current,
+                added: original,
+                attachment_id: None,
+            };
+            let value = change.added.clone().unwrap_or_default();
+            let name = change.field_name.as_str();
+            match name {
+                // bugs can't be unassigned
+                "assigned_to" if value.is_empty() => {
+                    self.skipped.push(change);
+                    continue;
+                }
+                "assigned_to" => params.assignee = Some(value),
+                "component" => params.component = Some(value),
+                "op_sys" => params.os = Some(value),
+                "platform" => params.platform = Some(value),
+                "priority" => params.priority = Some(value),
+                "product" => params.product = Some(value),
+                "qa_contact" => params.qa = Some(value),
+                // resolutions are cleared when reopening bugs
+                "resolution" if value.is_empty() => {
+                    if !reopened {
+                        self.skipped.push(change);
+                        continue;
+                    }
+                }
+                "resolution" => params.resolution = Some(value),
+                "severity" => params.severity = Some(value),
+                "status" => params.status = Some(value),
+                "summary" => params.summary = Some(value),
+                "target_milestone" => params.target = Some(value),
+                "url" => params.url = Some(value),
+                "version" => params.version = Some(value),
+                "whiteboard" => params.whiteboard = Some(value),
+                _ => {
+                    params
+                        .custom_fields
+                        .get_or_insert_with(Default::default)
+                        .insert(name.to_string(), value);
+                }
+            }
+            changes.push(change);
+        }
+
+        for (name, values) in self.lists {
+            // skip elements with no net change
+            let (added, removed): (Vec<_>, Vec<_>) = values
+                .into_iter()
+                .filter(|(_, (original, current))| original != current)
+                .partition(|(_, (original, _))| *original);
+            let added: Vec<_> = added.into_iter().map(|(x, _)| x).collect();
+            let removed: Vec<_> = removed.into_iter().map(|(x, _)| x).collect();
+            if added.is_empty() && removed.is_empty() {
+                continue;
+            }
+
+            let set_changes = added
+                .iter()
+                .map(|x| format!("+{x}"))
+                .chain(removed.iter().map(|x| format!("-{x}")));
+            match name.as_str() {
+                "alias" => params.alias = Some(parse(set_changes)?),
+                "blocks" => params.blocks = Some(parse(set_changes)?),
+                "cc" => params.cc = Some(parse(set_changes)?),
+                "depends_on" => params.depends = Some(parse(set_changes)?),
+                "keywords" => params.keywords = Some(parse(set_changes)?),
+                "see_also" => params.see_also = Some(parse(set_changes)?),
+                _ => return Err(Error::InvalidValue(format!("unhandled list field: {name}"))),
+            }
+
+            let join = |values: Vec<String>| Some(values.join(", ")).filter(|x| !x.is_empty());
+            changes.push(Change {
+                field_name: name,
+                removed: join(removed),
+                added: join(added),
+                attachment_id: None,
+            });
+        }
+
+        Ok((changes, self.skipped, params))
+    }
+}
+
+/// Parse list-based field changes.
+fn parse<T, I>(values: I) -> crate::Result<Vec<SetChange<T>>>
+where
+    T: std::str::FromStr,
+    I: IntoIterator<Item = String>,
+{
+    values.into_iter().map(|x| x.parse()).collect()
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub ids: Vec<String>,
+    pub since: Option<TimeDeltaOrStatic>,
+    pub by: Option<String>,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Bugzilla, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|s| s.to_string()).collect(),
+            since: None,
+            by: None,
+        }
+    }
+
+    /// Revert changes made at this time or later.
+    pub fn since(&mut self, value: TimeDeltaOrStatic) -> &mut Self {
+        self.since = Some(value);
+        self
+    }
+
+    /// Revert changes made by a user, matching their login exactly.
+    pub fn by<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.by = Some(value.into());
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Revert>;
+
+    /// Compute the changes reverting matching events without applying them.
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        } else if self.since.is_none() && self.by.is_none() {
+            return Err(Error::EmptyParams);
+        }
+
+        let by = self
+            .by
+            .as_deref()
+            .map(|x| self.service.replace_user_alias(x));
+        let mut request = self.service.history(&self.ids);
+        request.params.created_after = self.since.clone();
+        request.params.creator = by.map(Into::into);
+        let history = request.send().await?;
+
+        let mut reverts = vec![];
+        for (id, mut events) in self.ids.iter().zip(history) {
+            // history creator filtering matches substrings
+            if let Some(value) = by {
+                events.retain(|x| x.who == value);
+            }
+            let (changes, skipped, params) = Fields::new(&events).revert()?;
+            let mut request = self.service.update([id]);
+            request.params = params;
+            // abort if reverted fields were changed after the reverted events
+            if let Some(time) = events.iter().map(|x| x.when).max() {
+                request.check_conflicts(true).last_change_time(time);
+            }
+            reverts.push(Revert {
+                id: id.clone(),
+                changes,
+                skipped,
+                request,
+            });
+        }
+
+        Ok(reverts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::matchers;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u32>::new();
+        let err = service.revert(ids).by("user").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // no restrictions
+        let err = service.revert([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        server.respond(200, path.join("revert/history.json")).await;
+
+        // users are matched exactly
+        let reverts = service.revert([1]).by("user").send().await.unwrap();
+        assert!(reverts[0].changes.is_empty());
+
+        // all changes by a user
+        let reverts = service
+            .revert([1])
+            .by("user1@bugbite.test")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(reverts.len(), 1);
+        let revert = &reverts[0];
+        let changes: Vec<_> = revert
+            .changes
+            .iter()
+            .map(|x| {
+                (
+                    x.field_name.as_str(),
+                    x.removed.as_deref(),
+                    x.added.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("status", Some("RESOLVED"), Some("CONFIRMED")),
+                ("resolution", Some("FIXED"), None),
+                ("cf_stage", Some("beta"), Some("alpha")),
+                ("cc", Some("user3@bugbite.test"), Some("user2@bugbite.test")),
+                ("blocks", Some("20"), Some("10")),
+                ("keywords", None, Some("Regression")),
+            ]
+        );
+        let skipped: Vec<_> = revert.skipped.iter().map(|x| &x.field_name).collect();
+        assert_eq!(
+            skipped,
+            ["flagtypes.name", "attachments.isobsolete", "assigned_to"]
+        );
+
+        let params = &revert.request.params;
+        assert_eq!(params.status.as_deref(), Some("CONFIRMED"));
+        assert!(params.resolution.is_none());
+        assert!(params.assignee.is_none());
+        assert_eq!(params.custom_fields.as_ref().unwrap()["cf_stage"], "alpha");
+        assert_eq!(
+            params.cc.as_ref().unwrap(),
+            &[
+                "+user2@bugbite.test".parse().unwrap(),
+                "-user3@bugbite.test".parse().unwrap()
+            ]
+        );
+        assert_eq!(
+            params.keywords.as_ref().unwrap(),
+            &["+Regression".parse().unwrap()]
+        );
+        assert_eq!(
+            params.blocks.as_ref().unwrap(),
+            &["+10".parse().unwrap(), "-20".parse().unwrap()]
+        );
+        // summary changed and then restored by the same user
+        assert!(params.summary.is_none());
+        // list elements added and then removed by the same user
+        assert!(params.see_also.is_none());
+
+        // changes to reverted fields made afterwards by other users conflict
+        server.reset().await;
+        server
+            .respond_match(
+                matchers::path_regex("/history$"),
+                200,
+                path.join("revert/conflict.json"),
+            )
+            .await;
+        server.respond(200, path.join("update/summary.json")).await;
+        let reverts = service
+            .revert([1])
+            .by("user1@bugbite.test")
+            .send()
+            .await
+            .unwrap();
+        let err = reverts[0].request.send().await.unwrap_err();
+        assert_matches!(err, Error::Conflict(_));
+        assert_err_re!(err, "^conflicting changes: bug 1: cf_stage$");
+
+        // reverting all changes since a given time can't conflict
+        let time = "2024-03-13T00:00:00Z".parse().unwrap();
+        let reverts = service.revert([1]).since(time).send().await.unwrap();
+        reverts[0].request.send().await.unwrap();
+    }
+}
@@ -1,3 +1,6 @@
+pub mod buglist;
+pub mod expression;
+
 use std::collections::HashSet;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
@@ -7,21 +10,22 @@ use futures_util::Stream;
 use indexmap::IndexSet;
 use itertools::{Either, Itertools};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 use serde_with::{DeserializeFromStr, SerializeDisplay, skip_serializing_none};
 use strum::{AsRefStr, Display, EnumIter, EnumString};
 use url::Url;
 
 use crate::Error;
 use crate::args::ExistsOrValues;
-use crate::objects::bugzilla::Bug;
+use crate::objects::bugzilla::{Bug, BugzillaFieldKind, Hours};
 use crate::objects::{Range, RangeOp, RangeOrValue};
 use crate::query::{Order, Query};
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::time::TimeDeltaOrStatic;
 use crate::traits::{Api, InjectAuth, Merge, RequestPagedStream, RequestTemplate, WebService};
 
 use super::{BugField, FilterField};
+use expression::{Expression, Node};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct Request {
@@ -83,19 +87,28 @@ impl RequestPagedStream for Request {
     }
 
     async fn send(self) -> crate::Result<Vec<Bug>> {
-        let mut url = self.service.config().base.join("rest/bug")?;
+        // custom field queries depend on field types
+        if self.params.custom_fields.is_some() {
+            self.service.cache().await;
+        }
+
         let query = self.encode()?;
-        url.query_pairs_mut().extend_pairs(query.iter());
-        let request = self.service.client().get(url).auth_optional(&self.service);
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            self.service.rpc("Bug.search", query.rpc(), false).await?
+        } else {
+            let mut url = self.service.config().base.join("rest/bug")?;
+            url.query_pairs_mut().extend_pairs(query.iter());
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let Value::Array(data) = data["bugs"].take() else {
             return Err(Error::InvalidResponse("search request".to_string()));
         };
 
         let mut bugs = vec![];
         for value in data {
-            let bug = self.service.deserialize_bug(value)?;
+            let bug = self.service.deserialize_bug(value).await?;
             bugs.push(bug);
         }
 
@@ -318,6 +331,18 @@ impl Request {
             query.votes(value);
         }
 
+        if let Some(value) = &self.params.estimated_time {
+            query.hours("estimated_time", value);
+        }
+
+        if let Some(value) = &self.params.remaining_time {
+            query.hours("remaining_time", value);
+        }
+
+        if let Some(value) = &self.params.actual_time {
+            query.hours("work_time", value);
+        }
+
         if let Some(values) = &self.params.assignee {
             query.or(|query| {
                 for value in values {
@@ -363,6 +388,14 @@ impl Request {
             });
         }
 
+        if let Some(values) = &self.params.requestee {
+            query.or(|query| {
+                for value in values {
+                    query.and(|query| value.iter().for_each(|x| query.requestee(x)))
+                }
+            });
+        }
+
         if let Some(values) = &self.params.qa {
             query.or(|query| {
                 for value in values {
@@ -505,10 +538,20 @@ impl Request {
             query.status("@closed");
         }
 
+        if let Some(value) = &self.params.deadline {
+            query.deadline(value);
+        }
+
         if let Some(value) = &self.params.quicksearch {
             query.insert("quicksearch", value);
         }
 
+        if let Some(values) = &self.params.query {
+            for value in values {
+                query.expression(value.node());
+            }
+        }
+
         if let Some(values) = &self.params.custom_fields {
             query.or(|query| {
                 for (name, value) in values {
@@ -814,6 +857,19 @@ impl Request {
         self
     }
 
+    pub fn requestee<I, T>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Match>,
+    {
+        // TODO: move to get_or_insert_default() when it is stable
+        self.params
+            .requestee
+            .get_or_insert_with(Default::default)
+            .push(values.into_iter().map(Into::into).collect());
+        self
+    }
+
     pub fn qa<T>(&mut self, value: T) -> &mut Self
     where
         T: Into<ExistsOrValues<Match>>,
@@ -951,6 +1007,11 @@ impl Request {
         self
     }
 
+    pub fn deadline(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.deadline = Some(value);
+        self
+    }
+
     pub fn limit(&mut self, value: usize) -> &mut Self {
         self.params.limit = Some(value);
         self
@@ -961,6 +1022,18 @@ impl Request {
         self
     }
 
+    pub fn query<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<Expression>,
+    {
+        // TODO: move to get_or_insert_default() when it is stable
+        self.params
+            .query
+            .get_or_insert_with(Default::default)
+            .push(value.into());
+        self
+    }
+
     pub fn quicksearch<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.params.quicksearch = Some(value.into());
         self
@@ -1000,6 +1073,7 @@ pub struct Parameters {
     pub flagger: Option<Vec<Vec<Match>>>,
     pub qa: Option<Vec<ExistsOrValues<Match>>>,
     pub reporter: Option<Vec<Vec<Match>>>,
+    pub requestee: Option<Vec<Vec<Match>>>,
 
     #[serde(skip_serializing)]
     pub fields: Option<Vec<FilterField>>,
@@ -1011,6 +1085,7 @@ pub struct Parameters {
     pub created: Option<RangeOrValue<TimeDeltaOrStatic>>,
     pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
     pub closed: Option<RangeOrValue<TimeDeltaOrStatic>>,
+    pub deadline: Option<RangeOrValue<TimeDeltaOrStatic>>,
 
     pub comment: Option<Vec<Match>>,
     pub comment_is_private: Option<bool>,
@@ -1031,8 +1106,12 @@ pub struct Parameters {
     pub target: Option<Vec<Match>>,
     pub comments: Option<RangeOrValue<u64>>,
     pub votes: Option<RangeOrValue<u64>>,
+    pub estimated_time: Option<RangeOrValue<Hours>>,
+    pub remaining_time: Option<RangeOrValue<Hours>>,
+    pub actual_time: Option<RangeOrValue<Hours>>,
     pub summary: Option<Vec<Match>>,
     pub quicksearch: Option<String>,
+    pub query: Option<Vec<Expression>>,
     pub custom_fields: Option<Vec<(String, ExistsOrValues<Match>)>>,
 }
 
@@ -1074,6 +1153,7 @@ impl Merge for Parameters {
             flagger: self.flagger.merge(other.flagger),
             qa: self.qa.merge(other.qa),
             reporter: self.reporter.merge(other.reporter),
+            requestee: self.requestee.merge(other.requestee),
 
             fields: self.fields.merge(other.fields),
             limit: self.limit.merge(other.limit),
@@ -1084,6 +1164,7 @@ impl Merge for Parameters {
             created: self.created.merge(other.created),
             updated: self.updated.merge(other.updated),
             closed: self.closed.merge(other.closed),
+            deadline: self.deadline.merge(other.deadline),
 
             comment: self.comment.merge(other.comment),
             comment_is_private: self.comment_is_private.merge(other.comment_is_private),
@@ -1104,8 +1185,12 @@ impl Merge for Parameters {
             target: self.target.merge(other.target),
             comments: self.comments.merge(other.comments),
             votes: self.votes.merge(other.votes),
+            estimated_time: self.estimated_time.merge(other.estimated_time),
+            remaining_time: self.remaining_time.merge(other.remaining_time),
+            actual_time: self.actual_time.merge(other.actual_time),
             summary: self.summary.merge(other.summary),
             quicksearch: self.quicksearch.merge(other.quicksearch),
+            query: self.query.merge(other.query),
             custom_fields: self.custom_fields.merge(other.custom_fields),
         }
     }
@@ -1144,6 +1229,25 @@ impl<'a> QueryBuilder<'a> {
             advanced_count: Default::default(),
         }
     }
+
+    /// Encode the query into JSON-RPC method parameters.
+    fn rpc(&self) -> Value {
+        let mut params = serde_json::Map::new();
+        for key in self.keys() {
+            let mut values: Vec<_> = self.get_all(key).map(|x| x.as_str()).collect();
+
+            // field lists are required to be arrays
+            if key == "include_fields" || key == "exclude_fields" {
+                values = values.iter().flat_map(|x| x.split(',')).collect();
+                params.insert(key.clone(), json!(values));
+            } else if values.len() == 1 {
+                params.insert(key.clone(), json!(values[0]));
+            } else {
+                params.insert(key.clone(), json!(values));
+            }
+        }
+        Value::Object(params)
+    }
 }
 
 /// Fields changed within a specified time interval.
@@ -1255,6 +1359,64 @@ enum MatchOp {
     /// Doesn't match regular expression.
     #[strum(serialize = "!*")]
     NotRegexp,
+    /// Contains any of the words as substrings.
+    #[strum(serialize = "~|")]
+    AnyWordsSubstring,
+    /// Contains all of the words as substrings.
+    #[strum(serialize = "~&")]
+    AllWordsSubstring,
+    /// Contains none of the words as substrings.
+    #[strum(serialize = "!~|")]
+    NoWordsSubstring,
+    /// Contains any of the words.
+    #[strum(serialize = "=|")]
+    AnyWords,
+    /// Contains all of the words.
+    #[strum(serialize = "=&")]
+    AllWords,
+    /// Contains none of the words.
+    #[strum(serialize = "!=|")]
+    NoWords,
+    /// Less than value.
+    #[strum(serialize = "<")]
+    LessThan,
+    /// Less than or equal to value.
+    #[strum(serialize = "<=")]
+    LessThanEq,
+    /// Greater than value.
+    #[strum(serialize = ">")]
+    GreaterThan,
+    /// Greater than or equal to value.
+    #[strum(serialize = ">=")]
+    GreaterThanEq,
+    /// Changed before time.
+    #[strum(serialize = "@<")]
+    ChangedBefore,
+    /// Changed after time.
+    #[strum(serialize = "@>")]
+    ChangedAfter,
+    /// Changed from value.
+    #[strum(serialize = "@-")]
+    ChangedFrom,
+    /// Changed to value.
+    #[strum(serialize = "@+")]
+    ChangedTo,
+    /// Changed by user.
+    #[strum(serialize = "@@")]
+    ChangedBy,
+    /// Field is empty.
+    #[strum(serialize = "^$")]
+    IsEmpty,
+    /// Field is not empty.
+    #[strum(serialize = "!^$")]
+    IsNotEmpty,
+}
+
+impl MatchOp {
+    /// Return true if the operator doesn't use a value.
+    fn is_unary(&self) -> bool {
+        matches!(self, Self::IsEmpty | Self::IsNotEmpty)
+    }
 }
 
 impl Api for MatchOp {
@@ -1267,6 +1429,23 @@ impl Api for MatchOp {
             Self::NotEquals => "notequals",
             Self::Regexp => "regexp",
             Self::NotRegexp => "notregexp",
+            Self::AnyWordsSubstring => "anywordssubstr",
+            Self::AllWordsSubstring => "allwordssubstr",
+            Self::NoWordsSubstring => "nowordssubstr",
+            Self::AnyWords => "anywords",
+            Self::AllWords => "allwords",
+            Self::NoWords => "nowords",
+            Self::LessThan => "lessthan",
+            Self::LessThanEq => "lessthaneq",
+            Self::GreaterThan => "greaterthan",
+            Self::GreaterThanEq => "greaterthaneq",
+            Self::ChangedBefore => "changedbefore",
+            Self::ChangedAfter => "changedafter",
+            Self::ChangedFrom => "changedfrom",
+            Self::ChangedTo => "changedto",
+            Self::ChangedBy => "changedby",
+            Self::IsEmpty => "isempty",
+            Self::IsNotEmpty => "isnotempty",
         };
         value.to_string()
     }
@@ -1301,10 +1480,11 @@ impl Api for Match {
 
 impl fmt::Display for Match {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if let Some(op) = &self.op {
-            write!(f, "{op} ")?;
+        match &self.op {
+            Some(op) if op.is_unary() && self.value.is_empty() => write!(f, "{op}"),
+            Some(op) => write!(f, "{op} {}", self.value),
+            None => write!(f, "{}", self.value),
         }
-        write!(f, "{}", self.value)
     }
 }
 
@@ -1318,6 +1498,16 @@ impl FromStr for Match {
 
 impl From<&str> for Match {
     fn from(s: &str) -> Self {
+        // operators lacking values can be used alone
+        if let Ok(op) = s.parse::<MatchOp>()
+            && op.is_unary()
+        {
+            return Self {
+                op: Some(op),
+                value: Default::default(),
+            };
+        }
+
         let values = s.split_once(' ').map(|(op, value)| (op.parse(), value));
 
         let (op, value) = if let Some((Ok(op), value)) = values {
@@ -1560,6 +1750,14 @@ impl QueryBuilder<'_> {
         }
     }
 
+    fn deadline(&mut self, value: &RangeOrValue<TimeDeltaOrStatic>) {
+        match value {
+            RangeOrValue::Value(value) => self.advanced_field("deadline", "greaterthaneq", value),
+            RangeOrValue::RangeOp(value) => self.range_op("deadline", value),
+            RangeOrValue::Range(value) => self.range("deadline", value),
+        }
+    }
+
     fn order(&mut self, values: &[Order<OrderField>]) {
         let value = values.iter().map(|x| x.api()).join(",");
         self.insert("order", value);
@@ -1580,6 +1778,11 @@ impl QueryBuilder<'_> {
         self.advanced_field("setters.login_name", value.op(), value);
     }
 
+    fn requestee(&mut self, value: &Match) {
+        let value = value.replace_user_alias(self.service);
+        self.advanced_field("requestees.login_name", value.op(), value);
+    }
+
     fn url(&mut self, value: &Match) {
         self.advanced_field("bug_file_loc", value.op(), value);
     }
@@ -1633,14 +1836,18 @@ impl QueryBuilder<'_> {
             RangeOrValue::Range(value) => match value {
                 Range::Range(r) => {
                     self.not(status, |query| {
-                        query.advanced_field(&field, "changedafter", &r.start);
-                        query.advanced_field(&field, "changedbefore", &r.end);
+                        query.and(|query| {
+                            query.advanced_field(&field, "changedafter", &r.start);
+                            query.advanced_field(&field, "changedbefore", &r.end);
+                        })
                     });
                 }
                 Range::Inclusive(r) => {
                     self.not(status, |query| {
-                        query.advanced_field(&field, "changedafter", r.start());
-                        query.advanced_field(&field, "changedbefore", r.end());
+                        query.and(|query| {
+                            query.advanced_field(&field, "changedafter", r.start());
+                            query.advanced_field(&field, "changedbefore", r.end());
+                        })
                     });
                 }
                 Range::To(r) => {
@@ -1703,7 +1910,42 @@ impl QueryBuilder<'_> {
         Ok(())
     }
 
-    fn custom_field<F: Api>(&mut self, name: F, value: &Match) {
+    fn custom_field(&mut self, name: &str, value: &Match) {
+        // values without explicit operators use matching based on the field type
+        if value.op.is_none() {
+            match self.service.custom_field_kind(name) {
+                BugzillaFieldKind::BugId | BugzillaFieldKind::Integer => {
+                    if let Ok(value) = value.value.parse::<RangeOrValue<i64>>() {
+                        match value {
+                            RangeOrValue::Value(value) => {
+                                self.advanced_field(name, "equals", value)
+                            }
+                            RangeOrValue::RangeOp(value) => self.range_op(name, &value),
+                            RangeOrValue::Range(value) => self.range(name, &value),
+                        }
+                        return;
+                    }
+                }
+                BugzillaFieldKind::Date | BugzillaFieldKind::DateTime => {
+                    if let Ok(value) = value.value.parse::<RangeOrValue<TimeDeltaOrStatic>>() {
+                        match value {
+                            RangeOrValue::Value(value) => {
+                                self.advanced_field(name, "greaterthaneq", value)
+                            }
+                            RangeOrValue::RangeOp(value) => self.range_op(name, &value),
+                            RangeOrValue::Range(value) => self.range(name, &value),
+                        }
+                        return;
+                    }
+                }
+                BugzillaFieldKind::Value | BugzillaFieldKind::MultiValue => {
+                    self.advanced_field(name, "equals", value);
+                    return;
+                }
+                _ => (),
+            }
+        }
+
         self.advanced_field(name, value.op(), value);
     }
 
@@ -1780,6 +2022,15 @@ impl QueryBuilder<'_> {
         }
     }
 
+    /// Match bugs with time tracking field values.
+    fn hours(&mut self, field: &str, value: &RangeOrValue<Hours>) {
+        match value {
+            RangeOrValue::Value(value) => self.advanced_field(field, "equals", value),
+            RangeOrValue::RangeOp(value) => self.range_op(field, value),
+            RangeOrValue::Range(value) => self.range(field, value),
+        }
+    }
+
     fn comments(&mut self, value: &RangeOrValue<u64>) {
         match value {
             RangeOrValue::Value(value) => self.advanced_field("longdescs.count", "equals", value),
@@ -1946,13 +2197,30 @@ impl QueryBuilder<'_> {
         self.op_func("AND", func)
     }
 
+    /// Negate the advanced field or group added by a given function.
+    ///
+    /// Functions adding multiple fields must wrap them in a group so the entire body is
+    /// negated instead of only its first field.
     fn not<F: FnOnce(&mut Self)>(&mut self, status: bool, func: F) {
+        let num = self.advanced_count + 1;
         func(self);
         if status {
-            let num = self.advanced_count;
             self.insert(format!("n{num}"), "1");
         }
     }
+
+    /// Add a search expression node as nested advanced fields.
+    fn expression(&mut self, node: &Node) {
+        match node {
+            Node::And(nodes) => self.and(|query| nodes.iter().for_each(|x| query.expression(x))),
+            Node::Or(nodes) => self.or(|query| nodes.iter().for_each(|x| query.expression(x))),
+            Node::Not(node) => self.not(true, |query| query.expression(node)),
+            Node::Term { field, op, value } => {
+                let value = self.service.replace_user_alias(value).to_string();
+                self.advanced_field(Node::field_api(field), op, value);
+            }
+        }
+    }
 }
 
 /// Bug fields composed of value arrays.
@@ -1999,6 +2267,7 @@ impl Api for ExistsField {
 #[derive(Display, EnumIter, EnumString, Debug, Clone, Copy, PartialEq, Eq)]
 #[strum(serialize_all = "kebab-case")]
 pub enum OrderField {
+    ActualTime,
     Alias,
     Assignee,
     Blocks,
@@ -2007,6 +2276,7 @@ pub enum OrderField {
     Created,
     Deadline,
     Depends,
+    EstimatedTime,
     Flags,
     Id,
     Keywords,
@@ -2016,6 +2286,7 @@ pub enum OrderField {
     Priority,
     Product,
     Qa,
+    RemainingTime,
     Reporter,
     Resolution,
     Severity,
@@ -2033,6 +2304,7 @@ pub enum OrderField {
 impl Api for OrderField {
     fn api(&self) -> String {
         let value = match self {
+            Self::ActualTime => "actual_time",
             Self::Alias => "alias",
             Self::Assignee => "assigned_to",
             Self::Blocks => "blocked",
@@ -2041,6 +2313,7 @@ impl Api for OrderField {
             Self::Created => "opendate",
             Self::Deadline => "deadline",
             Self::Depends => "dependson",
+            Self::EstimatedTime => "estimated_time",
             Self::Flags => "flagtypes.name",
             Self::Id => "bug_id",
             Self::Keywords => "keywords",
@@ -2050,6 +2323,7 @@ impl Api for OrderField {
             Self::Priority => "priority",
             Self::Product => "product",
             Self::Qa => "qa_contact",
+            Self::RemainingTime => "remaining_time",
             Self::Reporter => "reporter",
             Self::Resolution => "resolution",
             Self::Severity => "bug_severity",
@@ -2087,6 +2361,7 @@ pub enum StaticChangeField {
     Cc,
     Deadline,
     Depends,
+    EstimatedTime,
     Flags,
     Keywords,
     Os,
@@ -2094,6 +2369,7 @@ pub enum StaticChangeField {
     Priority,
     Product,
     Qa,
+    RemainingTime,
     Reporter,
     Resolution,
     SeeAlso,
@@ -2117,6 +2393,7 @@ impl Api for StaticChangeField {
             Self::Cc => "cc",
             Self::Deadline => "deadline",
             Self::Depends => "dependson",
+            Self::EstimatedTime => "estimated_time",
             Self::Flags => "flagtypes.name",
             Self::Keywords => "keywords",
             Self::Os => "op_sys",
@@ -2124,6 +2401,7 @@ impl Api for StaticChangeField {
             Self::Priority => "priority",
             Self::Product => "product",
             Self::Qa => "qa_contact",
+            Self::RemainingTime => "remaining_time",
             Self::Reporter => "reporter",
             Self::Resolution => "resolution",
             Self::SeeAlso => "see_also",
@@ -2183,6 +2461,7 @@ impl ChangeField {
 #[cfg(test)]
 mod tests {
     use strum::IntoEnumIterator;
+    use wiremock::{ResponseTemplate, matchers};
 
     use crate::service::bugzilla::GroupField;
     use crate::test::*;
@@ -2455,6 +2734,9 @@ mod tests {
         // flagger
         stream!(service.search().flagger(["value"]));
 
+        // requestee
+        stream!(service.search().requestee(["value"]));
+
         // qa
         stream!(service.search().qa(true));
         stream!(service.search().qa(false));
@@ -2537,10 +2819,262 @@ mod tests {
             stream!(service.search().created(time.parse().unwrap()));
             stream!(service.search().updated(time.parse().unwrap()));
             stream!(service.search().closed(time.parse().unwrap()));
+            stream!(service.search().deadline(time.parse().unwrap()));
+        }
+
+        // time tracking ranges
+        for value in [
+            "1.5", "<1.5", "!=0", ">=8", "1..2.5", "0.5..=8", "..8", "2..",
+        ] {
+            let mut request = service.search();
+            request.params.estimated_time = Some(value.parse().unwrap());
+            request.params.remaining_time = Some(value.parse().unwrap());
+            request.params.actual_time = Some(value.parse().unwrap());
+            stream!(request);
         }
 
         stream!(service.search().limit(10));
         stream!(service.search().offset(10));
         stream!(service.search().quicksearch("ALL @user OR reporter:user"));
+        stream!(
+            service
+                .search()
+                .query("product=Foo".parse::<Expression>().unwrap())
+        );
+    }
+
+    #[test]
+    fn query() {
+        let service = Bugzilla::builder("https://bugzilla.mozilla.org")
+            .unwrap()
+            .user("user@bugbite.test")
+            .build()
+            .unwrap();
+
+        // nested groups
+        let mut request = service.search();
+        let expr = "(product=Foo | component~bar) & !keywords=VERIFIED";
+        request.query(expr.parse::<Expression>().unwrap());
+        let params = request.encode().unwrap().rpc();
+        for (key, value) in [
+            ("f1", "OP"),
+            ("j1", "AND"),
+            ("f2", "OP"),
+            ("j2", "OR"),
+            ("f3", "product"),
+            ("o3", "equals"),
+            ("v3", "Foo"),
+            ("f4", "component"),
+            ("o4", "substring"),
+            ("v4", "bar"),
+            ("f5", "CP"),
+            ("f6", "keywords"),
+            ("o6", "equals"),
+            ("v6", "VERIFIED"),
+            ("n6", "1"),
+            ("f7", "CP"),
+        ] {
+            assert_eq!(params[key], json!(value), "{key}");
+        }
+
+        // negated groups and user aliases
+        let mut request = service.search();
+        let expr = "!(assignee=@me | status<=RESOLVED)";
+        request.query(expr.parse::<Expression>().unwrap());
+        let params = request.encode().unwrap().rpc();
+        for (key, value) in [
+            ("f1", "OP"),
+            ("j1", "OR"),
+            ("n1", "1"),
+            ("f2", "assigned_to"),
+            ("v2", "user@bugbite.test"),
+            ("f3", "bug_status"),
+            ("o3", "lessthaneq"),
+            ("f4", "CP"),
+        ] {
+            assert_eq!(params[key], json!(value), "{key}");
+        }
+        assert!(params.get("n4").is_none());
+
+        // negated change ranges
+        let mut request = service.search();
+        request.params.changed = Some(vec!["!status=2020-01-01..2020-02-01".parse().unwrap()]);
+        let params = request.encode().unwrap().rpc();
+        for (key, value) in [
+            ("f1", "OP"),
+            ("j1", "AND"),
+            ("n1", "1"),
+            ("f2", "bug_status"),
+            ("o2", "changedafter"),
+            ("v2", "2020-01-01T00:00:00Z"),
+            ("f3", "bug_status"),
+            ("o3", "changedbefore"),
+            ("v3", "2020-02-01T00:00:00Z"),
+            ("f4", "CP"),
+        ] {
+            assert_eq!(params[key], json!(value), "{key}");
+        }
+        assert!(params.get("n2").is_none());
+        assert!(params.get("n3").is_none());
+    }
+
+    #[test]
+    fn match_ops() {
+        let service = Bugzilla::new("https://bugzilla.test").unwrap();
+
+        // operators with values
+        let mut request = service.search();
+        request.params.whiteboard = Some(vec!["~| foo bar".into()]);
+        request.params.summary = Some(vec!["!=| foo bar".into()]);
+        request.params.priority = Some(vec![vec![">= High".into()]]);
+        request.params.reporter = Some(vec![vec!["@@ user".into()]]);
+        let params = request.encode().unwrap().rpc();
+        let ops: HashSet<_> = params
+            .as_object()
+            .unwrap()
+            .iter()
+            .filter(|(k, _)| k.starts_with('o'))
+            .filter_map(|(_, v)| v.as_str())
+            .collect();
+        for op in ["anywordssubstr", "nowords", "greaterthaneq", "changedby"] {
+            assert!(ops.contains(op), "missing operator: {op}");
+        }
+
+        // operators lacking values
+        let value = Match::from("^$");
+        assert_eq!(value.op, Some(MatchOp::IsEmpty));
+        assert!(value.value.is_empty());
+        assert_eq!(value.to_string(), "^$");
+        let value = Match::from("!^$");
+        assert_eq!(value.op, Some(MatchOp::IsNotEmpty));
+
+        // unknown operators are treated as values
+        let value = Match::from("<> value");
+        assert!(value.op.is_none());
+        assert_eq!(value.value, "<> value");
+
+        // all operators roundtrip via templates
+        let values: Vec<_> = MatchOp::iter()
+            .map(|op| {
+                if op.is_unary() {
+                    op.to_string()
+                } else {
+                    format!("{op} value")
+                }
+            })
+            .collect();
+        let params = Parameters {
+            alias: Some(vec![values.as_slice().into()]),
+            ..Default::default()
+        };
+        let data = toml::to_string(&params).unwrap();
+        let loaded: Parameters = toml::from_str(&data).unwrap();
+        assert_eq!(params, loaded);
+    }
+
+    #[tokio::test]
+    async fn custom_fields() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("fields/custom.json")).await;
+        assert!(!service.cache().await.custom_fields.is_empty());
+
+        // values without operators use matching based on field types
+        let mut request = service.search();
+        request.params.custom_fields = Some(
+            [
+                ("cf_points", ">2"),
+                ("cf_parent", "10"),
+                ("cf_due", "2024-01-01..2024-02-01"),
+                ("cf_stage", "alpha"),
+                ("cf_notes", "text"),
+                ("cf_packages", "!~ foo"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.into()))
+            .collect(),
+        );
+        let params = request.encode().unwrap().rpc();
+        for (key, value) in [
+            ("f3", "cf_points"),
+            ("o3", "greaterthan"),
+            ("v3", "2"),
+            ("f6", "cf_parent"),
+            ("o6", "equals"),
+            ("v6", "10"),
+            ("f10", "cf_due"),
+            ("o10", "greaterthaneq"),
+            ("v10", "2024-01-01T00:00:00Z"),
+            ("f11", "cf_due"),
+            ("o11", "lessthan"),
+            ("v11", "2024-02-01T00:00:00Z"),
+            ("f15", "cf_stage"),
+            ("o15", "equals"),
+            ("f18", "cf_notes"),
+            ("o18", "substring"),
+            ("f21", "cf_packages"),
+            ("o21", "notsubstring"),
+        ] {
+            assert_eq!(params[key], json!(value), "{key}");
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_fields_failure() {
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        // failed field requests are only sent once across multiple bugs
+        let template = ResponseTemplate::new(403);
+        server
+            .respond_custom(matchers::path("/rest/field/bug"), template)
+            .await;
+        let bugs = serde_json::json!({"bugs": [
+            {"id": 1, "cf_stage": "alpha"},
+            {"id": 2, "cf_stage": "beta"},
+            {"id": 3, "cf_stage": "---"},
+        ]});
+        let template = ResponseTemplate::new(200).set_body_json(bugs);
+        server
+            .respond_custom(matchers::path("/rest/bug"), template)
+            .await;
+        let bugs = service.search().send().await.unwrap();
+        assert_eq!(bugs.len(), 3);
+        let requests = server.mock().received_requests().await.unwrap();
+        let fields = requests
+            .iter()
+            .filter(|x| x.url.path() == "/rest/field/bug")
+            .count();
+        assert_eq!(fields, 1);
+    }
+
+    #[tokio::test]
+    async fn rpc() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+
+        // parameter encoding
+        let mut request = service.search();
+        request
+            .status(["UNCONFIRMED", "CONFIRMED"])
+            .summary(["test"]);
+        let params = request.encode().unwrap().rpc();
+        assert_eq!(params["include_fields"], json!(["id", "summary"]));
+        assert_eq!(params["f1"], json!("OP"));
+        assert_eq!(params["j1"], json!("OR"));
+        assert_eq!(params["v2"], json!("UNCONFIRMED"));
+
+        server
+            .respond_rpc("Bug.search", path.join("search/rpc.json"))
+            .await;
+        let bugs = stream_result!(service.search().ids([1])).unwrap();
+        assert_eq!(bugs.len(), 5);
     }
 }
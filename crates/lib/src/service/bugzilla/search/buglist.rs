@@ -0,0 +1,956 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+use url::Url;
+
+use crate::Error;
+use crate::args::ExistsOrValues;
+use crate::objects::RangeOrValue;
+use crate::query::Order;
+use crate::service::bugzilla::FilterField;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::Api;
+
+use super::expression::{Expression, Node, Operator};
+use super::{Changed, ChangedBy, ChangedValue, Match, OrderField, Parameters, StaticChangeField};
+
+/// Parameters only affecting the web interface that are silently ignored.
+const IGNORED: &[&str] = &[
+    "cmdtype",
+    "known_name",
+    "list_id",
+    "namedcmd",
+    "query_based_on",
+    "query_format",
+    "remaction",
+    "sharer_id",
+];
+
+impl Parameters {
+    /// Convert a buglist.cgi or query.cgi URL into search parameters.
+    ///
+    /// Returns the parameters along with warnings for all URL parameters that couldn't be
+    /// converted.
+    pub fn from_url(url: &Url) -> crate::Result<(Self, Vec<String>)> {
+        if !url.path().ends_with("/buglist.cgi") && !url.path().ends_with("/query.cgi") {
+            return Err(Error::InvalidValue(format!(
+                "unsupported search URL: {url}"
+            )));
+        }
+
+        let mut pairs = IndexMap::<String, Vec<String>>::new();
+        for (key, value) in url.query_pairs() {
+            pairs
+                .entry(key.into_owned())
+                .or_default()
+                .push(value.into_owned());
+        }
+
+        let mut import = Import::default();
+        import.convert(pairs);
+        Ok((import.params, import.warnings))
+    }
+}
+
+/// Return the expression field name for a Bugzilla field.
+fn field_name(api: &str) -> Option<String> {
+    if api.starts_with("cf_") {
+        Some(api.to_string())
+    } else if api == "platform" {
+        // search queries use both platform field names
+        Some(api.to_string())
+    } else {
+        StaticChangeField::iter()
+            .find(|x| x.api() == api)
+            .map(|x| x.to_string())
+    }
+}
+
+/// Convert a Bugzilla time value into its relative or static equivalent.
+///
+/// Returns `None` for the current time which Bugzilla uses for open-ended intervals.
+fn time(value: &str) -> Result<Option<String>, String> {
+    let value = value.trim();
+    if value.is_empty() || value.eq_ignore_ascii_case("now") {
+        return Ok(None);
+    }
+
+    // Bugzilla uses negative values for relative times
+    let value = value.strip_prefix('-').unwrap_or(value);
+    TimeDeltaOrStatic::from_str(value)
+        .map(|_| Some(value.to_string()))
+        .map_err(|_| format!("unsupported time: {value}"))
+}
+
+/// Convert a text search type into AND-ed matches.
+///
+/// An empty search type matches all words as substrings, the web interface default.
+fn text_matches(kind: &str, value: &str) -> Result<Vec<Match>, String> {
+    let words = || value.split_whitespace();
+    let matches = match kind {
+        "substring" => vec![format!("~~ {value}").into()],
+        "casesubstring" => vec![format!("=~ {value}").into()],
+        "regexp" => vec![format!("=* {value}").into()],
+        "notregexp" => vec![format!("!* {value}").into()],
+        "" | "allwordssubstr" => words().map(|x| format!("~~ {x}").into()).collect(),
+        "anywordssubstr" => vec![format!("~| {value}").into()],
+        "nowordssubstr" => words().map(|x| format!("!~ {x}").into()).collect(),
+        "anywords" => vec![format!("=| {value}").into()],
+        "allwords" => vec![format!("=& {value}").into()],
+        "nowords" => vec![format!("!=| {value}").into()],
+        _ => return Err(format!("unsupported text search type: {kind}")),
+    };
+    Ok(matches)
+}
+
+/// Render an expression node into its string form.
+fn render(node: &Node) -> String {
+    match node {
+        Node::And(nodes) => nodes
+            .iter()
+            .map(|x| match x {
+                Node::Or(_) => format!("({})", render(x)),
+                _ => render(x),
+            })
+            .join(" & "),
+        Node::Or(nodes) => nodes.iter().map(render).join(" | "),
+        Node::Not(node) => match node.as_ref() {
+            Node::Term { .. } => format!("!{}", render(node)),
+            _ => format!("!({})", render(node)),
+        },
+        Node::Term { field, op, value } => {
+            let bare = |c: char| c.is_alphanumeric() || "-_.@:/+,'".contains(c);
+            if !value.is_empty() && value.chars().all(bare) {
+                format!("{field}{op}{value}")
+            } else {
+                let value = value.replace('\\', r"\\").replace('"', r#"\""#);
+                format!(r#"{field}{op}"{value}""#)
+            }
+        }
+    }
+}
+
+/// Collapse single item groups.
+fn group(nodes: Vec<Node>, func: fn(Vec<Node>) -> Node) -> Node {
+    if nodes.len() == 1 {
+        nodes.into_iter().next().unwrap()
+    } else {
+        func(nodes)
+    }
+}
+
+/// Advanced field row using its `f`, `o`, `v`, `n`, and `j` parameters.
+#[derive(Debug, Default)]
+struct Row {
+    field: String,
+    op: String,
+    value: String,
+    negate: bool,
+    join: String,
+}
+
+/// Advanced field chart item.
+#[derive(Debug)]
+enum Item {
+    Term {
+        field: String,
+        op: String,
+        value: String,
+        negate: bool,
+    },
+    Group {
+        or: bool,
+        negate: bool,
+        items: Vec<Item>,
+    },
+}
+
+impl Item {
+    fn group(row: &Row, items: Vec<Item>) -> Self {
+        Self::Group {
+            or: row.join == "OR",
+            negate: row.negate,
+            items,
+        }
+    }
+
+    /// Convert the item into an expression node.
+    fn node(&self) -> Result<Node, String> {
+        match self {
+            Self::Term {
+                field,
+                op,
+                value,
+                negate,
+            } => {
+                let name =
+                    field_name(field).ok_or_else(|| format!("unsupported field: {field}"))?;
+                let term = |op, value: &str| Node::Term {
+                    field: name.clone(),
+                    op,
+                    value: value.to_string(),
+                };
+
+                let node = match op.as_str() {
+                    "anyexact" => group(
+                        value
+                            .split(',')
+                            .map(|x| term(Operator::Equals, x.trim()))
+                            .collect(),
+                        Node::Or,
+                    ),
+                    "anywordssubstr" => group(
+                        value
+                            .split_whitespace()
+                            .map(|x| term(Operator::Substring, x))
+                            .collect(),
+                        Node::Or,
+                    ),
+                    "allwordssubstr" => group(
+                        value
+                            .split_whitespace()
+                            .map(|x| term(Operator::Substring, x))
+                            .collect(),
+                        Node::And,
+                    ),
+                    op => {
+                        let op = Operator::iter()
+                            .find(|x| x.api() == op)
+                            .ok_or_else(|| format!("unsupported operator: {op}"))?;
+                        term(op, value)
+                    }
+                };
+
+                if *negate {
+                    Ok(Node::Not(Box::new(node)))
+                } else {
+                    Ok(node)
+                }
+            }
+            Self::Group { or, negate, items } => {
+                let nodes: Vec<_> = items.iter().map(|x| x.node()).try_collect()?;
+                let node = if *or {
+                    group(nodes, Node::Or)
+                } else {
+                    group(nodes, Node::And)
+                };
+
+                if *negate {
+                    Ok(Node::Not(Box::new(node)))
+                } else {
+                    Ok(node)
+                }
+            }
+        }
+    }
+}
+
+/// Time interval bounds.
+#[derive(Debug, Default)]
+struct Bounds {
+    start: Option<(String, bool)>,
+    end: Option<(String, bool)>,
+}
+
+impl Bounds {
+    /// Convert the bounds into a time interval, `None` is returned for unbounded intervals.
+    fn interval(&self) -> Result<Option<RangeOrValue<TimeDeltaOrStatic>>, String> {
+        let value = match (&self.start, &self.end) {
+            (Some((start, true)), Some((end, false))) => format!("{start}..{end}"),
+            (Some((start, true)), Some((end, true))) => format!("{start}..={end}"),
+            (Some((start, false)), Some(_)) => {
+                return Err(format!("unsupported exclusive interval start: {start}"));
+            }
+            (Some((start, true)), None) => start.clone(),
+            (Some((start, false)), None) => format!(">{start}"),
+            (None, Some((end, false))) => format!("<{end}"),
+            (None, Some((end, true))) => format!("<={end}"),
+            (None, None) => return Ok(None),
+        };
+
+        value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("unsupported time interval: {value}"))
+    }
+}
+
+/// URL parameter conversion state.
+#[derive(Debug, Default)]
+struct Import {
+    params: Parameters,
+    warnings: Vec<String>,
+    created: Bounds,
+    updated: Bounds,
+}
+
+impl Import {
+    fn warn<S: Into<String>>(&mut self, msg: S) {
+        self.warnings.push(msg.into());
+    }
+
+    fn convert(&mut self, mut pairs: IndexMap<String, Vec<String>>) {
+        // parameters modifying other parameters
+        let mut modifier = |name: &str| {
+            pairs
+                .shift_remove(name)
+                .and_then(|x| x.into_iter().next())
+                .unwrap_or_default()
+        };
+        let bug_id_type = modifier("bug_id_type");
+        let short_desc_type = modifier("short_desc_type");
+        let longdesc_type = modifier("longdesc_type");
+        let whiteboard_type = modifier("status_whiteboard_type");
+        let keywords_type = modifier("keywords_type");
+        let chfieldfrom = modifier("chfieldfrom");
+        let chfieldto = modifier("chfieldto");
+        let chfieldvalue = modifier("chfieldvalue");
+        let j_top = modifier("j_top");
+
+        let mut rows = BTreeMap::<u64, Row>::new();
+        let mut chfields = vec![];
+
+        for (key, values) in pairs {
+            // skip empty parameters that the web interface commonly includes
+            let values: Vec<_> = values.into_iter().filter(|x| !x.is_empty()).collect();
+            if values.is_empty() || IGNORED.contains(&key.as_str()) {
+                continue;
+            }
+
+            match key.as_str() {
+                "bug_status" => {
+                    let status = self.params.status.get_or_insert_with(Default::default);
+                    for value in values {
+                        match value.as_str() {
+                            "__open__" => status.push("@open".to_string()),
+                            "__closed__" => status.push("@closed".to_string()),
+                            "__all__" => status.push("@all".to_string()),
+                            _ => status.push(value),
+                        }
+                    }
+                }
+                "product" => self.exact(values, |x| &mut x.product),
+                "component" => self.exact(values, |x| &mut x.component),
+                "version" => self.exact(values, |x| &mut x.version),
+                "resolution" => self.exact(values, |x| &mut x.resolution),
+                "op_sys" => self.exact(values, |x| &mut x.os),
+                "platform" | "rep_platform" => self.exact(values, |x| &mut x.platform),
+                "target_milestone" => self.exact(values, |x| &mut x.target),
+                "priority" => {
+                    let matches = values.iter().map(|x| vec![format!("== {x}").into()]);
+                    self.params
+                        .priority
+                        .get_or_insert_with(Default::default)
+                        .extend(matches);
+                }
+                "bug_severity" => {
+                    let matches = values.iter().map(|x| vec![format!("== {x}").into()]);
+                    self.params
+                        .severity
+                        .get_or_insert_with(Default::default)
+                        .extend(matches);
+                }
+                "bug_id" => self.ids(&values, &bug_id_type),
+                "short_desc" => self.summary(&values, &short_desc_type),
+                "longdesc" => self.comment(&values, &longdesc_type),
+                "status_whiteboard" => self.whiteboard(&values, &whiteboard_type),
+                "keywords" => self.keywords(&values, &keywords_type),
+                "chfield" => chfields.extend(values),
+                "order" => self.order(&values),
+                "include_fields" => self.fields(&values),
+                "quicksearch" => self.params.quicksearch = values.into_iter().next(),
+                "limit" => match values[0].parse() {
+                    // zero disables the limit in the web interface
+                    Ok(0) => (),
+                    Ok(value) => self.params.limit = Some(value),
+                    Err(_) => self.warn(format!("invalid limit: {}", values[0])),
+                },
+                "offset" => match values[0].parse() {
+                    Ok(value) => self.params.offset = Some(value),
+                    Err(_) => self.warn(format!("invalid offset: {}", values[0])),
+                },
+                _ => {
+                    // advanced field rows, e.g. f1, o1, v1, n1, and j1
+                    let mut chars = key.chars();
+                    let kind = chars.next().unwrap_or_default();
+                    match chars.as_str().parse::<u64>() {
+                        Ok(num) if "fovnj".contains(kind) => {
+                            let row = rows.entry(num).or_default();
+                            let value = values.into_iter().next().unwrap_or_default();
+                            match kind {
+                                'f' => row.field = value,
+                                'o' => row.op = value,
+                                'v' => row.value = value,
+                                'n' => row.negate = value == "1",
+                                _ => row.join = value,
+                            }
+                        }
+                        _ => self.warn(format!("unsupported parameter: {key}")),
+                    }
+                }
+            }
+        }
+
+        self.chfields(&chfields, &chfieldfrom, &chfieldto, &chfieldvalue);
+        self.advanced(rows, j_top == "OR");
+
+        match self.created.interval() {
+            Ok(value) => self.params.created = value,
+            Err(e) => self.warn(e),
+        }
+        match self.updated.interval() {
+            Ok(value) => self.params.updated = value,
+            Err(e) => self.warn(e),
+        }
+    }
+
+    /// Add exact value matches.
+    fn exact<F>(&mut self, values: Vec<String>, field: F)
+    where
+        F: FnOnce(&mut Parameters) -> &mut Option<Vec<Match>>,
+    {
+        let matches = values.iter().map(|x| format!("== {x}").into());
+        field(&mut self.params)
+            .get_or_insert_with(Default::default)
+            .extend(matches);
+    }
+
+    fn ids(&mut self, values: &[String], kind: &str) {
+        if !matches!(kind, "" | "anyexact") {
+            self.warn(format!("unsupported bug ID search type: {kind}"));
+            return;
+        }
+
+        let mut ids = vec![];
+        for value in values.iter().flat_map(|x| x.split(',')) {
+            match value.trim().parse() {
+                Ok(id) => ids.push(RangeOrValue::Value(id)),
+                Err(_) => self.warn(format!("invalid bug ID: {value}")),
+            }
+        }
+
+        if !ids.is_empty() {
+            self.params
+                .ids
+                .get_or_insert_with(Default::default)
+                .push(ExistsOrValues::Values(ids));
+        }
+    }
+
+    fn summary(&mut self, values: &[String], kind: &str) {
+        for value in values {
+            match text_matches(kind, value) {
+                Ok(matches) => {
+                    self.params
+                        .summary
+                        .get_or_insert_with(Default::default)
+                        .extend(matches);
+                }
+                Err(e) => self.warn(e),
+            }
+        }
+    }
+
+    fn comment(&mut self, values: &[String], kind: &str) {
+        for value in values {
+            match text_matches(kind, value) {
+                Ok(matches) => {
+                    self.params
+                        .comment
+                        .get_or_insert_with(Default::default)
+                        .extend(matches);
+                }
+                Err(e) => self.warn(e),
+            }
+        }
+    }
+
+    fn whiteboard(&mut self, values: &[String], kind: &str) {
+        for value in values {
+            match text_matches(kind, value) {
+                Ok(matches) => {
+                    self.params
+                        .whiteboard
+                        .get_or_insert_with(Default::default)
+                        .push(ExistsOrValues::Values(matches));
+                }
+                Err(e) => self.warn(e),
+            }
+        }
+    }
+
+    fn keywords(&mut self, values: &[String], kind: &str) {
+        let words = values
+            .iter()
+            .flat_map(|x| x.split([',', ' ']))
+            .filter(|x| !x.is_empty());
+        let keywords = self.params.keywords.get_or_insert_with(Default::default);
+        match kind {
+            "" | "allwords" => {
+                let matches = words.map(|x| format!("== {x}").into()).collect();
+                keywords.push(ExistsOrValues::Values(matches));
+            }
+            "anywords" => {
+                keywords.extend(words.map(|x| ExistsOrValues::Values(vec![x.into()])));
+            }
+            "nowords" => {
+                let matches = words.map(|x| format!("!= {x}").into()).collect();
+                keywords.push(ExistsOrValues::Values(matches));
+            }
+            _ => {
+                self.warn(format!("unsupported keywords search type: {kind}"));
+            }
+        }
+
+        if self.params.keywords.as_ref().is_some_and(|x| x.is_empty()) {
+            self.params.keywords = None;
+        }
+    }
+
+    fn order(&mut self, values: &[String]) {
+        for value in values.iter().flat_map(|x| x.split(',')) {
+            let value = value.trim();
+            let (field, descending) = match value.split_once(' ') {
+                Some((field, "DESC")) => (field, true),
+                Some((field, "ASC")) => (field, false),
+                _ => (value, false),
+            };
+
+            match OrderField::iter().find(|x| x.api() == field) {
+                Some(field) if descending => self
+                    .params
+                    .order
+                    .get_or_insert_with(Default::default)
+                    .push(Order::Descending(field)),
+                Some(field) => self
+                    .params
+                    .order
+                    .get_or_insert_with(Default::default)
+                    .push(Order::Ascending(field)),
+                None => self.warn(format!("unsupported order field: {field}")),
+            }
+        }
+    }
+
+    fn fields(&mut self, values: &[String]) {
+        for value in values.iter().flat_map(|x| x.split(',')) {
+            match FilterField::iter().find(|x| x.api() == value) {
+                Some(field) => self
+                    .params
+                    .fields
+                    .get_or_insert_with(Default::default)
+                    .push(field),
+                None => self.warn(format!("unsupported field: {value}")),
+            }
+        }
+    }
+
+    /// Convert fields changed within a time interval.
+    fn chfields(&mut self, fields: &[String], from: &str, to: &str, value: &str) {
+        let (from, to) = match (time(from), time(to)) {
+            (Ok(from), Ok(to)) => (from, to),
+            (Err(e), _) | (_, Err(e)) => {
+                self.warn(e);
+                return;
+            }
+        };
+
+        // changes without specified fields relate to the last change time
+        if fields.is_empty() {
+            self.updated.start = from.map(|x| (x, true));
+            self.updated.end = to.map(|x| (x, true));
+            if !value.is_empty() {
+                self.warn("unsupported change value without field: chfieldvalue");
+            }
+            return;
+        }
+
+        for field in fields {
+            if field == "[Bug creation]" {
+                self.created.start = from.clone().map(|x| (x, true));
+                self.created.end = to.clone().map(|x| (x, true));
+                continue;
+            }
+
+            let Some(name) = field_name(field) else {
+                self.warn(format!("unsupported change field: {field}"));
+                continue;
+            };
+
+            let interval = match (&from, &to) {
+                (Some(from), Some(to)) => format!("{from}..={to}"),
+                (Some(from), None) => from.clone(),
+                (None, Some(to)) => format!("<={to}"),
+                (None, None) => "<now".to_string(),
+            };
+            match Changed::from_str(&format!("{name}={interval}")) {
+                Ok(value) => self
+                    .params
+                    .changed
+                    .get_or_insert_with(Default::default)
+                    .push(value),
+                Err(e) => self.warn(e.to_string()),
+            }
+
+            if !value.is_empty() {
+                let value = ChangedValue::from_str(&format!("{name}={value}")).unwrap();
+                self.params
+                    .changed_to
+                    .get_or_insert_with(Default::default)
+                    .push(value);
+            }
+        }
+    }
+
+    /// Convert advanced field rows into search parameters and expressions.
+    fn advanced(&mut self, rows: BTreeMap<u64, Row>, or: bool) {
+        // build nested chart groups
+        let mut stack: Vec<(Row, Vec<Item>)> = vec![(Row::default(), vec![])];
+        for (_, row) in rows {
+            match row.field.as_str() {
+                "" | "noop" => (),
+                "OP" => stack.push((row, vec![])),
+                "CP" => {
+                    if stack.len() > 1 {
+                        // empty groups are ignored by Bugzilla
+                        let (row, items) = stack.pop().unwrap();
+                        if !items.is_empty() {
+                            stack.last_mut().unwrap().1.push(Item::group(&row, items));
+                        }
+                    } else {
+                        self.warn("unbalanced advanced field group");
+                    }
+                }
+                _ => stack.last_mut().unwrap().1.push(Item::Term {
+                    field: row.field,
+                    op: row.op,
+                    value: row.value,
+                    negate: row.negate,
+                }),
+            }
+        }
+
+        if stack.len() > 1 {
+            self.warn("unclosed advanced field group");
+            while stack.len() > 1 {
+                let (row, items) = stack.pop().unwrap();
+                if !items.is_empty() {
+                    stack.last_mut().unwrap().1.push(Item::group(&row, items));
+                }
+            }
+        }
+
+        let (_, items) = stack.pop().unwrap();
+        let mut nodes = vec![];
+        for item in items {
+            // top-level terms can use dedicated parameters when implicitly AND-ed
+            if !or && self.special(&item) {
+                continue;
+            }
+
+            match item.node() {
+                Ok(node) => nodes.push(node),
+                Err(e) => self.warn(format!("{e}: skipping advanced field: {}", item.raw())),
+            }
+        }
+
+        if or && !nodes.is_empty() {
+            self.expression(&group(nodes, Node::Or));
+        } else {
+            for node in nodes {
+                self.expression(&node);
+            }
+        }
+    }
+
+    /// Convert top-level terms for fields unsupported by expressions.
+    ///
+    /// Returns `true` if the item was handled.
+    fn special(&mut self, item: &Item) -> bool {
+        let Item::Term {
+            field,
+            op,
+            value,
+            negate,
+        } = item
+        else {
+            return false;
+        };
+
+        if !negate && matches!(field.as_str(), "creation_ts" | "delta_ts") {
+            let inclusive = match op.as_str() {
+                "greaterthaneq" | "lessthaneq" => op.ends_with("eq"),
+                "greaterthan" | "lessthan" => false,
+                _ => return false,
+            };
+
+            let value = match time(value) {
+                Ok(Some(value)) => (value, inclusive),
+                Ok(None) => return true,
+                Err(e) => {
+                    self.warn(e);
+                    return true;
+                }
+            };
+
+            let bounds = if field == "creation_ts" {
+                &mut self.created
+            } else {
+                &mut self.updated
+            };
+            if op.starts_with("greater") {
+                bounds.start = Some(value);
+            } else {
+                bounds.end = Some(value);
+            }
+            return true;
+        }
+
+        if !matches!(
+            op.as_str(),
+            "changedafter" | "changedbefore" | "changedby" | "changedfrom" | "changedto"
+        ) {
+            return false;
+        }
+
+        let Some(name) = field_name(field) else {
+            self.warn(format!("unsupported change field: {field}"));
+            return true;
+        };
+        let prefix = if *negate { "!" } else { "" };
+
+        match op.as_str() {
+            "changedafter" | "changedbefore" => {
+                let value = match time(value) {
+                    Ok(Some(value)) if op == "changedafter" => value,
+                    Ok(Some(value)) => format!("<{value}"),
+                    Ok(None) => "<now".to_string(),
+                    Err(e) => {
+                        self.warn(e);
+                        return true;
+                    }
+                };
+                let value = Changed::from_str(&format!("{prefix}{name}={value}")).unwrap();
+                self.params
+                    .changed
+                    .get_or_insert_with(Default::default)
+                    .push(value);
+            }
+            _ if *negate => {
+                self.warn(format!("unsupported negated change: {}", item.raw()));
+            }
+            "changedby" => {
+                let value = ChangedBy::from_str(&format!("{name}={value}")).unwrap();
+                self.params
+                    .changed_by
+                    .get_or_insert_with(Default::default)
+                    .push(value);
+            }
+            _ => {
+                let value = ChangedValue::from_str(&format!("{name}={value}")).unwrap();
+                let values = if op == "changedfrom" {
+                    &mut self.params.changed_from
+                } else {
+                    &mut self.params.changed_to
+                };
+                values.get_or_insert_with(Default::default).push(value);
+            }
+        }
+
+        true
+    }
+
+    /// Add an expression node to the query parameters.
+    fn expression(&mut self, node: &Node) {
+        let value = render(node);
+        match Expression::from_str(&value) {
+            Ok(expr) => self
+                .params
+                .query
+                .get_or_insert_with(Default::default)
+                .push(expr),
+            Err(e) => self.warn(e.to_string()),
+        }
+    }
+}
+
+impl Item {
+    /// Render the raw advanced field parameters for warnings.
+    fn raw(&self) -> String {
+        match self {
+            Self::Term {
+                field,
+                op,
+                value,
+                negate,
+            } => {
+                let prefix = if *negate { "!" } else { "" };
+                format!("{prefix}{field} {op} {value}")
+            }
+            Self::Group { or, negate, items } => {
+                let prefix = if *negate { "!" } else { "" };
+                let join = if *or { " OR " } else { " AND " };
+                format!("{prefix}({})", items.iter().map(|x| x.raw()).join(join))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::service::bugzilla::Bugzilla;
+    use crate::test::*;
+
+    use super::*;
+
+    fn parse(url: &str) -> (Parameters, Vec<String>) {
+        Parameters::from_url(&Url::parse(url).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn from_url() {
+        // invalid path
+        let url = Url::parse("https://bugzilla.test/show_bug.cgi?id=1").unwrap();
+        let err = Parameters::from_url(&url).unwrap_err();
+        assert_err_re!(err, "unsupported search URL: ");
+
+        // empty query
+        let (params, warnings) = parse("https://bugzilla.test/buglist.cgi");
+        assert_eq!(params, Parameters::default());
+        assert!(warnings.is_empty());
+
+        // standard fields
+        let (params, warnings) = parse(
+            "https://bugzilla.test/buglist.cgi?query_format=advanced&product=Foo&product=Bar\
+             &bug_status=__closed__&bug_status=NEW&bug_id=1,2&short_desc=foo%20bar\
+             &short_desc_type=allwordssubstr&keywords=a%2Cb&keywords_type=anywords\
+             &priority=High&order=bug_id%20DESC,short_desc&limit=0&offset=10&columnlist=short_desc",
+        );
+        assert_eq!(params.product.unwrap(), ["== Foo".into(), "== Bar".into()]);
+        assert_eq!(params.status.unwrap(), ["@closed", "NEW"]);
+        assert_eq!(
+            params.ids.unwrap(),
+            [ExistsOrValues::Values(vec![
+                RangeOrValue::Value(1),
+                RangeOrValue::Value(2)
+            ])]
+        );
+        assert_eq!(params.summary.unwrap(), ["~~ foo".into(), "~~ bar".into()]);
+        assert_eq!(params.keywords.unwrap().len(), 2);
+        assert_eq!(params.priority.unwrap(), [vec!["== High".into()]]);
+        assert_eq!(
+            params.order.unwrap(),
+            [
+                Order::Descending(OrderField::Id),
+                Order::Ascending(OrderField::Summary)
+            ]
+        );
+        assert!(params.limit.is_none());
+        assert_eq!(params.offset, Some(10));
+        assert_eq!(warnings, ["unsupported parameter: columnlist"]);
+
+        // change intervals
+        let (params, warnings) = parse(
+            "https://bugzilla.test/buglist.cgi?chfield=bug_status&chfield=%5BBug%20creation%5D\
+             &chfield=nonexistent&chfieldfrom=-1w&chfieldto=Now&chfieldvalue=RESOLVED",
+        );
+        assert_eq!(params.changed.unwrap()[0].to_string(), "status=1w");
+        assert_eq!(params.changed_to.unwrap()[0].to_string(), "status=RESOLVED");
+        assert_eq!(params.created.unwrap().to_string(), "1w");
+        assert_eq!(warnings, ["unsupported change field: nonexistent"]);
+
+        // word-based text search types
+        let (params, warnings) =
+            parse("https://bugzilla.test/buglist.cgi?longdesc=foo%20bar&longdesc_type=anywords");
+        assert_eq!(params.comment.unwrap(), ["=| foo bar".into()]);
+        assert!(warnings.is_empty());
+        let (params, warnings) = parse(
+            "https://bugzilla.test/buglist.cgi?short_desc=foo%20bar&short_desc_type=anywordssubstr\
+             &longdesc=baz%20qux&longdesc_type=anywordssubstr",
+        );
+        assert_eq!(params.summary.unwrap(), ["~| foo bar".into()]);
+        assert_eq!(params.comment.unwrap(), ["~| baz qux".into()]);
+        assert!(params.query.is_none());
+        assert!(warnings.is_empty());
+
+        // changes without fields relate to the last update
+        let (params, _) =
+            parse("https://bugzilla.test/buglist.cgi?chfieldfrom=2024-01-01&chfieldto=2024-02-01");
+        assert_eq!(
+            params.updated.unwrap().to_string(),
+            "2024-01-01..=2024-02-01"
+        );
+
+        // advanced fields
+        let (params, warnings) = parse(
+            "https://bugzilla.test/buglist.cgi?f1=OP&j1=OR&f2=product&o2=equals&v2=Foo\
+             &f3=component&o3=substring&v3=bar%20baz&f4=CP&n5=1&f5=keywords&o5=equals&v5=VERIFIED\
+             &f6=creation_ts&o6=greaterthaneq&v6=-2d&f7=creation_ts&o7=lessthan&v7=-1d\
+             &f8=bug_status&o8=changedafter&v8=2024-01-01&f9=longdescs.count&o9=equals&v9=1\
+             &f10=bug_severity&o10=casesubstring&v10=major&f11=noop",
+        );
+        assert_ordered_eq!(
+            params.query.unwrap().iter().map(|x| x.to_string()),
+            [r#"product=Foo | component~"bar baz""#, "!keywords=VERIFIED"]
+        );
+        assert_eq!(params.created.unwrap().to_string(), "2d..1d");
+        assert_eq!(params.changed.unwrap()[0].to_string(), "status=2024-01-01");
+        assert_eq!(
+            warnings,
+            [
+                "unsupported field: longdescs.count: skipping advanced field: longdescs.count equals 1",
+                "unsupported operator: casesubstring: skipping advanced field: bug_severity casesubstring major",
+            ]
+        );
+
+        // top-level OR joins all expressions
+        let (params, warnings) = parse(
+            "https://bugzilla.test/buglist.cgi?j_top=OR&f1=product&o1=anyexact&v1=Foo,Bar\
+             &f2=OP&n2=1&f3=cf_test&o3=regexp&v3=^a&f4=short_desc&o4=allwordssubstr&v4=a%20b\
+             &f5=CP",
+        );
+        assert_ordered_eq!(
+            params.query.unwrap().iter().map(|x| x.to_string()),
+            ["product=Foo | product=Bar | !(cf_test=*\"^a\" & summary~a & summary~b)"]
+        );
+        assert!(warnings.is_empty());
+
+        // unbalanced groups
+        let (params, warnings) =
+            parse("https://bugzilla.test/buglist.cgi?f1=CP&f2=OP&f3=product&o3=equals&v3=Foo");
+        assert_eq!(params.query.unwrap()[0].to_string(), "product=Foo");
+        assert_eq!(
+            warnings,
+            [
+                "unbalanced advanced field group",
+                "unclosed advanced field group"
+            ]
+        );
+    }
+
+    #[test]
+    fn search_url_roundtrip() {
+        let service = Bugzilla::new("https://bugzilla.test").unwrap();
+        let mut request = service.search();
+        request.params.product = Some(vec!["Foo".into()]);
+        request
+            .summary(["bar"])
+            .status(["@all"])
+            .order([Order::Descending(OrderField::Updated)]);
+        let url = request.search_url().unwrap();
+        let (params, warnings) = Parameters::from_url(&url).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(params.status.unwrap(), ["@all"]);
+        assert_eq!(
+            params.order.unwrap(),
+            [Order::Descending(OrderField::Updated)]
+        );
+        assert_ordered_eq!(
+            params.query.unwrap().iter().map(|x| x.to_string()),
+            ["summary~bar", "product~Foo"]
+        );
+    }
+}
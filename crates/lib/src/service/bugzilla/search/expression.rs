@@ -0,0 +1,325 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::Error;
+use crate::traits::Api;
+
+use super::ChangeField;
+
+/// Expression term comparison operators.
+#[derive(Display, EnumIter, EnumString, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operator {
+    #[strum(serialize = "!=")]
+    NotEquals,
+    #[strum(serialize = "!~")]
+    NotSubstring,
+    #[strum(serialize = "!*")]
+    NotRegexp,
+    #[strum(serialize = "=*")]
+    Regexp,
+    #[strum(serialize = "<=")]
+    LessOrEqual,
+    #[strum(serialize = ">=")]
+    GreaterOrEqual,
+    #[strum(serialize = "=")]
+    Equals,
+    #[strum(serialize = "~")]
+    Substring,
+    #[strum(serialize = "<")]
+    Less,
+    #[strum(serialize = ">")]
+    Greater,
+}
+
+impl Api for Operator {
+    fn api(&self) -> String {
+        let value = match self {
+            Self::NotEquals => "notequals",
+            Self::NotSubstring => "notsubstring",
+            Self::NotRegexp => "notregexp",
+            Self::Regexp => "regexp",
+            Self::LessOrEqual => "lessthaneq",
+            Self::GreaterOrEqual => "greaterthaneq",
+            Self::Equals => "equals",
+            Self::Substring => "substring",
+            Self::Less => "lessthan",
+            Self::Greater => "greaterthan",
+        };
+        value.to_string()
+    }
+}
+
+/// Parsed expression node.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Node {
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Box<Node>),
+    Term {
+        field: String,
+        op: Operator,
+        value: String,
+    },
+}
+
+impl Node {
+    /// Return the Bugzilla field name for a term field.
+    pub(super) fn field_api(field: &str) -> String {
+        // fields are validated during parsing
+        ChangeField::from_str(field)
+            .map(|x| x.api())
+            .unwrap_or_else(|_| field.to_string())
+    }
+}
+
+/// Boolean search expression, e.g. `(product=Foo | component~bar) & !keywords=VERIFIED`.
+#[derive(DeserializeFromStr, SerializeDisplay, Debug, PartialEq, Eq, Clone)]
+pub struct Expression {
+    raw: String,
+    node: Node,
+}
+
+impl Expression {
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+}
+
+impl FromStr for Expression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let mut parser = Parser { s, pos: 0 };
+        let node = parser.or()?;
+        parser.skip_whitespace();
+        if let Some(c) = parser.peek() {
+            return Err(parser.error(format!("unexpected character: {c}")));
+        }
+
+        Ok(Self {
+            raw: s.to_string(),
+            node,
+        })
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// Recursive descent expression parser.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, msg: String) -> Error {
+        Error::InvalidValue(format!("invalid query: {msg}: {}", self.s))
+    }
+
+    fn rest(&self) -> &str {
+        &self.s[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consume a character if it's next in the input.
+    fn consume(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> crate::Result<Node> {
+        let mut nodes = vec![self.and()?];
+        while self.consume('|') {
+            nodes.push(self.and()?);
+        }
+        if nodes.len() == 1 {
+            Ok(nodes.remove(0))
+        } else {
+            Ok(Node::Or(nodes))
+        }
+    }
+
+    fn and(&mut self) -> crate::Result<Node> {
+        let mut nodes = vec![self.unary()?];
+        while self.consume('&') {
+            nodes.push(self.unary()?);
+        }
+        if nodes.len() == 1 {
+            Ok(nodes.remove(0))
+        } else {
+            Ok(Node::And(nodes))
+        }
+    }
+
+    fn unary(&mut self) -> crate::Result<Node> {
+        if self.consume('!') {
+            // collapse double negation
+            match self.unary()? {
+                Node::Not(node) => Ok(*node),
+                node => Ok(Node::Not(Box::new(node))),
+            }
+        } else if self.consume('(') {
+            let node = self.or()?;
+            if !self.consume(')') {
+                return Err(self.error("unclosed group".to_string()));
+            }
+            Ok(node)
+        } else {
+            self.term()
+        }
+    }
+
+    fn term(&mut self) -> crate::Result<Node> {
+        self.skip_whitespace();
+
+        // field name
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("missing field".to_string()));
+        }
+        let field = self.rest()[..len].to_string();
+        ChangeField::from_str(&field).map_err(|_| self.error(format!("invalid field: {field}")))?;
+        self.pos += len;
+
+        // operator, preferring the longest match
+        self.skip_whitespace();
+        let Some(op) = Operator::iter().find(|x| self.rest().starts_with(&x.to_string())) else {
+            return Err(self.error(format!("missing operator for field: {field}")));
+        };
+        self.pos += op.to_string().len();
+
+        // value, optionally quoted
+        self.skip_whitespace();
+        let value = if self.consume('"') {
+            let mut value = String::new();
+            let mut chars = self.rest().char_indices();
+            loop {
+                match chars.next() {
+                    Some((i, '"')) => {
+                        self.pos += i + 1;
+                        break;
+                    }
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, c)) => value.push(c),
+                        None => return Err(self.error("unclosed quote".to_string())),
+                    },
+                    Some((_, c)) => value.push(c),
+                    None => return Err(self.error("unclosed quote".to_string())),
+                }
+            }
+            value
+        } else {
+            let len = self
+                .rest()
+                .find(|c: char| c.is_whitespace() || "()&|".contains(c))
+                .unwrap_or(self.rest().len());
+            let value = self.rest()[..len].to_string();
+            self.pos += len;
+            value
+        };
+
+        if value.is_empty() {
+            return Err(self.error(format!("missing value for field: {field}")));
+        }
+
+        Ok(Node::Term { field, op, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::*;
+
+    use super::*;
+
+    fn term(field: &str, op: Operator, value: &str) -> Node {
+        Node::Term {
+            field: field.to_string(),
+            op,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        // valid
+        let expr: Expression = "(product=Foo | component~bar) & !keywords=VERIFIED"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            expr.node(),
+            &Node::And(vec![
+                Node::Or(vec![
+                    term("product", Operator::Equals, "Foo"),
+                    term("component", Operator::Substring, "bar"),
+                ]),
+                Node::Not(Box::new(term("keywords", Operator::Equals, "VERIFIED"))),
+            ])
+        );
+        assert_eq!(
+            expr.to_string(),
+            "(product=Foo | component~bar) & !keywords=VERIFIED"
+        );
+
+        // operator precedence
+        let expr: Expression = "status=NEW|status=ASSIGNED&priority>=High".parse().unwrap();
+        assert_eq!(
+            expr.node(),
+            &Node::Or(vec![
+                term("status", Operator::Equals, "NEW"),
+                Node::And(vec![
+                    term("status", Operator::Equals, "ASSIGNED"),
+                    term("priority", Operator::GreaterOrEqual, "High"),
+                ]),
+            ])
+        );
+
+        // quoted values, double negation, and custom fields
+        let expr: Expression = r#"!!summary!~"foo \"bar\"" & cf_test=*^a"#.parse().unwrap();
+        assert_eq!(
+            expr.node(),
+            &Node::And(vec![
+                term("summary", Operator::NotSubstring, r#"foo "bar""#),
+                term("cf_test", Operator::Regexp, "^a"),
+            ])
+        );
+
+        // invalid
+        for (s, msg) in [
+            ("", "missing field"),
+            ("product", "missing operator for field: product"),
+            ("product=", "missing value for field: product"),
+            ("nonexistent=value", "invalid field: nonexistent"),
+            ("(product=Foo", "unclosed group"),
+            ("product=Foo)", "unexpected character: \\)"),
+            (r#"summary="foo"#, "unclosed quote"),
+            ("product=Foo &", "missing field"),
+        ] {
+            let err = s.parse::<Expression>().unwrap_err();
+            assert_err_re!(err, format!("invalid query: {msg}"), s);
+        }
+    }
+}
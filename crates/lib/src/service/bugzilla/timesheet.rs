@@ -0,0 +1,167 @@
+use std::fmt;
+
+use futures_util::TryStreamExt;
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::objects::bugzilla::Hours;
+use crate::service::bugzilla::{BugField, search};
+use crate::traits::RequestSend;
+
+/// Time tracking totals for a group of bugs.
+#[derive(Serialize, Debug, Default, PartialEq, Eq, Clone)]
+pub struct Entry {
+    pub assignee: String,
+    pub bugs: usize,
+    pub estimated: Hours,
+    pub worked: Hours,
+    pub remaining: Hours,
+}
+
+impl Entry {
+    fn new<S: Into<String>>(assignee: S) -> Self {
+        Self {
+            assignee: assignee.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Return the percentage of work completed based on hours worked and remaining.
+    pub fn complete(&self) -> f64 {
+        let total = self.worked.value() + self.remaining.value();
+        if total > 0.0 {
+            self.worked.value() / total * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<30} {:>5} {:>10.2} {:>10.2} {:>10.2} {:>5.0}%",
+            self.assignee,
+            self.bugs,
+            self.estimated,
+            self.worked,
+            self.remaining,
+            self.complete()
+        )
+    }
+}
+
+/// Time tracking summary for search results grouped by assignee.
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+pub struct Timesheet {
+    pub entries: Vec<Entry>,
+    pub total: Entry,
+}
+
+impl fmt::Display for Timesheet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<30} {:>5} {:>10} {:>10} {:>10} {:>6}",
+            "Assignee", "Bugs", "Estimated", "Worked", "Remaining", "Done"
+        )?;
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        write!(f, "{}", self.total)
+    }
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub search: search::Request,
+}
+
+impl Request {
+    pub(super) fn new(search: search::Request) -> Self {
+        Self { search }
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Timesheet;
+
+    /// Return the time tracking totals for the matching bugs, ordered by assignee.
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let mut search = self.search.clone();
+        search.fields([
+            BugField::Id,
+            BugField::Assignee,
+            BugField::EstimatedTime,
+            BugField::ActualTime,
+            BugField::RemainingTime,
+        ]);
+        let bugs: Vec<_> = search.stream().try_collect().await?;
+
+        let mut entries = IndexMap::<String, Entry>::new();
+        let mut total = Entry::new("Total");
+        for bug in bugs {
+            let assignee = bug.assigned_to.unwrap_or_else(|| "None".to_string());
+            let entry = entries
+                .entry(assignee)
+                .or_insert_with_key(|x| Entry::new(x));
+            for entry in [entry, &mut total] {
+                entry.bugs += 1;
+                entry.estimated = entry.estimated + bug.estimated_time.unwrap_or_default();
+                entry.worked = entry.worked + bug.actual_time.unwrap_or_default();
+                entry.remaining = entry.remaining + bug.remaining_time.unwrap_or_default();
+            }
+        }
+
+        entries.sort_keys();
+        Ok(Timesheet {
+            entries: entries.into_values().collect(),
+            total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::service::bugzilla::Bugzilla;
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+
+        server
+            .respond_match(
+                |req: &wiremock::Request| {
+                    req.url.query_pairs().any(|(k, v)| {
+                        k == "include_fields"
+                            && v == "id,assigned_to,estimated_time,actual_time,remaining_time"
+                    })
+                },
+                200,
+                path.join("timesheet/search.json"),
+            )
+            .await;
+
+        let timesheet = service.timesheet(service.search()).send().await.unwrap();
+        let assignees: Vec<_> = timesheet.entries.iter().map(|x| &x.assignee).collect();
+        assert_eq!(assignees, ["user1@bugbite.test", "user2@bugbite.test"]);
+        let entry = &timesheet.entries[0];
+        assert_eq!(entry.bugs, 2);
+        assert_eq!(entry.estimated, Hours::new(12.5).unwrap());
+        assert_eq!(entry.worked, Hours::new(6.0).unwrap());
+        assert_eq!(entry.remaining, Hours::new(6.5).unwrap());
+        assert_eq!(timesheet.total.bugs, 3);
+        assert_eq!(timesheet.total.estimated, Hours::new(16.5).unwrap());
+        assert_eq!(timesheet.total.complete(), 50.0);
+        assert_eq!(
+            timesheet.to_string().lines().last().unwrap(),
+            "Total                              3      16.50       7.50       7.50    50%"
+        );
+    }
+}
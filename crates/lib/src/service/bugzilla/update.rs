@@ -1,3 +1,5 @@
+pub mod bulk;
+
 use std::borrow::Cow;
 use std::hash::Hash;
 use std::ops::Deref;
@@ -5,6 +7,7 @@ use std::str::FromStr;
 use std::{fmt, fs};
 
 use camino::Utf8PathBuf;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -13,9 +16,11 @@ use strum::{Display, EnumString};
 use url::Url;
 
 use crate::Error;
-use crate::objects::{Range, bugzilla::Flag};
+use crate::objects::Range;
+use crate::objects::bugzilla::{Bug, Event, Flag, Hours};
 use crate::serde::non_empty_str;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
+use crate::time::TimeDeltaOrStatic;
 use crate::traits::{Contains, InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
 
 /// Changes made to a field.
@@ -67,6 +72,30 @@ impl fmt::Display for BugChange {
     }
 }
 
+/// Conflicting changes made to a bug since it was last read.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Conflict {
+    pub id: String,
+    pub events: Vec<Event>,
+}
+
+impl Conflict {
+    /// Return the names of the conflicting fields.
+    pub fn fields(&self) -> IndexSet<&str> {
+        self.events
+            .iter()
+            .flat_map(|x| &x.changes)
+            .map(|x| x.field_name.as_str())
+            .collect()
+    }
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bug {}: {}", self.id, self.fields().iter().join(", "))
+    }
+}
+
 #[derive(DeserializeFromStr, SerializeDisplay, Debug, PartialEq, Eq, Clone)]
 pub enum RangeOrSet<T: FromStr + PartialOrd + Eq + Hash> {
     Range(Range<T>),
@@ -119,6 +148,14 @@ pub struct Request {
     service: Bugzilla,
     #[serde(skip)]
     pub ids: Vec<String>,
+    #[serde(skip)]
+    validate: bool,
+    #[serde(skip)]
+    check_conflicts: bool,
+    #[serde(skip)]
+    last_change: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    last_changes: IndexMap<String, DateTime<Utc>>,
     #[serde(flatten)]
     pub params: Parameters,
 }
@@ -129,14 +166,30 @@ impl RequestSend for Request {
     async fn send(&self) -> crate::Result<Self::Output> {
         let url = self.url()?;
         let params = self.encode().await?;
-        let request = self
-            .service
-            .client()
-            .put(url)
-            .json(&params)
-            .auth(&self.service)?;
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
+        if self.validate {
+            self.validate_products(&params).await?;
+        }
+        if self.check_conflicts {
+            let conflicts = self.find_conflicts(&params).await?;
+            if !conflicts.is_empty() {
+                return Err(Error::Conflict(conflicts.iter().join("; ")));
+            }
+        }
+
+        let mut data = if self.service.transport().await? == Transport::JsonRpc {
+            let value = serde_json::to_value(&params)
+                .map_err(|e| Error::InvalidValue(format!("failed serializing parameters: {e}")))?;
+            self.service.rpc("Bug.update", value, true).await?
+        } else {
+            let request = self
+                .service
+                .client()
+                .put(url)
+                .json(&params)
+                .auth(&self.service)?;
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
         let data = data["bugs"].take();
         let mut changes: Vec<BugChange> = serde_json::from_value(data)
             .map_err(|e| Error::InvalidResponse(format!("failed deserializing changes: {e}")))?;
@@ -172,10 +225,125 @@ impl Request {
         Self {
             service: service.clone(),
             ids: ids.into_iter().map(|s| s.to_string()).collect(),
+            validate: false,
+            check_conflicts: false,
+            last_change: None,
+            last_changes: Default::default(),
             params: Default::default(),
         }
     }
 
+    /// Enable or disable validating product-related values before updating.
+    pub fn validate(&mut self, value: bool) -> &mut Self {
+        self.validate = value;
+        self
+    }
+
+    /// Enable or disable aborting updates that conflict with changes made since bugs were read.
+    ///
+    /// Bug change times must be specified using [`Request::last_change_time`] or recorded
+    /// from retrieved bugs via [`Request::last_change_bugs`].
+    pub fn check_conflicts(&mut self, value: bool) -> &mut Self {
+        self.check_conflicts = value;
+        self
+    }
+
+    /// Set the last known change time for the targeted bugs.
+    pub fn last_change_time(&mut self, value: DateTime<Utc>) -> &mut Self {
+        self.last_change = Some(value);
+        self
+    }
+
+    /// Record the last change times of retrieved bugs, matching by ID or alias.
+    pub fn last_change_bugs<'a, I>(&mut self, bugs: I) -> &mut Self
+    where
+        I: IntoIterator<Item = &'a Bug>,
+    {
+        for bug in bugs {
+            if let Some(time) = bug.updated {
+                self.last_changes.insert(bug.id.to_string(), time);
+                for alias in &bug.alias {
+                    self.last_changes.insert(alias.clone(), time);
+                }
+            }
+        }
+        self
+    }
+
+    /// Return changes made since the targeted bugs were read that conflict with the update.
+    pub async fn conflicts(&self) -> crate::Result<Vec<Conflict>> {
+        let params = self.encode().await?;
+        self.find_conflicts(&params).await
+    }
+
+    /// Find changes to updated fields made since the targeted bugs were read.
+    async fn find_conflicts(&self, params: &RequestParameters<'_>) -> crate::Result<Vec<Conflict>> {
+        let fields = params.fields()?;
+        let mut conflicts = vec![];
+
+        for id in &self.ids {
+            let time = self
+                .last_change
+                .or_else(|| self.last_changes.get(id).copied())
+                .ok_or_else(|| {
+                    Error::InvalidRequest(format!("unknown last change time for bug: {id}"))
+                })?;
+            let since = time.to_rfc3339_opts(SecondsFormat::Secs, true);
+            let events = self
+                .service
+                .history([id])
+                .created_after(TimeDeltaOrStatic::from_str(&since)?)
+                .send()
+                .await?
+                .into_iter()
+                .next()
+                .unwrap_or_default();
+
+            // only changes after the last read time to updated fields conflict
+            let events: Vec<_> = events
+                .into_iter()
+                .filter(|x| x.when > time)
+                .filter_map(|mut event| {
+                    event.changes.retain(|x| fields.contains(&x.field_name));
+                    (!event.changes.is_empty()).then_some(event)
+                })
+                .collect();
+
+            if !events.is_empty() {
+                conflicts.push(Conflict {
+                    id: id.clone(),
+                    events,
+                });
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Verify product-related values are valid for the targeted bugs.
+    async fn validate_products(&self, params: &RequestParameters<'_>) -> crate::Result<()> {
+        let (component, version, target) =
+            (params.component, params.version, params.target_milestone);
+        if params.product.is_none() && component.is_none() && version.is_none() && target.is_none()
+        {
+            return Ok(());
+        }
+
+        // pull current products if not altering them
+        let names: IndexSet<_> = if let Some(value) = params.product {
+            [value.to_string()].into_iter().collect()
+        } else {
+            let bugs = self.service.get(&self.ids).send().await?;
+            bugs.into_iter().filter_map(|x| x.product).collect()
+        };
+
+        for product in self.service.product(names).send().await? {
+            product.validate(component, version, target)?;
+        }
+
+        Ok(())
+    }
+
     fn url(&self) -> crate::Result<Url> {
         let id = self
             .ids
@@ -207,6 +375,7 @@ impl Request {
             component: self.params.component.as_deref(),
             depends_on: self.params.depends.as_ref().map(|x| x.iter().collect()),
             dupe_of: self.params.duplicate_of,
+            estimated_time: self.params.estimated_time,
             flags: self.params.flags.as_deref(),
             groups: self.params.groups.as_ref().map(|x| x.iter().collect()),
             keywords: self.params.keywords.as_ref().map(|x| x.iter().collect()),
@@ -214,6 +383,7 @@ impl Request {
             platform: self.params.platform.as_deref(),
             priority: self.params.priority.as_deref(),
             product: self.params.product.as_deref(),
+            remaining_time: self.params.remaining_time,
             resolution: self.params.resolution.as_deref(),
             severity: self.params.severity.as_deref(),
             status: self.params.status.as_deref(),
@@ -222,7 +392,11 @@ impl Request {
             url: self.params.url.as_deref(),
             version: self.params.version.as_deref(),
             whiteboard: self.params.whiteboard.as_deref(),
-            custom_fields: self.params.custom_fields.as_ref(),
+            work_time: self.params.work_time,
+            custom_fields: self
+                .service
+                .encode_custom_fields(self.params.custom_fields.as_ref())
+                .await?,
             ..Default::default()
         };
 
@@ -247,6 +421,14 @@ impl Request {
             params.cc = Some(iter.collect());
         }
 
+        if let Some(value) = self.params.deadline.as_deref() {
+            // empty values clear the deadline
+            if !value.is_empty() && NaiveDate::from_str(value).is_err() {
+                return Err(Error::InvalidValue(format!("invalid deadline: {value}")));
+            }
+            params.deadline = Some(value);
+        }
+
         if let Some(value) = self.params.comment.as_deref() {
             params.comment = Some(Comment {
                 body: Cow::Borrowed(value),
@@ -392,8 +574,10 @@ pub struct Parameters {
     pub comment_is_private: Option<bool>,
     pub comment_privacy: Option<(RangeOrSet<usize>, TriBool)>,
     pub component: Option<String>,
+    pub deadline: Option<String>,
     pub depends: Option<Vec<SetChange<u64>>>,
     pub duplicate_of: Option<u64>,
+    pub estimated_time: Option<Hours>,
     pub flags: Option<Vec<Flag>>,
     pub groups: Option<Vec<SetChange<String>>>,
     pub keywords: Option<Vec<SetChange<String>>>,
@@ -402,6 +586,7 @@ pub struct Parameters {
     pub priority: Option<String>,
     pub product: Option<String>,
     pub qa: Option<String>,
+    pub remaining_time: Option<Hours>,
     pub resolution: Option<String>,
     pub see_also: Option<Vec<SetChange<String>>>,
     pub severity: Option<String>,
@@ -411,6 +596,7 @@ pub struct Parameters {
     pub url: Option<String>,
     pub version: Option<String>,
     pub whiteboard: Option<String>,
+    pub work_time: Option<Hours>,
 
     #[serde(flatten)]
     pub custom_fields: Option<IndexMap<String, String>>,
@@ -428,8 +614,10 @@ impl Merge for Parameters {
             comment_is_private: self.comment_is_private.merge(other.comment_is_private),
             comment_privacy: self.comment_privacy.merge(other.comment_privacy),
             component: self.component.merge(other.component),
+            deadline: self.deadline.merge(other.deadline),
             depends: self.depends.merge(other.depends),
             duplicate_of: self.duplicate_of.merge(other.duplicate_of),
+            estimated_time: self.estimated_time.merge(other.estimated_time),
             flags: self.flags.merge(other.flags),
             groups: self.groups.merge(other.groups),
             keywords: self.keywords.merge(other.keywords),
@@ -438,6 +626,7 @@ impl Merge for Parameters {
             priority: self.priority.merge(other.priority),
             product: self.product.merge(other.product),
             qa: self.qa.merge(other.qa),
+            remaining_time: self.remaining_time.merge(other.remaining_time),
             resolution: self.resolution.merge(other.resolution),
             see_also: self.see_also.merge(other.see_also),
             status: self.status.merge(other.status),
@@ -447,6 +636,7 @@ impl Merge for Parameters {
             url: self.url.merge(other.url),
             version: self.version.merge(other.version),
             whiteboard: self.whiteboard.merge(other.whiteboard),
+            work_time: self.work_time.merge(other.work_time),
             custom_fields: self.custom_fields.merge(other.custom_fields),
         }
     }
@@ -548,8 +738,10 @@ struct RequestParameters<'a> {
     comment: Option<Comment<'a>>,
     comment_is_private: Option<IndexMap<u64, bool>>,
     component: Option<&'a str>,
+    deadline: Option<&'a str>,
     depends_on: Option<SetChanges<&'a u64>>,
     dupe_of: Option<u64>,
+    estimated_time: Option<Hours>,
     flags: Option<&'a [Flag]>,
     groups: Option<Changes<&'a String>>,
     keywords: Option<SetChanges<&'a String>>,
@@ -558,6 +750,7 @@ struct RequestParameters<'a> {
     priority: Option<&'a str>,
     product: Option<&'a str>,
     qa_contact: Option<&'a str>,
+    remaining_time: Option<Hours>,
     reset_assigned_to: Option<bool>,
     reset_qa_contact: Option<bool>,
     resolution: Option<&'a str>,
@@ -569,9 +762,43 @@ struct RequestParameters<'a> {
     url: Option<&'a str>,
     version: Option<&'a str>,
     whiteboard: Option<&'a str>,
+    work_time: Option<Hours>,
 
     #[serde(flatten)]
-    custom_fields: Option<&'a IndexMap<String, String>>,
+    custom_fields: Option<IndexMap<String, serde_json::Value>>,
+}
+
+impl RequestParameters<'_> {
+    /// Return the history field names for values overwritten by the update.
+    fn fields(&self) -> crate::Result<IndexSet<String>> {
+        let serde_json::Value::Object(values) = serde_json::to_value(self)
+            .map_err(|e| Error::InvalidValue(format!("failed serializing parameters: {e}")))?
+        else {
+            return Err(Error::InvalidValue("invalid update parameters".to_string()));
+        };
+
+        let fields = values
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let name = match name.as_str() {
+                    "ids" | "comment" | "comment_is_private" | "work_time" => return None,
+                    "reset_assigned_to" => "assigned_to".to_string(),
+                    "reset_qa_contact" => "qa_contact".to_string(),
+                    "flags" => "flagtypes.name".to_string(),
+                    _ => name,
+                };
+
+                // adding or removing values doesn't overwrite existing values
+                if value.is_object() && value.get("set").is_none() {
+                    None
+                } else {
+                    Some(name)
+                }
+            })
+            .collect();
+
+        Ok(fields)
+    }
 }
 
 #[cfg(test)]
@@ -597,4 +824,281 @@ mod tests {
         let err = service.update([1]).send().await.unwrap_err();
         assert_matches!(err, Error::EmptyParams);
     }
+
+    #[tokio::test]
+    async fn custom_fields() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        let fields = |req: &wiremock::Request| req.url.path() == "/rest/field/bug";
+        server
+            .respond_match(fields, 200, path.join("fields/custom.json"))
+            .await;
+        let matcher = wiremock::matchers::body_partial_json(serde_json::json!({
+            "cf_packages": ["foo", "bar"],
+            "cf_notes": "notes",
+            "cf_parent": 10,
+            "cf_points": -1,
+            "cf_due": "2024-04-01",
+            "cf_reviewed": "2024-03-14 10:00:00",
+            "cf_unknown": "1",
+        }));
+        server
+            .respond_match(matcher, 200, path.join("update/summary.json"))
+            .await;
+
+        // values are encoded using their field types
+        let mut request = service.update([123]);
+        request.params.custom_fields = Some(
+            [
+                ("cf_packages", "foo, bar"),
+                ("cf_notes", "notes"),
+                ("cf_parent", "10"),
+                ("cf_points", "-1"),
+                ("cf_due", "2024-04-01"),
+                ("cf_reviewed", "2024-03-14T10:00:00Z"),
+                ("cf_unknown", "1"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        );
+        request.send().await.unwrap();
+
+        // invalid values
+        for (name, value, kind) in [
+            ("cf_parent", "abc", "bug ID"),
+            ("cf_points", "1.5", "integer"),
+            ("cf_due", "tomorrow", "date"),
+            ("cf_reviewed", "2024-13-01", "datetime"),
+        ] {
+            let mut request = service.update([123]);
+            request.params.custom_fields = Some(
+                [(name.to_string(), value.to_string())]
+                    .into_iter()
+                    .collect(),
+            );
+            let err = request.send().await.unwrap_err();
+            assert_matches!(err, Error::InvalidValue(_));
+            assert_err_re!(err, format!("invalid {kind} value: {name}={value}"));
+        }
+    }
+
+    #[tokio::test]
+    async fn time_tracking() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        let matcher = wiremock::matchers::body_partial_json(serde_json::json!({
+            "comment": {"body": "progress"},
+            "deadline": "2024-04-01",
+            "estimated_time": 10.5,
+            "remaining_time": 2.0,
+            "work_time": 1.5,
+        }));
+        server
+            .respond_match(matcher, 200, path.join("update/summary.json"))
+            .await;
+
+        // work time is attached to the comment
+        let mut request = service.update([123]);
+        request.params.comment = Some("progress".to_string());
+        request.params.deadline = Some("2024-04-01".to_string());
+        request.params.estimated_time = Some("10.5".parse().unwrap());
+        request.params.remaining_time = Some("2".parse().unwrap());
+        request.params.work_time = Some("1.5".parse().unwrap());
+        request.send().await.unwrap();
+
+        // invalid deadline
+        let mut request = service.update([123]);
+        request.params.deadline = Some("tomorrow".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidValue(_));
+        assert_err_re!(err, "invalid deadline: tomorrow");
+
+        // invalid hours
+        for s in ["", "abc", "inf", "NaN"] {
+            assert!(s.parse::<Hours>().is_err(), "{s} didn't fail");
+        }
+    }
+
+    #[tokio::test]
+    async fn conflicts() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        let history = |req: &wiremock::Request| req.url.path().ends_with("/history");
+        server
+            .respond_match(history, 200, path.join("history/single-bug.json"))
+            .await;
+        server
+            .respond_match(
+                wiremock::matchers::method("GET"),
+                200,
+                path.join("get/single-bug.json"),
+            )
+            .await;
+        server.respond(200, path.join("update/summary.json")).await;
+
+        // unknown change time
+        let mut request = service.update([1]);
+        request.check_conflicts(true).params.summary = Some("summary".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "unknown last change time for bug: 1");
+
+        // changes made after the specified time conflict
+        let time = "2024-03-13T00:00:00Z".parse().unwrap();
+        request.last_change_time(time);
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Conflict(_));
+        assert_err_re!(err, "^conflicting changes: bug 1: summary$");
+        let conflicts = request.conflicts().await.unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].events.len(), 1);
+        assert_eq!(conflicts[0].events[0].who, "user1@bugbite.test");
+
+        // changes to unrelated fields don't conflict
+        let mut request = service.update([1]);
+        request.check_conflicts(true).last_change_time(time);
+        request.params.whiteboard = Some("whiteboard".to_string());
+        request.send().await.unwrap();
+
+        // adding to set-based fields doesn't conflict while overriding them does
+        let mut request = service.update([1]);
+        request.check_conflicts(true).last_change_time(time);
+        request.params.blocks = Some(vec!["+10".parse().unwrap()]);
+        request.send().await.unwrap();
+        request.params.blocks = Some(vec!["10".parse().unwrap()]);
+        let err = request.send().await.unwrap_err();
+        assert_err_re!(err, "^conflicting changes: bug 1: blocks$");
+
+        // change times are recorded from retrieved bugs
+        let mut request = service.update(["alias"]);
+        request.check_conflicts(true).params.summary = Some("summary".to_string());
+        let bugs = service.get([1]).send().await.unwrap();
+        request.last_change_bugs(&bugs);
+        request.send().await.unwrap();
+
+        // conflict checks are disabled by default
+        let mut request = service.update([1]);
+        request.params.summary = Some("summary".to_string());
+        request.send().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn validate() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        server
+            .respond_match(
+                wiremock::matchers::query_param("names", "Client"),
+                200,
+                path.join("product/client.json"),
+            )
+            .await;
+        let template = wiremock::ResponseTemplate::new(200)
+            .set_body_raw(r#"{"products":[]}"#, "application/json");
+        server
+            .respond_custom(
+                wiremock::matchers::query_param("names", "product"),
+                template,
+            )
+            .await;
+        server
+            .respond_match(
+                wiremock::matchers::method("GET"),
+                200,
+                path.join("get/single-bug.json"),
+            )
+            .await;
+        server.respond(200, path.join("update/summary.json")).await;
+
+        // values unrelated to products aren't validated
+        let mut request = service.update([123]);
+        request.validate(true).params.summary = Some("new summary".to_string());
+        request.send().await.unwrap();
+
+        // values are validated against a specified product
+        let mut request = service.update([123]);
+        request.validate(true).params.product = Some("Client".to_string());
+        request.params.component = Some("CLI".to_string());
+        request.send().await.unwrap();
+        request.params.component = Some("TUI".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_err_re!(err, "invalid component for Client: TUI");
+
+        // values are validated against the current products of the bugs
+        let mut request = service.update([1]);
+        request.validate(true).params.component = Some("CLI".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_err_re!(err, "nonexistent product: product");
+    }
+
+    #[tokio::test]
+    async fn rpc() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_rpc("Bug.update", path.join("update/rpc.json"))
+            .await;
+
+        // authentication required
+        let mut request = service.update([123]);
+        request.params.summary = Some("new summary".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        // authentication is passed via method parameters
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+        server.reset().await;
+        let matcher = wiremock::matchers::body_partial_json(serde_json::json!({
+            "method": "Bug.update",
+            "params": [{"ids": ["123"], "summary": "new summary", "Bugzilla_login": "user"}],
+        }));
+        server
+            .respond_match(matcher, 200, path.join("update/rpc.json"))
+            .await;
+        let mut request = service.update([123]);
+        request.params.summary = Some("new summary".to_string());
+        let changes = request.send().await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].id, 123);
+    }
 }
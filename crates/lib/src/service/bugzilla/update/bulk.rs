@@ -0,0 +1,476 @@
+use std::fs;
+
+use camino::Utf8Path;
+use futures_util::{Stream, StreamExt, stream};
+use indexmap::IndexSet;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use strum::{Display, EnumString};
+
+use crate::Error;
+use crate::objects::bugzilla::Flag;
+use crate::service::bugzilla::Bugzilla;
+use crate::traits::{Merge, RequestSend};
+
+use super::{BugChange, Parameters};
+
+/// Supported change file formats.
+#[derive(Display, EnumString, Debug, PartialEq, Eq, Clone, Copy)]
+#[strum(serialize_all = "lowercase")]
+pub enum Format {
+    Csv,
+    Json,
+    Toml,
+}
+
+impl Format {
+    /// Determine the change file format from a path's extension.
+    pub fn from_path(path: &Utf8Path) -> crate::Result<Self> {
+        path.extension()
+            .and_then(|x| x.to_lowercase().parse().ok())
+            .ok_or_else(|| Error::InvalidValue(format!("unknown change file format: {path}")))
+    }
+}
+
+/// Deserialize a bug ID or alias from a string or number.
+fn id<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Number(u64),
+        String(String),
+    }
+
+    match Id::deserialize(d)? {
+        Id::Number(value) => Ok(value.to_string()),
+        Id::String(value) if !value.trim().is_empty() => Ok(value.trim().to_string()),
+        Id::String(_) => Err(serde::de::Error::custom("empty bug ID")),
+    }
+}
+
+/// Changes for a single bug.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    #[serde(deserialize_with = "id")]
+    pub id: String,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// TOML change file layout using an array of bug tables.
+#[derive(Deserialize, Serialize)]
+struct Entries {
+    #[serde(default)]
+    bugs: Vec<Entry>,
+}
+
+/// Fields with comma-separated values in CSV change files.
+const CSV_LISTS: &[&str] = &[
+    "alias", "blocks", "cc", "depends", "flags", "groups", "keywords", "see_also",
+];
+
+/// Convert a CSV cell into its related JSON value.
+fn csv_value(name: &str, cell: &str) -> crate::Result<Value> {
+    let invalid = || Error::InvalidValue(format!("invalid {name} value: {cell}"));
+    let value = match name {
+        "flags" => {
+            let flags = cell
+                .split(',')
+                .map(|x| x.trim().parse())
+                .collect::<crate::Result<Vec<Flag>>>()?;
+            serde_json::to_value(flags).map_err(|_| invalid())?
+        }
+        _ if CSV_LISTS.contains(&name) => cell.split(',').map(|x| x.trim()).collect(),
+        "duplicate_of" => cell.parse::<u64>().map_err(|_| invalid())?.into(),
+        "comment_is_private" => cell.parse::<bool>().map_err(|_| invalid())?.into(),
+        _ => cell.into(),
+    };
+    Ok(value)
+}
+
+/// Convert a JSON value into its related CSV cell.
+fn csv_cell(name: &str, value: Value) -> crate::Result<String> {
+    let cell = match value {
+        Value::Null => String::new(),
+        Value::String(value) => value,
+        Value::Array(values) if name == "flags" => {
+            let flags: Vec<Flag> = serde_json::from_value(values.into())
+                .map_err(|e| Error::InvalidValue(format!("invalid flags: {e}")))?;
+            flags
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        }
+        Value::Array(values) => values
+            .into_iter()
+            .map(|x| csv_cell(name, x))
+            .collect::<crate::Result<Vec<_>>>()?
+            .join(","),
+        value => value.to_string(),
+    };
+    Ok(cell)
+}
+
+/// Parse change file entries from a string.
+pub fn parse(data: &str, format: Format) -> crate::Result<Vec<Entry>> {
+    let err = |e: &dyn std::fmt::Display| {
+        Error::InvalidValue(format!("failed parsing {format} changes: {e}"))
+    };
+
+    let mut entries: Vec<Entry> = match format {
+        Format::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(data.as_bytes());
+            let headers = reader.headers().map_err(|e| err(&e))?.clone();
+            if !headers.iter().any(|x| x == "id") {
+                return Err(err(&"missing id column"));
+            }
+
+            let mut entries = vec![];
+            for record in reader.records() {
+                let record = record.map_err(|e| err(&e))?;
+                let mut map = Map::new();
+                for (name, cell) in headers.iter().zip(&record) {
+                    if !cell.is_empty() {
+                        map.insert(name.to_string(), csv_value(name, cell)?);
+                    }
+                }
+                entries.push(serde_json::from_value(map.into()).map_err(|e| err(&e))?);
+            }
+            entries
+        }
+        Format::Json => serde_json::from_str(data).map_err(|e| err(&e))?,
+        Format::Toml => {
+            let entries: Entries = toml::from_str(data).map_err(|e| err(&e))?;
+            entries.bugs
+        }
+    };
+
+    // flattened custom fields are always deserialized
+    for entry in &mut entries {
+        if entry
+            .params
+            .custom_fields
+            .as_ref()
+            .is_some_and(|x| x.is_empty())
+        {
+            entry.params.custom_fields = None;
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Serialize change file entries into a string.
+pub fn dump(entries: &[Entry], format: Format) -> crate::Result<String> {
+    let err = |e: &dyn std::fmt::Display| {
+        Error::InvalidValue(format!("failed serializing {format} changes: {e}"))
+    };
+
+    let data = match format {
+        Format::Csv => {
+            let mut rows = vec![];
+            let mut headers = IndexSet::from(["id".to_string()]);
+            for entry in entries {
+                let Value::Object(map) = serde_json::to_value(entry).map_err(|e| err(&e))? else {
+                    panic!("invalid change entry: {entry:?}");
+                };
+                headers.extend(map.keys().cloned());
+                rows.push(map);
+            }
+
+            let mut writer = csv::Writer::from_writer(vec![]);
+            writer.write_record(&headers).map_err(|e| err(&e))?;
+            for mut row in rows {
+                let cells = headers
+                    .iter()
+                    .map(|x| csv_cell(x, row.remove(x).unwrap_or_default()))
+                    .collect::<crate::Result<Vec<_>>>()?;
+                writer.write_record(cells).map_err(|e| err(&e))?;
+            }
+            let data = writer.into_inner().map_err(|e| err(&e))?;
+            String::from_utf8(data).map_err(|e| err(&e))?
+        }
+        Format::Json => serde_json::to_string_pretty(entries).map_err(|e| err(&e))? + "\n",
+        Format::Toml => {
+            let entries = Entries {
+                bugs: entries.to_vec(),
+            };
+            toml::to_string(&entries).map_err(|e| err(&e))?
+        }
+    };
+
+    Ok(data)
+}
+
+/// Load change file entries from a path, determining the format from its extension.
+pub fn load(path: &Utf8Path) -> crate::Result<Vec<Entry>> {
+    let format = Format::from_path(path)?;
+    let data = fs::read_to_string(path)
+        .map_err(|e| Error::IO(format!("failed reading change file: {path}: {e}")))?;
+    parse(&data, format)
+}
+
+/// Save change file entries to a path, determining the format from its extension.
+pub fn save(entries: &[Entry], path: &Utf8Path) -> crate::Result<()> {
+    let format = Format::from_path(path)?;
+    let data = dump(entries, format)?;
+    fs::write(path, data).map_err(|e| Error::IO(format!("failed saving change file: {path}: {e}")))
+}
+
+/// Result of applying the changes for a single bug.
+#[derive(Debug)]
+pub struct Outcome {
+    /// Change file entry as originally specified.
+    pub entry: Entry,
+    pub result: crate::Result<Vec<BugChange>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    service: Bugzilla,
+    pub entries: Vec<Entry>,
+    /// Parameters applied to all bugs, overridden by entry values.
+    pub params: Parameters,
+    validate: bool,
+}
+
+impl Request {
+    pub(crate) fn new<I>(service: &Bugzilla, entries: I) -> Self
+    where
+        I: IntoIterator<Item = Entry>,
+    {
+        Self {
+            service: service.clone(),
+            entries: entries.into_iter().collect(),
+            params: Default::default(),
+            validate: false,
+        }
+    }
+
+    /// Enable or disable validating product-related values before updating.
+    pub fn validate(&mut self, value: bool) -> &mut Self {
+        self.validate = value;
+        self
+    }
+
+    /// Return the change entries with the shared parameters applied.
+    pub fn changes(&self) -> Vec<Entry> {
+        self.entries
+            .iter()
+            .map(|entry| {
+                let mut params = self.params.clone();
+                params.merge(entry.params.clone());
+                Entry {
+                    id: entry.id.clone(),
+                    params,
+                }
+            })
+            .collect()
+    }
+
+    /// Return the stream of update outcomes in completion order.
+    ///
+    /// Updates are sent concurrently, bounded by the service's concurrency limit, and
+    /// failures are reported per bug without affecting the remaining updates.
+    pub fn stream(&self) -> impl Stream<Item = Outcome> + 'static {
+        let concurrent = self.service.config().client.concurrent.unwrap_or(1);
+        let (service, params, validate) =
+            (self.service.clone(), self.params.clone(), self.validate);
+
+        stream::iter(self.entries.clone())
+            .map(move |entry| {
+                let mut request = service.update([&entry.id]);
+                request.params = params.clone();
+                request.params.merge(entry.params.clone());
+                request.validate(validate);
+                async move {
+                    let result = request.send().await;
+                    Outcome { entry, result }
+                }
+            })
+            .buffer_unordered(concurrent)
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Outcome>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.entries.is_empty() {
+            return Err(Error::InvalidRequest("no changes specified".to_string()));
+        }
+
+        Ok(self.stream().collect().await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use tempfile::tempdir;
+
+    use crate::service::bugzilla::update::SetChange;
+    use crate::test::*;
+
+    use super::*;
+
+    #[test]
+    fn format() {
+        for (path, format) in [
+            ("changes.csv", Format::Csv),
+            ("changes.json", Format::Json),
+            ("changes.TOML", Format::Toml),
+        ] {
+            assert_eq!(Format::from_path(Utf8Path::new(path)).unwrap(), format);
+        }
+
+        for path in ["changes", "changes.txt"] {
+            let err = Format::from_path(Utf8Path::new(path)).unwrap_err();
+            assert_err_re!(err, format!("unknown change file format: {path}"));
+        }
+    }
+
+    #[test]
+    fn parse_and_dump() {
+        let path = TESTDATA_PATH.join("bugzilla/update/bulk");
+
+        for format in [Format::Csv, Format::Json, Format::Toml] {
+            let file = path.join(format!("changes.{format}"));
+            let entries = load(&file).unwrap();
+            let ids: Vec<_> = entries.iter().map(|x| x.id.as_str()).collect();
+            assert_eq!(ids, ["1", "2", "alias"], "{file}");
+
+            let params = &entries[0].params;
+            assert_eq!(params.status.as_deref(), Some("RESOLVED"), "{file}");
+            assert_eq!(params.resolution.as_deref(), Some("FIXED"), "{file}");
+            assert_eq!(
+                params.keywords.as_ref().unwrap(),
+                &[
+                    SetChange::Add("a".to_string()),
+                    SetChange::Remove("b".to_string())
+                ],
+                "{file}"
+            );
+            assert_eq!(
+                params.blocks.as_ref().unwrap(),
+                &[SetChange::Set(10)],
+                "{file}"
+            );
+            assert_eq!(
+                params.flags.as_ref().unwrap(),
+                &["needinfo?(user@bugbite.test)".parse::<Flag>().unwrap()],
+                "{file}"
+            );
+
+            let params = &entries[1].params;
+            assert_eq!(params.summary.as_deref(), Some("new summary"), "{file}");
+            assert_eq!(params.comment_is_private, Some(true), "{file}");
+            assert_eq!(
+                params.comment.as_deref(),
+                Some("comment, with comma"),
+                "{file}"
+            );
+
+            let params = &entries[2].params;
+            assert_eq!(params.duplicate_of, Some(1), "{file}");
+            assert_eq!(
+                params.custom_fields.as_ref().unwrap()["cf_stage"],
+                "beta",
+                "{file}"
+            );
+
+            // serialized entries roundtrip
+            for format in [Format::Csv, Format::Json, Format::Toml] {
+                let data = dump(&entries, format).unwrap();
+                assert_eq!(parse(&data, format).unwrap(), entries, "{file}: {format}");
+            }
+        }
+
+        // invalid data
+        for (data, format, err) in [
+            ("summary\nvalue", Format::Csv, "missing id column"),
+            (
+                "id,duplicate_of\n1,a",
+                Format::Csv,
+                "invalid duplicate_of value: a",
+            ),
+            ("id,flags\n1,a", Format::Csv, "invalid flag status"),
+            ("[{\"summary\": \"a\"}]", Format::Json, "missing field `id`"),
+            ("[[bugs]]\nid = \"\"", Format::Toml, "empty bug ID"),
+        ] {
+            let e = parse(data, format).unwrap_err();
+            assert_matches!(e, Error::InvalidValue(_));
+            assert_err_re!(e, err);
+        }
+
+        // save failures using the path's format
+        let dir = tempdir().unwrap();
+        let file = Utf8Path::from_path(dir.path())
+            .unwrap()
+            .join("failures.toml");
+        let entries = load(&path.join("changes.json")).unwrap();
+        save(&entries[1..], &file).unwrap();
+        assert_eq!(load(&file).unwrap(), &entries[1..]);
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        // no changes
+        let err = service.update_bulk([]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no changes specified");
+
+        let updated = |req: &wiremock::Request| req.url.path() == "/rest/bug/1";
+        server
+            .respond_match(updated, 200, path.join("update/summary.json"))
+            .await;
+        let missing = |req: &wiremock::Request| req.url.path() == "/rest/bug/2";
+        server
+            .respond_match(missing, 404, path.join("errors/nonexistent-bug.json"))
+            .await;
+
+        let entries = ["1", "2", "3"].map(|id| Entry {
+            id: id.to_string(),
+            params: Default::default(),
+        });
+        let mut request = service.update_bulk(entries);
+        request.params.summary = Some("new summary".to_string());
+        request.entries[2].params.summary = Some("summary".to_string());
+
+        // shared parameters are overridden by entry values
+        let summaries: Vec<_> = request
+            .changes()
+            .into_iter()
+            .map(|x| x.params.summary.unwrap())
+            .collect();
+        assert_eq!(summaries, ["new summary", "new summary", "summary"]);
+
+        let mut outcomes = request.send().await.unwrap();
+        outcomes.sort_by(|a, b| a.entry.id.cmp(&b.entry.id));
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[0].result.as_ref().unwrap().len(), 1);
+        assert!(outcomes[1].result.is_err());
+        assert!(outcomes[2].result.is_err());
+
+        // entries without changes fail individually
+        let entries = [Entry {
+            id: "1".to_string(),
+            params: Default::default(),
+        }];
+        let outcomes = service.update_bulk(entries).send().await.unwrap();
+        assert_matches!(outcomes[0].result, Err(Error::EmptyParams));
+    }
+}
@@ -1,7 +1,7 @@
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use crate::Error;
-use crate::service::bugzilla::Bugzilla;
+use crate::service::bugzilla::{Bugzilla, Transport};
 use crate::traits::{InjectAuth, RequestSend, WebService};
 
 #[derive(Debug)]
@@ -15,21 +15,145 @@ impl Request {
             service: service.clone(),
         }
     }
+
+    /// Send the request using a given transport.
+    pub(super) async fn send_transport(&self, transport: Transport) -> crate::Result<String> {
+        let mut data = if transport == Transport::JsonRpc {
+            self.service
+                .rpc("Bugzilla.version", json!({}), false)
+                .await?
+        } else {
+            let url = self.service.config().base.join("rest/version")?;
+            let request = self.service.client().get(url).auth_optional(&self.service);
+            let response = request.send().await?;
+            self.service.parse_response(response).await?
+        };
+
+        let Value::String(version) = data["version"].take() else {
+            return Err(Error::InvalidResponse("version request".to_string()));
+        };
+
+        Ok(version)
+    }
 }
 
 impl RequestSend for Request {
     type Output = String;
 
     async fn send(&self) -> crate::Result<Self::Output> {
-        let url = self.service.config().base.join("rest/version")?;
-        let request = self.service.client().get(url).auth_optional(&self.service);
+        let transport = self.service.transport().await?;
+        self.send_transport(transport).await
+    }
+}
 
-        let response = request.send().await?;
-        let mut data = self.service.parse_response(response).await?;
-        let Value::String(version) = data["version"].take() else {
-            return Err(Error::InvalidResponse("version request".to_string()));
-        };
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
 
-        Ok(version)
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+
+        // REST
+        let service = Bugzilla::new(server.uri()).unwrap();
+        server
+            .respond_match(
+                matchers::path("/rest/version"),
+                200,
+                path.join("version/rest.json"),
+            )
+            .await;
+        assert_eq!(service.version().send().await.unwrap(), "5.0.4");
+
+        // JSON-RPC
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::JsonRpc)
+            .build()
+            .unwrap();
+        server
+            .respond_rpc("Bugzilla.version", path.join("version/rpc.json"))
+            .await;
+        assert_eq!(service.version().send().await.unwrap(), "4.4.13");
+
+        // JSON-RPC errors
+        server.reset().await;
+        server
+            .respond_rpc("Bugzilla.version", path.join("errors/rpc.json"))
+            .await;
+        let err = service.version().send().await.unwrap_err();
+        assert_matches!(err, Error::Bugzilla { code: 32610, .. });
+        assert_err_re!(err, "For security reasons");
+    }
+
+    #[tokio::test]
+    async fn transport() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+
+        // REST is used by default without any detection
+        let service = Bugzilla::new(server.uri()).unwrap();
+        assert_eq!(service.transport().await.unwrap(), Transport::Rest);
+
+        // server errors are returned without caching a transport
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::Auto)
+            .build()
+            .unwrap();
+        let template = ResponseTemplate::new(503);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.transport().await.unwrap_err();
+        assert_matches!(err, Error::Request(_));
+        assert!(service.0.transport.get().is_none());
+
+        server.reset().await;
+
+        // non-JSON responses are treated as legacy instances
+        let template = ResponseTemplate::new(200).set_body_string("<html></html>");
+        server
+            .respond_custom(matchers::path("/rest/version"), template)
+            .await;
+        assert_eq!(service.transport().await.unwrap(), Transport::JsonRpc);
+
+        server.reset().await;
+
+        // legacy instance without REST support
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::Auto)
+            .build()
+            .unwrap();
+        let template = ResponseTemplate::new(404);
+        server
+            .respond_custom(matchers::path("/rest/version"), template)
+            .await;
+        assert_eq!(service.transport().await.unwrap(), Transport::JsonRpc);
+
+        // detected transport is cached
+        server.reset().await;
+        assert_eq!(service.transport().await.unwrap(), Transport::JsonRpc);
+
+        // REST support
+        let service = Bugzilla::builder(server.uri())
+            .unwrap()
+            .transport(Transport::Auto)
+            .build()
+            .unwrap();
+        server
+            .respond_match(
+                matchers::path("/rest/version"),
+                200,
+                path.join("version/rest.json"),
+            )
+            .await;
+        assert_eq!(service.transport().await.unwrap(), Transport::Rest);
     }
 }
@@ -0,0 +1,407 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+use camino::Utf8PathBuf;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::TryStreamExt;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+use crate::args::ExistsOrValues;
+use crate::objects::RangeOrValue;
+use crate::objects::bugzilla::{Bug, Change, Comment};
+use crate::service::bugzilla::{BugField, Bugzilla, search};
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{RequestSend, WebClient};
+use crate::utils::config_dir;
+
+/// Maximum number of known bugs checked per request.
+const CHUNK_SIZE: usize = 100;
+
+/// Last known values for a watched bug.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub summary: Option<String>,
+    pub status: Option<String>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+impl From<&Bug> for Snapshot {
+    fn from(bug: &Bug) -> Self {
+        Self {
+            summary: bug.summary.clone(),
+            status: bug.status.clone(),
+            updated: bug.updated,
+        }
+    }
+}
+
+/// Watch state persisted between polls.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct State {
+    /// Latest bug change time seen by previous polls, bounding the next poll.
+    pub polled: Option<DateTime<Utc>>,
+
+    /// Snapshots of known bugs.
+    #[serde(default)]
+    pub bugs: IndexMap<u64, Snapshot>,
+}
+
+/// Change to a watched bug.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Bug newly matching the search.
+    New { id: u64, snapshot: Snapshot },
+
+    /// Bug changed to no longer match the search.
+    Removed { id: u64, snapshot: Snapshot },
+
+    /// Field value changed.
+    Changed {
+        id: u64,
+        who: String,
+        when: DateTime<Utc>,
+        change: Change,
+    },
+
+    /// Comment added.
+    Comment(Comment),
+}
+
+impl Event {
+    /// Return the ID of the bug the event occurred on.
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::New { id, .. } => *id,
+            Self::Removed { id, .. } => *id,
+            Self::Changed { id, .. } => *id,
+            Self::Comment(comment) => comment.bug_id,
+        }
+    }
+
+    /// Return the event type.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::New { .. } => "new",
+            Self::Removed { .. } => "removed",
+            Self::Changed { .. } => "change",
+            Self::Comment(_) => "comment",
+        }
+    }
+
+    /// Return the time the event occurred if known.
+    fn when(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::New { snapshot, .. } => snapshot.updated,
+            Self::Removed { snapshot, .. } => snapshot.updated,
+            Self::Changed { when, .. } => Some(*when),
+            Self::Comment(comment) => Some(comment.created),
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let id = self.id();
+        match self {
+            Self::New { snapshot, .. } => {
+                let summary = snapshot.summary.as_deref().unwrap_or_default();
+                write!(f, "{id}: new: {summary}")
+            }
+            Self::Removed { snapshot, .. } => {
+                let summary = snapshot.summary.as_deref().unwrap_or_default();
+                write!(f, "{id}: removed: {summary}")
+            }
+            Self::Changed { who, change, .. } => {
+                let removed = change.removed.as_deref().unwrap_or_default();
+                let added = change.added.as_deref().unwrap_or_default();
+                let field = &change.field_name;
+                write!(f, "{id}: {field}: {removed:?} -> {added:?} by {who}")
+            }
+            Self::Comment(comment) => {
+                write!(f, "{id}: comment #{} by {}", comment.count, comment.creator)
+            }
+        }
+    }
+}
+
+/// Convert a datetime into a static time value.
+fn time(value: DateTime<Utc>) -> crate::Result<TimeDeltaOrStatic> {
+    TimeDeltaOrStatic::from_str(&value.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+#[derive(Debug)]
+pub struct Request {
+    service: Bugzilla,
+    pub search: search::Request,
+    pub state: State,
+}
+
+impl Request {
+    pub(super) fn new(service: &Bugzilla, search: search::Request) -> Self {
+        Self {
+            service: service.clone(),
+            search,
+            state: Default::default(),
+        }
+    }
+
+    /// Return the config path for a state file.
+    fn state_path(&self, name: &str) -> crate::Result<Utf8PathBuf> {
+        let service_name = self.service.name();
+        if service_name.trim().is_empty() || name.contains(std::path::is_separator) {
+            Ok(Utf8PathBuf::from(name))
+        } else {
+            config_dir().map(|x| x.join(format!("watch/{service_name}/{name}")))
+        }
+    }
+
+    /// Load the watch state using the given name, starting fresh if none exists.
+    pub fn load_state(&mut self, name: &str) -> crate::Result<&mut Self> {
+        let path = self.state_path(name)?;
+        if path.exists() {
+            let data = fs::read_to_string(&path)
+                .map_err(|e| Error::IO(format!("failed loading watch state: {name}: {e}")))?;
+            self.state = serde_json::from_str(&data).map_err(|e| {
+                Error::InvalidValue(format!("failed parsing watch state: {name}: {e}"))
+            })?;
+        }
+        Ok(self)
+    }
+
+    /// Save the watch state using the given name.
+    pub fn save_state(&self, name: &str) -> crate::Result<()> {
+        let path = self.state_path(name)?;
+        if let Some(dir) = path.parent().filter(|x| !x.as_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .map_err(|e| Error::IO(format!("failed creating watch state dir: {e}")))?;
+        }
+        let data = serde_json::to_string_pretty(&self.state)
+            .map_err(|e| Error::InvalidValue(format!("failed serializing watch state: {e}")))?;
+        fs::write(&path, data)
+            .map_err(|e| Error::IO(format!("failed saving watch state: {name}: {e}")))
+    }
+
+    /// Rerun the search for bugs updated since the last poll, returning their changes.
+    ///
+    /// The initial poll only records the matching bugs without generating events. Known
+    /// bugs changed to no longer match the search are dropped from the state on later
+    /// polls after generating events for their final changes.
+    pub async fn poll(&mut self) -> crate::Result<Vec<Event>> {
+        let fields = [
+            BugField::Id,
+            BugField::Summary,
+            BugField::Status,
+            BugField::Updated,
+        ];
+        let mut search = self.search.clone();
+        search.fields(fields);
+        if let Some(value) = self.state.polled {
+            search.updated(RangeOrValue::Value(time(value)?));
+        }
+        let bugs: Vec<_> = search.stream().try_collect().await?;
+
+        let mut events = vec![];
+        let mut removed = vec![];
+        if let Some(polled) = self.state.polled {
+            // known bugs changed since the last poll that no longer match the search
+            let matching: HashSet<_> = bugs.iter().map(|x| x.id).collect();
+            let ids: Vec<_> = self
+                .state
+                .bugs
+                .keys()
+                .filter(|x| !matching.contains(x))
+                .map(|x| RangeOrValue::Value(*x as i64))
+                .collect();
+            for ids in ids.chunks(CHUNK_SIZE) {
+                let mut search = self.service.search();
+                search
+                    .ids(ExistsOrValues::Values(ids.to_vec()))
+                    .status(["@all"])
+                    .updated(RangeOrValue::Value(time(polled)?))
+                    .fields(fields);
+                removed.extend(search.stream().try_collect::<Vec<_>>().await?);
+            }
+
+            // known bugs with their last change times
+            let known: IndexMap<_, _> = bugs
+                .iter()
+                .chain(&removed)
+                .filter_map(|bug| {
+                    let updated = self.state.bugs.get(&bug.id)?.updated?;
+                    (bug.updated > Some(updated)).then_some((bug.id, updated))
+                })
+                .collect();
+
+            for bug in bugs.iter().filter(|x| !self.state.bugs.contains_key(&x.id)) {
+                events.push(Event::New {
+                    id: bug.id,
+                    snapshot: bug.into(),
+                });
+            }
+
+            if let Some(since) = known.values().min().copied() {
+                let ids: Vec<_> = known.keys().collect();
+                let since = time(since)?;
+                let history = self
+                    .service
+                    .history(&ids)
+                    .created_after(since.clone())
+                    .send()
+                    .await?;
+                let comments = self
+                    .service
+                    .comment(&ids)
+                    .created_after(since)
+                    .send()
+                    .await?;
+
+                for ((id, updated), (history, comments)) in
+                    known.iter().zip(history.into_iter().zip(comments))
+                {
+                    let mut bug_events = vec![];
+                    for event in history.into_iter().filter(|x| x.when > *updated) {
+                        for change in event.changes {
+                            bug_events.push(Event::Changed {
+                                id: *id,
+                                who: event.who.clone(),
+                                when: event.when,
+                                change,
+                            });
+                        }
+                    }
+                    for comment in comments.into_iter().filter(|x| x.created > *updated) {
+                        bug_events.push(Event::Comment(comment));
+                    }
+                    bug_events.sort_by_key(|x| x.when());
+                    events.extend(bug_events);
+                }
+            }
+
+            for bug in &removed {
+                events.push(Event::Removed {
+                    id: bug.id,
+                    snapshot: bug.into(),
+                });
+                self.state.bugs.shift_remove(&bug.id);
+            }
+        }
+
+        // Use the latest change time as the next bound to avoid depending on the local
+        // clock, falling back to the epoch so all bugs are new if none match.
+        let updated = bugs.iter().chain(&removed).filter_map(|x| x.updated).max();
+        self.state.polled = updated
+            .max(self.state.polled)
+            .or(Some(DateTime::UNIX_EPOCH));
+        for bug in &bugs {
+            self.state.bugs.insert(bug.id, bug.into());
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn poll() {
+        let path = TESTDATA_PATH.join("bugzilla");
+        let server = TestServer::new().await;
+        let service = Bugzilla::new(server.uri()).unwrap();
+        let mut request = service.watch(service.search());
+
+        // initial poll records bugs without events
+        server.respond(200, path.join("watch/initial.json")).await;
+        let events = request.poll().await.unwrap();
+        assert!(events.is_empty());
+        let polled = "2024-03-13T15:10:00Z".parse().unwrap();
+        assert_eq!(request.state.polled, Some(polled));
+        assert_eq!(
+            request.state.bugs.keys().copied().collect::<Vec<_>>(),
+            [1, 2]
+        );
+
+        // changes since the last poll
+        server.reset().await;
+        let search = |fields: &'static str| {
+            move |req: &wiremock::Request| {
+                req.url.path() == "/rest/bug"
+                    && req
+                        .url
+                        .query_pairs()
+                        .any(|(k, v)| k == "include_fields" && v == fields)
+            }
+        };
+        let known = |req: &wiremock::Request| req.url.query_pairs().any(|(_, v)| v == "bug_id");
+        server
+            .respond_match(known, 200, path.join("watch/removed.json"))
+            .await;
+        server
+            .respond_match(
+                search("id,summary,status,last_change_time"),
+                200,
+                path.join("watch/updated.json"),
+            )
+            .await;
+        server
+            .respond_match(
+                |req: &wiremock::Request| req.url.path() == "/rest/bug/1/history",
+                200,
+                path.join("watch/history.json"),
+            )
+            .await;
+        server
+            .respond_match(
+                |req: &wiremock::Request| req.url.path() == "/rest/bug/1/comment",
+                200,
+                path.join("watch/comments.json"),
+            )
+            .await;
+        let events = request.poll().await.unwrap();
+        let events: Vec<_> = events.iter().map(|x| x.to_string()).collect();
+        assert_eq!(
+            events,
+            [
+                "3: new: new bug",
+                r#"1: status: "NEW" -> "CONFIRMED" by user1@bugbite.test"#,
+                "1: comment #1 by user2@bugbite.test",
+                r#"2: status: "NEW" -> "RESOLVED" by user1@bugbite.test"#,
+                r#"2: resolution: "" -> "FIXED" by user1@bugbite.test"#,
+                "2: removed: second bug",
+            ]
+        );
+        assert_eq!(request.state.bugs[&1].status.as_deref(), Some("CONFIRMED"));
+        let polled = "2024-03-14T10:00:00Z".parse().unwrap();
+        assert_eq!(request.state.polled, Some(polled));
+
+        // known bugs changed to no longer match the search are dropped
+        assert_eq!(
+            request.state.bugs.keys().copied().collect::<Vec<_>>(),
+            [1, 3]
+        );
+
+        // state roundtrips through files
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("state.json");
+        let file = file.to_str().unwrap();
+        request.save_state(file).unwrap();
+        let mut loaded = service.watch(service.search());
+        loaded.load_state(file).unwrap();
+        assert_eq!(loaded.state, request.state);
+
+        // nonexistent state files start fresh
+        let file = dir.path().join("nonexistent.json");
+        let mut request = service.watch(service.search());
+        request.load_state(file.to_str().unwrap()).unwrap();
+        assert_eq!(request.state, State::default());
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod get;
+pub mod search;
+mod soap;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Maximum number of bugs that can be returned by a single status request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of debbugs' max search results.
+fn default_max_search_results() -> usize {
+    100
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            name: Default::default(),
+            client: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Debbugs
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Debbugs> {
+        Ok(Debbugs(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Debbugs(Arc<Service>);
+
+impl PartialEq for Debbugs {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Debbugs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Debbugs {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Debbugs, name)?
+            .into_debbugs()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/cgi-bin/bugreport.cgi?bug={id}")
+    }
+
+    /// Send a SOAP method call, returning the decoded result value.
+    async fn call(&self, method: &str, args: &[soap::Arg]) -> crate::Result<serde_json::Value> {
+        let url = self.base().join("cgi-bin/soap.cgi")?;
+        let request = self
+            .client()
+            .post(url)
+            .header("Content-Type", "text/xml; charset=utf-8")
+            .header("SOAPAction", format!("\"{}#{method}\"", soap::NAMESPACE))
+            .body(soap::encode(method, args));
+        let response = request.send().await?;
+        self.parse_response(response).await
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+}
+
+impl WebService for Debbugs {
+    const API_VERSION: &'static str = "1";
+    type Response = serde_json::Value;
+
+    // The SOAP interface is read-only and doesn't support authentication.
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        if required {
+            Err(Error::Auth)
+        } else {
+            Ok(request)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        // faults are returned with server error statuses so parse them first
+        let status = response.error_for_status_ref().map(|_| ());
+        let text = response.text().await?;
+        debug!("response data:\n{text}");
+        match (soap::decode(&text), status) {
+            (Err(e @ Error::Debbugs(_)), _) => Err(e),
+            (_, Err(e)) => Err(e.into()),
+            (result, Ok(_)) => result,
+        }
+    }
+}
+
+impl WebClient for Debbugs {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BugField {
+    /// time when the bug was created
+    Created,
+    /// upstream location the bug was forwarded to
+    Forwarded,
+    /// bug ID
+    Id,
+    /// person who owns the bug
+    Owner,
+    /// binary package name
+    Package,
+    /// person who reported the bug
+    Reporter,
+    /// bug severity
+    Severity,
+    /// source package name
+    Source,
+    /// bug status
+    Status,
+    /// bug subject
+    Subject,
+    /// bug tags
+    Tags,
+    /// time when the bug was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
@@ -0,0 +1,165 @@
+use futures_util::future::try_join_all;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::Error;
+use crate::objects::debbugs::{Bug, Comment};
+use crate::service::debbugs::Debbugs;
+use crate::traits::RequestSend;
+
+use super::soap::Arg;
+
+#[derive(Debug)]
+pub struct Request {
+    service: Debbugs,
+    pub ids: Vec<u64>,
+    comments: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Debbugs, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Fetch the message log for a bug.
+    async fn log(&self, id: u64) -> crate::Result<Vec<Comment>> {
+        let data = self.service.call("get_bug_log", &[id.into()]).await?;
+        let entries: Vec<LogEntry> = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing bug log: {e}")))?;
+        Ok(entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| Comment::new(x.msg_num, i, &x.header, &x.body))
+            .collect())
+    }
+}
+
+/// Raw bug log entry.
+#[derive(Deserialize)]
+struct LogEntry {
+    #[serde(default)]
+    header: String,
+    #[serde(default)]
+    body: String,
+    msg_num: u64,
+}
+
+/// Fetch the status for a set of bugs, returning them in the requested order.
+pub(super) async fn status(service: &Debbugs, ids: &[u64]) -> crate::Result<Vec<Bug>> {
+    let mut bugs = IndexMap::new();
+    for chunk in ids.chunks(service.config().max_search_results) {
+        let args = [Arg::from(chunk.to_vec())];
+        let data = service.call("get_status", &args).await?;
+        // empty responses are decoded as a string
+        if !data.is_object() {
+            continue;
+        }
+        let chunk: IndexMap<u64, Bug> = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing bugs: {e}")))?;
+        bugs.extend(chunk);
+    }
+
+    ids.iter()
+        .map(|id| {
+            bugs.swap_remove(id)
+                .ok_or_else(|| Error::Debbugs(format!("nonexistent bug: {id}")))
+        })
+        .collect()
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Bug>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let mut bugs = status(&self.service, &self.ids).await?;
+
+        if self.comments {
+            let futures = self.ids.iter().map(|id| self.log(*id));
+            let logs = try_join_all(futures).await?;
+            for (bug, comments) in bugs.iter_mut().zip(logs) {
+                bug.comments = comments;
+            }
+        }
+
+        Ok(bugs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("debbugs");
+        let server = TestServer::new().await;
+        let service = Debbugs::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        server
+            .respond_soap("get_status", path.join("get/nonexistent.xml"))
+            .await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Debbugs(_));
+        assert_err_re!(err, "nonexistent bug: 1");
+
+        server.reset().await;
+
+        // single with comments
+        server
+            .respond_soap("get_status", path.join("get/status.xml"))
+            .await;
+        server
+            .respond_soap("get_bug_log", path.join("get/log.xml"))
+            .await;
+        let bug = service
+            .get([123456])
+            .comments(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(bug.id, 123456);
+        assert_eq!(bug.package.as_deref(), Some("bugbite"));
+        assert_eq!(bug.merged, [123457]);
+        assert_ordered_eq!(bug.comments.iter().map(|x| x.count), [0, 1]);
+        assert_ordered_eq!(bug.comments.iter().map(|x| x.id), [5, 10]);
+
+        // faults
+        server.reset().await;
+        server
+            .respond_soap("get_status", path.join("errors/fault.xml"))
+            .await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Debbugs(_));
+    }
+}
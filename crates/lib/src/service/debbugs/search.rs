@@ -0,0 +1,314 @@
+use async_stream::try_stream;
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::debbugs::Bug;
+use crate::service::debbugs::Debbugs;
+use crate::traits::{Merge, RequestTemplate, WebClient};
+
+use super::soap::Arg;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Debbugs,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl Request {
+    pub(super) fn new(service: &Debbugs) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    ///
+    /// Matching bug IDs are pulled in a single request, newest first, with their
+    /// status being fetched in chunks.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Bug>> + 'static {
+        let request = self.clone();
+        try_stream! {
+            let args = request.encode()?;
+            let data = request.service.call("get_bugs", &args).await?;
+            let mut ids: Vec<u64> = serde_json::from_value(data)
+                .map_err(|e| Error::InvalidResponse(format!("failed deserializing bug IDs: {e}")))?;
+            ids.sort_unstable_by(|a, b| b.cmp(a));
+            if let Some(limit) = request.params.limit {
+                ids.truncate(limit);
+            }
+
+            let size = request.service.config().max_search_results;
+            for chunk in ids.chunks(size) {
+                for bug in super::get::status(&request.service, chunk).await? {
+                    yield bug;
+                }
+            }
+        }
+    }
+
+    /// Encode parameters into key-value arguments.
+    fn encode(&self) -> crate::Result<Vec<Arg>> {
+        let mut args = vec![];
+        let insert = |args: &mut Vec<Arg>, key: &str, values: &[String]| {
+            args.push(key.into());
+            match values {
+                [value] => args.push(value.into()),
+                values => args.push(values.iter().collect::<Vec<_>>().into()),
+            }
+        };
+
+        let params = &self.params;
+        let fields = [
+            ("package", &params.package),
+            ("src", &params.source),
+            ("maint", &params.maintainer),
+            ("submitter", &params.reporter),
+            ("severity", &params.severity),
+            ("tag", &params.tags),
+        ];
+
+        for (key, values) in fields {
+            if let Some(values) = values.as_deref().filter(|x| !x.is_empty()) {
+                insert(&mut args, key, values);
+            }
+        }
+
+        // debbugs can't list all bugs so require at least one selector
+        if args.is_empty() {
+            return Err(Error::EmptyParams);
+        }
+
+        if let Some(value) = &params.status {
+            match value.as_str() {
+                "open" | "forwarded" | "done" => {
+                    insert(&mut args, "status", std::slice::from_ref(value))
+                }
+                _ => return Err(Error::InvalidValue(format!("invalid status: {value}"))),
+            }
+        }
+
+        if params.archived.unwrap_or_default() {
+            insert(&mut args, "archive", &["1".to_string()]);
+        }
+
+        Ok(args)
+    }
+
+    /// Return the website URL for a query.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let base = self.service.base().as_str().trim_end_matches('/');
+        let mut url = Url::parse(&format!("{base}/cgi-bin/pkgreport.cgi"))?;
+        let params = &self.params;
+        let fields = [
+            ("package", &params.package),
+            ("src", &params.source),
+            ("maint", &params.maintainer),
+            ("submitter", &params.reporter),
+            ("severity", &params.severity),
+            ("tag", &params.tags),
+        ];
+
+        {
+            let mut query = url.query_pairs_mut();
+            for (key, values) in fields {
+                for value in values.iter().flatten() {
+                    query.append_pair(key, value);
+                }
+            }
+            if let Some(value) = &params.status {
+                query.append_pair("status", value);
+            }
+            if params.archived.unwrap_or_default() {
+                query.append_pair("archive", "1");
+            }
+        }
+
+        Ok(url)
+    }
+
+    pub fn archived(&mut self, value: bool) -> &mut Self {
+        self.params.archived = Some(value);
+        self
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.params.limit = Some(value);
+        self
+    }
+
+    pub fn maintainer<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.maintainer = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn package<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.package = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn reporter<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.reporter = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn severity<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.severity = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn source<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.source = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn status<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.status = Some(value.into());
+        self
+    }
+
+    pub fn tags<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.tags = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Debbugs;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Bug search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub package: Option<Vec<String>>,
+    pub source: Option<Vec<String>>,
+    pub maintainer: Option<Vec<String>>,
+    pub reporter: Option<Vec<String>>,
+    pub severity: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+
+    pub status: Option<String>,
+    pub archived: Option<bool>,
+    pub limit: Option<usize>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            package: self.package.merge(other.package),
+            source: self.source.merge(other.source),
+            maintainer: self.maintainer.merge(other.maintainer),
+            reporter: self.reporter.merge(other.reporter),
+            severity: self.severity.merge(other.severity),
+            tags: self.tags.merge(other.tags),
+            status: self.status.merge(other.status),
+            archived: self.archived.merge(other.archived),
+            limit: self.limit.merge(other.limit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("debbugs");
+        let server = TestServer::new().await;
+        let service = Debbugs::new(server.uri()).unwrap();
+
+        // no selectors
+        let err = stream_result!(service.search()).unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        server
+            .respond_soap("get_bugs", path.join("search/valid"))
+            .await;
+
+        stream!(service.search().package(["bugbite"]));
+        stream!(service.search().package(["a", "b"]).severity(["serious"]));
+        stream!(service.search().source(["bugbite"]));
+        stream!(service.search().maintainer(["user@example.com"]));
+        stream!(service.search().reporter(["user@example.com"]));
+        stream!(service.search().tags(["patch"]).archived(true));
+
+        // status
+        stream!(service.search().package(["bugbite"]).status("open"));
+        stream!(service.search().package(["bugbite"]).status("done"));
+        let err = stream_result!(service.search().package(["a"]).status("invalid")).unwrap_err();
+        assert_err_re!(err, "invalid status: invalid");
+
+        // matching bugs are fetched newest first
+        server.reset().await;
+        server
+            .respond_soap("get_bugs", path.join("search/bugs.xml"))
+            .await;
+        server
+            .respond_soap("get_status", path.join("get/status.xml"))
+            .await;
+        let mut request = service.search();
+        request.package(["bugbite"]).limit(1);
+        let bugs = stream_result!(request).unwrap();
+        assert_ordered_eq!(bugs.iter().map(|x| x.id), [123456]);
+    }
+
+    #[test]
+    fn search_url() {
+        let service = Debbugs::new("https://bugs.debian.org").unwrap();
+        let mut request = service.search();
+        request.package(["bugbite"]).tags(["patch"]).status("open");
+        let url = request.search_url().unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://bugs.debian.org/cgi-bin/pkgreport.cgi?package=bugbite&tag=patch&status=open"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Write;
+
+use roxmltree::{Document, Node};
+use serde_json::{Map, Value};
+
+use crate::Error;
+
+const SOAP_ENV: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+const SOAP_ENC: &str = "http://schemas.xmlsoap.org/soap/encoding/";
+const XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+pub(super) const NAMESPACE: &str = "Debbugs/SOAP";
+
+/// Request argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Arg {
+    Int(u64),
+    String(String),
+    Array(Vec<Arg>),
+}
+
+impl From<u64> for Arg {
+    fn from(value: u64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<&str> for Arg {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<&String> for Arg {
+    fn from(value: &String) -> Self {
+        Self::String(value.clone())
+    }
+}
+
+impl<T: Into<Arg>> From<Vec<T>> for Arg {
+    fn from(values: Vec<T>) -> Self {
+        Self::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Arg {
+    fn xsd_type(&self) -> &str {
+        match self {
+            Self::Int(_) => "xsd:int",
+            Self::String(_) => "xsd:string",
+            Self::Array(_) => "soapenc:Array",
+        }
+    }
+
+    fn encode(&self, name: &str, s: &mut String) {
+        match self {
+            Self::Int(value) => {
+                write!(s, r#"<{name} xsi:type="xsd:int">{value}</{name}>"#).unwrap();
+            }
+            Self::String(value) => {
+                let value = escape(value);
+                write!(s, r#"<{name} xsi:type="xsd:string">{value}</{name}>"#).unwrap();
+            }
+            Self::Array(values) => {
+                let kind = values
+                    .first()
+                    .map(|x| x.xsd_type())
+                    .unwrap_or("xsd:anyType");
+                let len = values.len();
+                write!(
+                    s,
+                    r#"<{name} xsi:type="soapenc:Array" soapenc:arrayType="{kind}[{len}]">"#
+                )
+                .unwrap();
+                for value in values {
+                    value.encode("item", s);
+                }
+                write!(s, "</{name}>").unwrap();
+            }
+        }
+    }
+}
+
+/// Escape text for use in XML content.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Encode a method call with its arguments into a SOAP envelope.
+///
+/// Arguments are positional using the naming scheme generated by SOAP::Lite which
+/// powers the server side.
+pub(super) fn encode(method: &str, args: &[Arg]) -> String {
+    let mut s = String::new();
+    s.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    write!(
+        s,
+        r#"<soap:Envelope xmlns:soap="{SOAP_ENV}" xmlns:soapenc="{SOAP_ENC}" "#
+    )
+    .unwrap();
+    write!(
+        s,
+        r#"xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:xsi="{XSI}" "#
+    )
+    .unwrap();
+    write!(s, r#"soap:encodingStyle="{SOAP_ENC}">"#).unwrap();
+    write!(s, r#"<soap:Body><{method} xmlns="{NAMESPACE}">"#).unwrap();
+    for (i, arg) in args.iter().enumerate() {
+        arg.encode(&format!("c-gensym{}", i + 1), &mut s);
+    }
+    write!(s, "</{method}></soap:Body></soap:Envelope>").unwrap();
+    s
+}
+
+/// Decode the result value from a SOAP response envelope.
+///
+/// Encoded type hints are used to convert values into their JSON equivalents,
+/// allowing regular deserialization for response objects.
+pub(super) fn decode(data: &str) -> crate::Result<Value> {
+    let doc = Document::parse(data)
+        .map_err(|e| Error::InvalidResponse(format!("invalid SOAP response: {e}")))?;
+    let body = doc
+        .descendants()
+        .find(|x| x.has_tag_name((SOAP_ENV, "Body")))
+        .ok_or_else(|| Error::InvalidResponse("missing SOAP body".to_string()))?;
+    let response = body
+        .first_element_child()
+        .ok_or_else(|| Error::InvalidResponse("missing SOAP response".to_string()))?;
+
+    if response.has_tag_name((SOAP_ENV, "Fault")) {
+        let message = response
+            .children()
+            .find(|x| x.has_tag_name("faultstring"))
+            .and_then(|x| x.text())
+            .unwrap_or("unknown error");
+        return Err(Error::Debbugs(message.trim().to_string()));
+    }
+
+    // methods without return values have empty responses
+    Ok(response
+        .first_element_child()
+        .map(decode_node)
+        .unwrap_or_default())
+}
+
+/// Decode an XML element into a JSON value.
+fn decode_node(node: Node) -> Value {
+    if node.attribute((XSI, "nil")) == Some("true") {
+        return Value::Null;
+    }
+
+    let kind = node.attribute((XSI, "type")).unwrap_or_default();
+    let children: Vec<_> = node.children().filter(|x| x.is_element()).collect();
+
+    if children.is_empty() {
+        if kind.ends_with("Array") {
+            return Value::Array(vec![]);
+        } else if kind.ends_with("Map") {
+            return Value::Object(Default::default());
+        }
+
+        let text = node.text().unwrap_or_default();
+        return match kind.split_once(':').map(|(_, x)| x) {
+            Some("int" | "integer" | "long" | "short") => text
+                .parse::<i64>()
+                .map(Into::into)
+                .unwrap_or_else(|_| text.into()),
+            Some("boolean") => Value::Bool(matches!(text, "1" | "true")),
+            _ => text.into(),
+        };
+    }
+
+    let items = children.iter().all(|x| x.tag_name().name() == "item");
+    let map = |item: &Node| -> Option<(String, Value)> {
+        let key = item.children().find(|x| x.has_tag_name("key"))?;
+        let value = item.children().find(|x| x.has_tag_name("value"))?;
+        Some((
+            key.text().unwrap_or_default().to_string(),
+            decode_node(value),
+        ))
+    };
+
+    if items && !kind.ends_with("Array") {
+        // key-value pair mappings
+        if let Some(values) = children.iter().map(map).collect::<Option<Map<_, _>>>() {
+            return Value::Object(values);
+        }
+    }
+
+    if items || kind.ends_with("Array") {
+        Value::Array(children.into_iter().map(decode_node).collect())
+    } else {
+        let values = children
+            .into_iter()
+            .map(|x| (x.tag_name().name().to_string(), decode_node(x)))
+            .collect();
+        Value::Object(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn encode_args() {
+        let data = encode("get_bugs", &["package".into(), vec!["a&b", "c"].into()]);
+        assert!(data.contains(r#"<get_bugs xmlns="Debbugs/SOAP">"#));
+        assert!(data.contains(r#"<c-gensym1 xsi:type="xsd:string">package</c-gensym1>"#));
+        assert!(data.contains(
+            r#"soapenc:arrayType="xsd:string[2]"><item xsi:type="xsd:string">a&amp;b</item>"#
+        ));
+
+        // verify generated envelopes are valid
+        Document::parse(&data).unwrap();
+    }
+
+    #[test]
+    fn decode_values() {
+        let data = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"
+                xmlns:soapenc="http://schemas.xmlsoap.org/soap/encoding/"
+                xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+                xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+            <soap:Body>
+            <testResponse xmlns="urn:Debbugs/SOAP">
+            <s-gensym3 xsi:type="apachens:Map">
+                <item>
+                    <key xsi:type="xsd:int">1</key>
+                    <value>
+                        <int xsi:type="xsd:int">1</int>
+                        <string xsi:type="xsd:string">value</string>
+                        <nil xsi:nil="true"/>
+                        <empty soapenc:arrayType="xsd:anyType[0]" xsi:type="soapenc:Array"/>
+                        <array soapenc:arrayType="xsd:string[2]" xsi:type="soapenc:Array">
+                            <item xsi:type="xsd:string">a</item>
+                            <item xsi:type="xsd:string">b</item>
+                        </array>
+                    </value>
+                </item>
+            </s-gensym3>
+            </testResponse>
+            </soap:Body>
+            </soap:Envelope>"#;
+        let value = decode(data).unwrap();
+        assert_eq!(
+            value,
+            json!({"1": {
+                "int": 1,
+                "string": "value",
+                "nil": null,
+                "empty": [],
+                "array": ["a", "b"],
+            }})
+        );
+
+        // faults
+        let data = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+            <soap:Body>
+            <soap:Fault>
+                <faultcode>soap:Server</faultcode>
+                <faultstring>Failed to locate method (invalid)</faultstring>
+            </soap:Fault>
+            </soap:Body>
+            </soap:Envelope>"#;
+        let err = decode(data).unwrap_err();
+        assert_matches!(err, Error::Debbugs(_));
+        assert_eq!(
+            err.to_string(),
+            "debbugs: Failed to locate method (invalid)"
+        );
+
+        // invalid
+        let err = decode("invalid").unwrap_err();
+        assert_matches!(err, Error::InvalidResponse(_));
+    }
+}
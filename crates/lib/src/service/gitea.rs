@@ -0,0 +1,343 @@
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod comment;
+pub mod create;
+pub mod get;
+pub mod search;
+pub mod update;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub token: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(skip)]
+    api_base: OnceLock<Url>,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Maximum number of results that can be returned by a search request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of gitea's max search results.
+fn default_max_search_results() -> usize {
+    50
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            api_base: Default::default(),
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the repository API URL for the service.
+    ///
+    /// Repository paths are determined from the configured base URL, e.g.
+    /// https://codeberg.org/owner/repo maps to
+    /// https://codeberg.org/api/v1/repos/owner/repo/.
+    fn api_base(&self) -> &Url {
+        self.api_base.get_or_init(|| {
+            let repo = self.base.path().trim_matches('/');
+            let path = if repo.is_empty() {
+                "/api/v1/".to_string()
+            } else {
+                format!("/api/v1/repos/{repo}/")
+            };
+            let mut url = self.base.clone();
+            url.set_path(&path);
+            url
+        })
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Gitea
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn token(mut self, value: &str) -> Self {
+        self.config.auth.token = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Gitea> {
+        Ok(Gitea(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Gitea(Arc<Service>);
+
+impl PartialEq for Gitea {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Gitea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Gitea {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Gitea, name)?
+            .into_gitea()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/issues/{id}")
+    }
+
+    pub fn comment<I>(&self, ids: I) -> comment::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        comment::Request::new(self, ids)
+    }
+
+    pub fn create(&self) -> create::Request {
+        create::Request::new(self)
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+}
+
+impl WebService for Gitea {
+    const API_VERSION: &'static str = "v1";
+    type Response = serde_json::Value;
+
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        if let Some(token) = self.config().auth.token.as_ref() {
+            Ok(request.header("Authorization", format!("token {token}")))
+        } else if !required {
+            Ok(request)
+        } else {
+            Err(Error::Auth)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        match response.error_for_status_ref() {
+            // requests such as label removal return no content
+            Ok(_) if response.status() == StatusCode::NO_CONTENT => Ok(serde_json::Value::Null),
+            Ok(_) => {
+                let data: serde_json::Value = response.json().await?;
+                debug!(
+                    "response data:\n{}",
+                    serde_json::to_string_pretty(&data).unwrap()
+                );
+                Ok(data)
+            }
+            Err(e) => {
+                if let Ok(mut data) = response.json::<serde_json::Value>().await {
+                    debug!("error:\n{}", serde_json::to_string_pretty(&data).unwrap());
+                    match data["message"].take() {
+                        serde_json::Value::String(s) if !s.is_empty() => {
+                            return Err(Error::Gitea(s));
+                        }
+                        _ => (),
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+}
+
+impl WebClient for Gitea {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssueField {
+    /// people the issue is assigned to
+    Assignees,
+    /// person who created the issue
+    Author,
+    /// time when the issue was closed
+    Closed,
+    /// time when the issue was created
+    Created,
+    /// issue ID
+    Id,
+    /// issue labels
+    Labels,
+    /// issue milestone
+    Milestone,
+    /// issue state
+    State,
+    /// issue title
+    Title,
+    /// time when the issue was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+    use crate::traits::RequestSend;
+
+    use super::*;
+
+    #[test]
+    fn api_base() {
+        let config = Config::new("https://codeberg.org/owner/repo").unwrap();
+        assert_eq!(
+            config.api_base().as_str(),
+            "https://codeberg.org/api/v1/repos/owner/repo/"
+        );
+
+        let config = Config::new("https://codeberg.org").unwrap();
+        assert_eq!(config.api_base().as_str(), "https://codeberg.org/api/v1/");
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let server = TestServer::new().await;
+        let service = Gitea::new(server.uri()).unwrap();
+
+        // error messages are used when available
+        let template = ResponseTemplate::new(422).set_body_raw(
+            r#"{"message": "invalid state", "url": "https://codeberg.org/api/swagger"}"#,
+            "application/json",
+        );
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Gitea(_));
+        assert_err_re!(err, "invalid state");
+    }
+}
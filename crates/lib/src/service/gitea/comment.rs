@@ -0,0 +1,104 @@
+use serde_json::json;
+
+use crate::Error;
+use crate::service::gitea::Gitea;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Gitea,
+    pub ids: Vec<u64>,
+    text: Option<String>,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Gitea, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            text: None,
+        }
+    }
+
+    /// Set the comment text.
+    pub fn text<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.text = Some(value.into());
+        self
+    }
+}
+
+impl RequestSend for Request {
+    /// IDs of the created comments.
+    type Output = Vec<u64>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let text = self.text.as_deref().unwrap_or_default().trim();
+        if text.is_empty() {
+            return Err(Error::EmptyParams);
+        }
+
+        let base = self.service.config().api_base();
+        let mut comments = vec![];
+        for id in &self.ids {
+            let url = base.join(&format!("issues/{id}/comments"))?;
+            let request = self
+                .service
+                .client()
+                .post(url)
+                .json(&json!({"body": text}))
+                .auth(&self.service)?;
+            let response = request.send().await?;
+            let mut data = self.service.parse_response(response).await?;
+            let comment = serde_json::from_value(data["id"].take())
+                .map_err(|e| Error::InvalidResponse(format!("failed deserializing id: {e}")))?;
+            comments.push(comment);
+        }
+
+        Ok(comments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitea");
+        let server = TestServer::new().await;
+        let service = Gitea::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.comment(ids).text("text").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty comment
+        let err = service.comment([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // valid
+        server.respond(201, path.join("comment/valid.json")).await;
+        let ids = service.comment([1]).text("comment").send().await.unwrap();
+        assert_eq!(ids, [1002]);
+    }
+}
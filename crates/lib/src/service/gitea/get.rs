@@ -0,0 +1,272 @@
+use chrono::prelude::*;
+use futures_util::future::try_join_all;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::Error;
+use crate::objects::gitea::{Change, Comment, Event, Issue, Label, Milestone, User};
+use crate::service::gitea::Gitea;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Gitea,
+    pub ids: Vec<u64>,
+    comments: bool,
+    timeline: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Gitea, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+            timeline: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching timeline events.
+    pub fn timeline(&mut self, fetch: bool) -> &mut Self {
+        self.timeline = fetch;
+        self
+    }
+
+    fn url(&self, id: u64, path: &str) -> crate::Result<Url> {
+        let base = self.service.config().api_base();
+        let mut url = base.join(&format!("issues/{id}{path}"))?;
+        if path == "/timeline" {
+            // TODO: support fetching all pages for heavily updated issues
+            let limit = self.service.config().max_search_results;
+            url.query_pairs_mut()
+                .append_pair("limit", &limit.to_string());
+        }
+        Ok(url)
+    }
+
+    /// Send a request for a given issue subpath, deserializing the response.
+    async fn fetch<T>(&self, id: u64, path: &str) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.url(id, path)?;
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        // nonexistent issues return a generic error message
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(Error::Gitea(format!("nonexistent issue: {id}")));
+        }
+        let data = self.service.parse_response(response).await?;
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing {path}: {e}")))
+    }
+
+    async fn issue(&self, id: u64) -> crate::Result<Issue> {
+        let mut issue: Issue = self.fetch(id, "").await?;
+
+        if self.comments {
+            // treat description as a comment
+            if let Some(text) = issue.description.take() {
+                issue.comments.push(Comment {
+                    id: 0,
+                    count: 0,
+                    text,
+                    author: issue.author.clone().unwrap(),
+                    created: issue.created.unwrap(),
+                });
+            }
+
+            let comments: Vec<Comment> = self.fetch(id, "/comments").await?;
+            for (i, mut comment) in comments.into_iter().enumerate() {
+                comment.count = i as u64 + 1;
+                issue.comments.push(comment);
+            }
+        }
+
+        if self.timeline {
+            let timeline: Vec<TimelineEvent> = self.fetch(id, "/timeline").await?;
+
+            // combine changes made by the same user at the same time
+            for (user, created, change) in timeline.into_iter().filter_map(|x| x.into_change()) {
+                match issue.events.last_mut() {
+                    Some(event) if event.user == user && event.created == created => {
+                        event.changes.push(change);
+                    }
+                    _ => issue.events.push(Event {
+                        user,
+                        created,
+                        changes: vec![change],
+                    }),
+                }
+            }
+        }
+
+        Ok(issue)
+    }
+}
+
+/// Issue timeline entry.
+///
+/// Comments are included in the timeline as well, but are skipped in favor of
+/// the separate comments endpoint.
+#[derive(Deserialize)]
+struct TimelineEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    user: Option<User>,
+    created_at: DateTime<Utc>,
+    #[serde(default)]
+    body: String,
+    label: Option<Label>,
+    milestone: Option<Milestone>,
+    old_milestone: Option<Milestone>,
+    #[serde(default)]
+    old_title: String,
+    #[serde(default)]
+    new_title: String,
+    assignee: Option<User>,
+    #[serde(default)]
+    removed_assignee: bool,
+}
+
+impl TimelineEvent {
+    fn into_change(self) -> Option<(User, DateTime<Utc>, Change)> {
+        let (field, removed, added) = match self.kind.as_str() {
+            "label" => {
+                let label = self.label.map(|x| x.name);
+                // label additions use a body value of "1"
+                if self.body == "1" {
+                    ("labels", None, label)
+                } else {
+                    ("labels", label, None)
+                }
+            }
+            "milestone" => (
+                "milestone",
+                self.old_milestone.map(|x| x.title),
+                self.milestone.map(|x| x.title),
+            ),
+            "assignees" => {
+                let assignee = self.assignee.map(|x| x.login);
+                if self.removed_assignee {
+                    ("assignees", assignee, None)
+                } else {
+                    ("assignees", None, assignee)
+                }
+            }
+            "change_title" => ("title", Some(self.old_title), Some(self.new_title)),
+            "close" => ("state", None, Some("closed".to_string())),
+            "reopen" => ("state", None, Some("open".to_string())),
+            _ => return None,
+        };
+
+        if removed.is_none() && added.is_none() {
+            return None;
+        }
+
+        let change = Change {
+            field: field.to_string(),
+            removed,
+            added,
+        };
+        Some((self.user?, self.created_at, change))
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Issue>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.issue(*id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitea");
+        let server = TestServer::new().await;
+        let service = Gitea::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Gitea(_));
+        assert_err_re!(err, "nonexistent issue: 1");
+
+        server.reset().await;
+
+        // single
+        server.respond(200, path.join("get/single.json")).await;
+        let ids = [1];
+        let issues = service.get(ids).send().await.unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), ids);
+
+        server.reset().await;
+
+        // comments and timeline
+        server
+            .respond_match(
+                matchers::path_regex("/comments$"),
+                200,
+                path.join("get/comments.json"),
+            )
+            .await;
+        server
+            .respond_match(
+                matchers::path_regex("/timeline$"),
+                200,
+                path.join("get/timeline.json"),
+            )
+            .await;
+        server.respond(200, path.join("get/single.json")).await;
+        let issue = service
+            .get([1])
+            .comments(true)
+            .timeline(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        // description and a single user comment
+        assert_ordered_eq!(issue.comments.iter().map(|x| x.count), [0, 1]);
+        // comment entries are skipped and simultaneous changes are combined
+        assert_eq!(issue.events.len(), 2);
+        assert_eq!(issue.events[0].changes.len(), 2);
+        assert_eq!(issue.events[1].changes[0].field, "state");
+    }
+}
@@ -0,0 +1,427 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use futures_util::Stream;
+use itertools::{Either, Itertools};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::gitea::Issue;
+use crate::objects::{Range, RangeOp, RangeOrValue};
+use crate::query::Query;
+use crate::service::gitea::Gitea;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{
+    Api, InjectAuth, Merge, RequestPagedStream, RequestTemplate, WebClient, WebService,
+};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Gitea,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// Iterator of consecutive, paged requests.
+struct PagedIterator {
+    request: Request,
+}
+
+impl Iterator for PagedIterator {
+    type Item = Request;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let req = self.request.clone();
+        self.request.params.page = self.request.params.page.unwrap_or(1).checked_add(1);
+        req.params.page.map(|_| req)
+    }
+}
+
+impl RequestPagedStream for Request {
+    type Item = Issue;
+
+    fn concurrent(&self) -> Option<usize> {
+        self.service.config().client.concurrent
+    }
+
+    fn paged(&mut self) -> Option<usize> {
+        if self.params.paged.unwrap_or_default() || self.params.limit.is_none() {
+            self.params
+                .limit
+                .get_or_insert_with(|| self.service.config().max_search_results);
+            self.params.page.get_or_insert(1);
+            self.params.limit
+        } else {
+            None
+        }
+    }
+
+    fn paged_requests(self, paged: Option<usize>) -> impl Iterator<Item = Self> {
+        if paged.is_some() {
+            Either::Left(PagedIterator { request: self })
+        } else {
+            Either::Right([self].into_iter())
+        }
+    }
+
+    async fn send(self) -> crate::Result<Vec<Issue>> {
+        let mut url = self.service.config().api_base().join("issues")?;
+        let query = self.encode()?;
+        url.query_pairs_mut().extend_pairs(query.iter());
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self.service.parse_response(response).await?;
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issues: {e}")))
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Gitea) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Issue>> + 'static {
+        RequestPagedStream::paged_stream(self.clone())
+    }
+
+    fn encode(&self) -> crate::Result<QueryBuilder<'_>> {
+        let mut query = QueryBuilder::new(&self.service);
+
+        // exclude pull requests
+        query.insert("type", "issues");
+
+        if let Some(value) = &self.params.assignee {
+            query.insert("assigned_by", value);
+        }
+
+        if let Some(value) = &self.params.author {
+            query.insert("created_by", value);
+        }
+
+        if let Some(values) = &self.params.labels {
+            query.insert("labels", values.iter().join(","));
+        }
+
+        if let Some(values) = &self.params.milestones {
+            query.insert("milestones", values.iter().join(","));
+        }
+
+        if let Some(value) = &self.params.updated {
+            query.time(value)?;
+        }
+
+        if let Some(values) = &self.params.title {
+            query.insert("q", values.iter().join(" "));
+        }
+
+        // limit to open issues by default
+        query.state(self.params.state.as_deref().unwrap_or("open"))?;
+
+        if let Some(value) = &self.params.limit {
+            query.insert("limit", value);
+        }
+
+        if let Some(value) = &self.params.page {
+            query.insert("page", value);
+        }
+
+        Ok(query)
+    }
+
+    /// Return the website URL for a query.
+    ///
+    /// Only the state and title terms are used since the website matches other
+    /// attributes using internal IDs.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let base = self.service.base().as_str().trim_end_matches('/');
+        let mut url = Url::parse(&format!("{base}/issues"))?;
+        let query = self.encode()?;
+        url.query_pairs_mut().extend_pairs(
+            query
+                .iter()
+                .filter(|(k, _)| ["q", "state"].contains(&k.as_str())),
+        );
+        Ok(url)
+    }
+
+    pub fn assignee<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.assignee = Some(value.into());
+        self
+    }
+
+    pub fn author<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.author = Some(value.into());
+        self
+    }
+
+    pub fn labels<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.labels = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn milestones<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.milestones = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn updated(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+
+    pub fn state<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.state = Some(value.into());
+        self
+    }
+
+    pub fn title<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.title = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Gitea;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Issue search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<String>,
+    pub author: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub milestones: Option<Vec<String>>,
+
+    pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    pub limit: Option<usize>,
+    pub page: Option<usize>,
+    pub paged: Option<bool>,
+
+    pub state: Option<String>,
+    pub title: Option<Vec<String>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            author: self.author.merge(other.author),
+            labels: self.labels.merge(other.labels),
+            milestones: self.milestones.merge(other.milestones),
+            updated: self.updated.merge(other.updated),
+            limit: self.limit.merge(other.limit),
+            page: self.page.merge(other.page),
+            paged: self.paged.merge(other.paged),
+            state: self.state.merge(other.state),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+struct QueryBuilder<'a> {
+    _service: &'a Gitea,
+    query: Query,
+}
+
+impl Deref for QueryBuilder<'_> {
+    type Target = Query;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl DerefMut for QueryBuilder<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+impl<'a> QueryBuilder<'a> {
+    fn new(_service: &'a Gitea) -> Self {
+        Self {
+            _service,
+            query: Default::default(),
+        }
+    }
+
+    fn state(&mut self, value: &str) -> crate::Result<()> {
+        match value {
+            "open" | "closed" | "all" => self.insert("state", value),
+            _ => return Err(Error::InvalidValue(format!("invalid state: {value}"))),
+        }
+
+        Ok(())
+    }
+
+    // Gitea only supports exclusive before and after bounds for update times,
+    // so inclusive bounds are treated as exclusive.
+    fn time(&mut self, value: &RangeOrValue<TimeDeltaOrStatic>) -> crate::Result<()> {
+        match value {
+            RangeOrValue::Value(value) => self.insert("since", value.api()),
+            RangeOrValue::RangeOp(value) => self.range_op(value)?,
+            RangeOrValue::Range(value) => self.range(value),
+        }
+
+        Ok(())
+    }
+
+    fn range_op<T>(&mut self, value: &RangeOp<T>) -> crate::Result<()>
+    where
+        T: Api + Eq + fmt::Display,
+    {
+        match value {
+            RangeOp::Less(value) | RangeOp::LessOrEqual(value) => {
+                self.insert("before", value.api());
+            }
+            RangeOp::GreaterOrEqual(value) | RangeOp::Greater(value) => {
+                self.insert("since", value.api());
+            }
+            RangeOp::Equal(_) | RangeOp::NotEqual(_) => {
+                return Err(Error::InvalidValue(format!(
+                    "unsupported time operator: {value}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn range<T>(&mut self, value: &Range<T>)
+    where
+        T: Api + Eq,
+    {
+        match value {
+            Range::Range(r) => {
+                self.insert("since", r.start.api());
+                self.insert("before", r.end.api());
+            }
+            Range::Inclusive(r) => {
+                self.insert("since", r.start().api());
+                self.insert("before", r.end().api());
+            }
+            Range::To(r) => self.insert("before", r.end.api()),
+            Range::ToInclusive(r) => self.insert("before", r.end.api()),
+            Range::From(r) => self.insert("since", r.start.api()),
+            Range::Full(_) => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitea");
+        let server = TestServer::new().await;
+        let service = Gitea::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid TimeDeltaOrStatic values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+            "<1d",
+            "<=1w",
+            ">=1m",
+            ">1y",
+            "2020..2021",
+            "2020..=2021",
+            "..2021",
+            "..=2021",
+            "2021..",
+            "..",
+        ];
+
+        // time related combinators
+        for time in &times {
+            stream!(service.search().updated(time.parse().unwrap()));
+        }
+        let err = stream_result!(service.search().updated("=2020".parse().unwrap())).unwrap_err();
+        assert_err_re!(err, "unsupported time operator: =2020");
+
+        // users
+        stream!(service.search().author("user"));
+        stream!(service.search().assignee("user"));
+
+        // labels
+        stream!(service.search().labels(["bug"]));
+        stream!(service.search().labels(["bug", "regression"]));
+
+        // milestones
+        stream!(service.search().milestones(["v1"]));
+        stream!(service.search().milestones(["v1", "v2"]));
+
+        // title
+        stream!(service.search().title(["test"]));
+        stream!(service.search().title(["test1", "test2"]));
+
+        // state
+        stream!(service.search().state("open"));
+        stream!(service.search().state("closed"));
+        stream!(service.search().state("all"));
+        let err = stream_result!(service.search().state("invalid")).unwrap_err();
+        assert_err_re!(err, "invalid state: invalid");
+    }
+
+    #[test]
+    fn search_url() {
+        let service = Gitea::new("https://codeberg.org/owner/repo").unwrap();
+        let mut request = service.search();
+        request.labels(["bug"]).title(["crash"]);
+        let url = request.search_url().unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://codeberg.org/owner/repo/issues?q=crash&state=open"
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+use strum::{Display, EnumString};
+
+use crate::Error;
+use crate::service::bugzilla::update::SetChange;
+use crate::service::gitea::Gitea;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Gitea,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        let base = self.service.config().api_base();
+        let params = self.encode();
+        let labels = self.labels();
+        for id in &self.ids {
+            if let Some(params) = &params {
+                let url = base.join(&format!("issues/{id}"))?;
+                let request = self
+                    .service
+                    .client()
+                    .patch(url)
+                    .json(params)
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+
+            // labels are altered separately from other issue attributes
+            if let Some(labels) = &labels {
+                let url = base.join(&format!("issues/{id}/labels"))?;
+                let client = self.service.client();
+                let mut requests = vec![];
+                if !labels.set.is_empty() {
+                    requests.push(client.put(url).json(&json!({"labels": labels.set})));
+                } else {
+                    if !labels.add.is_empty() {
+                        requests.push(client.post(url).json(&json!({"labels": labels.add})));
+                    }
+                    for label in &labels.remove {
+                        let url = base.join(&format!("issues/{id}/labels/{label}"))?;
+                        requests.push(client.delete(url));
+                    }
+                }
+
+                for request in requests {
+                    let response = request.auth(&self.service)?.send().await?;
+                    self.service.parse_response(response).await?;
+                }
+            }
+
+            if let Some(comment) = &self.params.comment {
+                let url = base.join(&format!("issues/{id}/comments"))?;
+                let request = self
+                    .service
+                    .client()
+                    .post(url)
+                    .json(&json!({"body": comment}))
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Gitea;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Gitea, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode issue attribute changes into the form required for the request.
+    fn encode(&self) -> Option<RequestParameters<'_>> {
+        let params = RequestParameters {
+            assignees: self.params.assignees.as_deref(),
+            body: self.params.description.as_deref(),
+            milestone: self.params.milestone,
+            state: self.params.state,
+            title: self.params.title.as_deref(),
+        };
+
+        if params == RequestParameters::default() {
+            None
+        } else {
+            Some(params)
+        }
+    }
+
+    /// Split label changes into their respective variants.
+    fn labels(&self) -> Option<LabelChanges> {
+        let values = self.params.labels.as_ref()?;
+        let mut labels = LabelChanges::default();
+        for change in values {
+            match change {
+                SetChange::Add(value) => labels.add.insert(*value),
+                SetChange::Remove(value) => labels.remove.insert(*value),
+                SetChange::Set(value) => labels.set.insert(*value),
+            };
+        }
+        Some(labels)
+    }
+}
+
+/// Label changes where set values override the entire list, ignoring any
+/// prefixed values.
+#[derive(Default)]
+struct LabelChanges {
+    add: IndexSet<u64>,
+    remove: IndexSet<u64>,
+    set: IndexSet<u64>,
+}
+
+/// Issue states.
+#[derive(Display, EnumString, Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum State {
+    Closed,
+    Open,
+}
+
+/// Issue update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignees: Option<Vec<String>>,
+    pub comment: Option<String>,
+    pub description: Option<String>,
+    pub labels: Option<Vec<SetChange<u64>>>,
+    pub milestone: Option<u64>,
+    pub state: Option<State>,
+    pub title: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignees: self.assignees.merge(other.assignees),
+            comment: self.comment.merge(other.comment),
+            description: self.description.merge(other.description),
+            labels: self.labels.merge(other.labels),
+            milestone: self.milestone.merge(other.milestone),
+            state: self.state.merge(other.state),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+/// Internal issue update request parameters.
+///
+/// See https://codeberg.org/api/swagger#/issue/issueEditIssue for more information.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+struct RequestParameters<'a> {
+    assignees: Option<&'a [String]>,
+    body: Option<&'a str>,
+    milestone: Option<u64>,
+    state: Option<State>,
+    title: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitea");
+        let server = TestServer::new().await;
+        let service = Gitea::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // label removal returns no content
+        let template = ResponseTemplate::new(204);
+        server
+            .respond_custom(matchers::method("DELETE"), template)
+            .await;
+
+        // valid
+        server.respond(201, path.join("get/single.json")).await;
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.labels = Some(vec!["+10".parse().unwrap(), "-12".parse().unwrap()]);
+        request.params.state = Some(State::Closed);
+        request.send().await.unwrap();
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod create;
+pub mod get;
+pub mod search;
+pub mod update;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub token: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(skip)]
+    api_base: OnceLock<Url>,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Project path, required for instances served under a URL subpath.
+    pub project: Option<String>,
+
+    /// Maximum number of results that can be returned by a search request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of gitlab's max search results.
+fn default_max_search_results() -> usize {
+    100
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            api_base: Default::default(),
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            project: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the project API URL for the service.
+    ///
+    /// Project paths are determined from the configured base URL, e.g.
+    /// https://gitlab.com/group/project maps to
+    /// https://gitlab.com/api/v4/projects/group%2Fproject/.
+    ///
+    /// If a project is specified, it's stripped from the end of the base URL path with
+    /// the remaining prefix used as the instance subpath, e.g.
+    /// https://host/gitlab/group/project with the project group/project maps to
+    /// https://host/gitlab/api/v4/projects/group%2Fproject/.
+    fn api_base(&self) -> &Url {
+        self.api_base.get_or_init(|| {
+            let path = self.base.path().trim_matches('/');
+            let (prefix, project) = if let Some(project) = &self.project {
+                let project = project.trim_matches('/');
+                let prefix = path.strip_suffix(project).unwrap_or(path);
+                (prefix.trim_end_matches('/'), project)
+            } else {
+                ("", path)
+            };
+            let prefix = if prefix.is_empty() {
+                String::new()
+            } else {
+                format!("/{prefix}")
+            };
+            let project = project.replace('/', "%2F");
+            let path = if project.is_empty() {
+                format!("{prefix}/api/v4/")
+            } else {
+                format!("{prefix}/api/v4/projects/{project}/")
+            };
+            let mut url = self.base.clone();
+            url.set_path(&path);
+            url
+        })
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Gitlab
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn token(mut self, value: &str) -> Self {
+        self.config.auth.token = Some(value.to_string());
+        self
+    }
+
+    pub fn project(mut self, value: &str) -> Self {
+        self.config.project = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Gitlab> {
+        Ok(Gitlab(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Gitlab(Arc<Service>);
+
+impl PartialEq for Gitlab {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Gitlab {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Gitlab {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Gitlab, name)?
+            .into_gitlab()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/-/issues/{id}")
+    }
+
+    pub fn create(&self) -> create::Request {
+        create::Request::new(self)
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+}
+
+impl WebService for Gitlab {
+    const API_VERSION: &'static str = "v4";
+    type Response = serde_json::Value;
+
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        if let Some(token) = self.config().auth.token.as_ref() {
+            Ok(request.header("PRIVATE-TOKEN", token))
+        } else if !required {
+            Ok(request)
+        } else {
+            Err(Error::Auth)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        match response.error_for_status_ref() {
+            Ok(_) => {
+                let data: serde_json::Value = response.json().await?;
+                debug!(
+                    "response data:\n{}",
+                    serde_json::to_string_pretty(&data).unwrap()
+                );
+                Ok(data)
+            }
+            Err(e) => {
+                if let Ok(mut data) = response.json::<serde_json::Value>().await {
+                    debug!("error:\n{}", serde_json::to_string_pretty(&data).unwrap());
+                    // errors use either a `message` or `error` field with varying value types
+                    let error = match data["message"].take() {
+                        serde_json::Value::Null => data["error"].take(),
+                        value => value,
+                    };
+                    match error {
+                        serde_json::Value::Null => (),
+                        serde_json::Value::String(s) => return Err(Error::Gitlab(s)),
+                        value => return Err(Error::Gitlab(value.to_string())),
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+}
+
+impl WebClient for Gitlab {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssueField {
+    /// people the issue is assigned to
+    Assignees,
+    /// person who created the issue
+    Author,
+    /// time when the issue was closed
+    Closed,
+    /// time when the issue was created
+    Created,
+    /// issue ID
+    Id,
+    /// issue labels
+    Labels,
+    /// issue milestone
+    Milestone,
+    /// issue state
+    State,
+    /// issue title
+    Title,
+    /// time when the issue was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_base() {
+        let config = Config::new("https://gitlab.com/group/subgroup/project").unwrap();
+        assert_eq!(
+            config.api_base().as_str(),
+            "https://gitlab.com/api/v4/projects/group%2Fsubgroup%2Fproject/"
+        );
+
+        let config = Config::new("https://gitlab.com").unwrap();
+        assert_eq!(config.api_base().as_str(), "https://gitlab.com/api/v4/");
+
+        // instance served under a subpath
+        let mut config = Config::new("https://host/gitlab/group/project").unwrap();
+        config.project = Some("group/project".to_string());
+        assert_eq!(
+            config.api_base().as_str(),
+            "https://host/gitlab/api/v4/projects/group%2Fproject/"
+        );
+
+        // project matching the entire path
+        let mut config = Config::new("https://gitlab.com/group/project").unwrap();
+        config.project = Some("group/project".to_string());
+        assert_eq!(
+            config.api_base().as_str(),
+            "https://gitlab.com/api/v4/projects/group%2Fproject/"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::service::gitlab::Gitlab;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Gitlab,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = u64;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let url = self.service.config().api_base().join("issues")?;
+        let params = self.encode()?;
+        let request = self
+            .service
+            .client()
+            .post(url)
+            .json(&params)
+            .auth(&self.service)?;
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        serde_json::from_value(data["iid"].take())
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing id: {e}")))
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Gitlab;
+    const TYPE: &'static str = "create";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Gitlab) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode parameters into the form required for the request.
+    fn encode(&self) -> crate::Result<RequestParameters<'_>> {
+        let title = self.params.title.as_deref().unwrap_or_default();
+        if title.is_empty() {
+            return Err(Error::InvalidRequest(
+                "missing required fields: title".to_string(),
+            ));
+        }
+
+        Ok(RequestParameters {
+            title,
+            assignee_ids: self.params.assignees.as_deref(),
+            confidential: self.params.confidential,
+            description: self.params.description.as_deref(),
+            labels: self.params.labels.as_ref().map(|x| x.iter().join(",")),
+            milestone_id: self.params.milestone,
+        })
+    }
+
+    pub fn assignees<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.params.assignees = Some(values.into_iter().collect());
+        self
+    }
+
+    pub fn description<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.description = Some(value.into());
+        self
+    }
+
+    pub fn labels<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.labels = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn title<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.title = Some(value.into());
+        self
+    }
+}
+
+/// Issue creation parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignees: Option<Vec<u64>>,
+    pub confidential: Option<bool>,
+    pub description: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub milestone: Option<u64>,
+    pub title: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignees: self.assignees.merge(other.assignees),
+            confidential: self.confidential.merge(other.confidential),
+            description: self.description.merge(other.description),
+            labels: self.labels.merge(other.labels),
+            milestone: self.milestone.merge(other.milestone),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+/// Internal issue creation request parameters.
+///
+/// See https://docs.gitlab.com/api/issues/#new-issue for more information.
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct RequestParameters<'a> {
+    // required fields
+    title: &'a str,
+    // optional fields
+    assignee_ids: Option<&'a [u64]>,
+    confidential: Option<bool>,
+    description: Option<&'a str>,
+    labels: Option<String>,
+    milestone_id: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitlab");
+        let server = TestServer::new().await;
+        let service = Gitlab::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // missing required fields
+        let err = service.create().send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "missing required fields: title");
+
+        // valid
+        server.respond(200, path.join("get/single.json")).await;
+        let id = service
+            .create()
+            .title("title")
+            .description("description")
+            .labels(["bug"])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(id, 1);
+    }
+}
@@ -0,0 +1,371 @@
+use chrono::prelude::*;
+use futures_util::future::try_join_all;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::Error;
+use crate::objects::gitlab::{Change, Comment, Event, Issue, Milestone, User};
+use crate::service::gitlab::Gitlab;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+/// Number of items requested per page for issue comments and events.
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug)]
+pub struct Request {
+    service: Gitlab,
+    pub ids: Vec<u64>,
+    comments: bool,
+    events: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Gitlab, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+            events: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching resource events.
+    pub fn events(&mut self, fetch: bool) -> &mut Self {
+        self.events = fetch;
+        self
+    }
+
+    fn url(&self, id: u64, path: &str, page: Option<usize>) -> crate::Result<Url> {
+        let base = self.service.config().api_base();
+        let mut url = base.join(&format!("issues/{id}{path}"))?;
+        if let Some(page) = page {
+            url.query_pairs_mut()
+                .append_pair("sort", "asc")
+                .append_pair("per_page", &PAGE_SIZE.to_string())
+                .append_pair("page", &page.to_string());
+        }
+        Ok(url)
+    }
+
+    /// Send a request for a given issue subpath, deserializing the response.
+    async fn fetch<T>(&self, id: u64, path: &str, page: Option<usize>) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.url(id, path, page)?;
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self
+            .service
+            .parse_response(response)
+            .await
+            .map_err(|e| match e {
+                Error::Request(e) if e.status() == Some(StatusCode::NOT_FOUND) => {
+                    Error::Gitlab(format!("nonexistent issue: {id}"))
+                }
+                _ => e,
+            })?;
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing {path}: {e}")))
+    }
+
+    /// Send requests for all pages of a given issue subpath.
+    async fn fetch_all<T>(&self, id: u64, path: &str) -> crate::Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut values = vec![];
+        for page in 1.. {
+            let data: Vec<T> = self.fetch(id, path, Some(page)).await?;
+            let last = data.len() < PAGE_SIZE;
+            values.extend(data);
+            if last {
+                break;
+            }
+        }
+        Ok(values)
+    }
+
+    async fn issue(&self, id: u64) -> crate::Result<Issue> {
+        let mut issue: Issue = self.fetch(id, "", None).await?;
+
+        if self.comments {
+            let mut count = 0;
+            // treat description as a comment
+            if let Some(text) = issue.description.take() {
+                issue.comments.push(Comment {
+                    id: 0,
+                    count,
+                    text,
+                    author: issue.author.clone().unwrap(),
+                    created: issue.created.unwrap(),
+                });
+            }
+
+            let notes: Vec<Note> = self.fetch_all(id, "/notes").await?;
+            // skip notes automatically generated for issue changes
+            for note in notes.into_iter().filter(|x| !x.system) {
+                count += 1;
+                let mut comment = note.comment;
+                comment.count = count;
+                issue.comments.push(comment);
+            }
+        }
+
+        if self.events {
+            let (labels, states, milestones) = futures_util::try_join!(
+                self.fetch_all::<LabelEvent>(id, "/resource_label_events"),
+                self.fetch_all::<StateEvent>(id, "/resource_state_events"),
+                self.fetch_all::<MilestoneEvent>(id, "/resource_milestone_events"),
+            )?;
+
+            let mut changes: Vec<_> = labels
+                .into_iter()
+                .filter_map(|x| x.into_change())
+                .chain(states.into_iter().map(|x| x.into_change()))
+                .chain(milestones.into_iter().filter_map(|x| x.into_change()))
+                .collect();
+            changes.sort_by_key(|(_, created, _)| *created);
+
+            // combine changes made by the same user at the same time
+            for (user, created, change) in changes {
+                match issue.events.last_mut() {
+                    Some(event) if event.user == user && event.created == created => {
+                        event.changes.push(change);
+                    }
+                    _ => issue.events.push(Event {
+                        user,
+                        created,
+                        changes: vec![change],
+                    }),
+                }
+            }
+        }
+
+        Ok(issue)
+    }
+}
+
+#[derive(Deserialize)]
+struct Note {
+    #[serde(flatten)]
+    comment: Comment,
+    #[serde(default)]
+    system: bool,
+}
+
+#[derive(Deserialize)]
+struct Label {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct LabelEvent {
+    user: User,
+    created_at: DateTime<Utc>,
+    label: Option<Label>,
+    action: String,
+}
+
+impl LabelEvent {
+    fn into_change(self) -> Option<(User, DateTime<Utc>, Change)> {
+        let label = self.label.map(|x| x.name);
+        let (removed, added) = match self.action.as_str() {
+            "remove" => (label, None),
+            _ => (None, label),
+        };
+        // skip events for deleted labels
+        if removed.is_none() && added.is_none() {
+            return None;
+        }
+        let change = Change {
+            field: "labels".to_string(),
+            removed,
+            added,
+        };
+        Some((self.user, self.created_at, change))
+    }
+}
+
+#[derive(Deserialize)]
+struct StateEvent {
+    user: User,
+    created_at: DateTime<Utc>,
+    state: String,
+}
+
+impl StateEvent {
+    fn into_change(self) -> (User, DateTime<Utc>, Change) {
+        let change = Change {
+            field: "state".to_string(),
+            removed: None,
+            added: Some(self.state),
+        };
+        (self.user, self.created_at, change)
+    }
+}
+
+#[derive(Deserialize)]
+struct MilestoneEvent {
+    user: User,
+    created_at: DateTime<Utc>,
+    milestone: Option<Milestone>,
+    action: String,
+}
+
+impl MilestoneEvent {
+    fn into_change(self) -> Option<(User, DateTime<Utc>, Change)> {
+        let milestone = self.milestone.map(|x| x.title);
+        let (removed, added) = match self.action.as_str() {
+            "remove" => (milestone, None),
+            _ => (None, milestone),
+        };
+        // skip events for deleted milestones
+        if removed.is_none() && added.is_none() {
+            return None;
+        }
+        let change = Change {
+            field: "milestone".to_string(),
+            removed,
+            added,
+        };
+        Some((self.user, self.created_at, change))
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Issue>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.issue(*id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitlab");
+        let server = TestServer::new().await;
+        let service = Gitlab::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Gitlab(_));
+        assert_err_re!(err, "nonexistent issue: 1");
+
+        server.reset().await;
+
+        // single
+        server.respond(200, path.join("get/single.json")).await;
+        let ids = [1];
+        let issues = service.get(ids).send().await.unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), ids);
+
+        server.reset().await;
+
+        // comments and events
+        server
+            .respond_match(
+                matchers::path_regex("/notes$"),
+                200,
+                path.join("get/notes.json"),
+            )
+            .await;
+        server
+            .respond_match(
+                matchers::path_regex("/resource_label_events$"),
+                200,
+                path.join("get/label-events.json"),
+            )
+            .await;
+        server
+            .respond_match(
+                matchers::path_regex("/resource_(state|milestone)_events$"),
+                200,
+                path.join("get/empty.json"),
+            )
+            .await;
+        server.respond(200, path.join("get/single.json")).await;
+        let issue = service
+            .get([1])
+            .comments(true)
+            .events(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        // description and a single user comment, skipping system notes
+        assert_ordered_eq!(issue.comments.iter().map(|x| x.count), [0, 1]);
+        // events for deleted labels are skipped
+        assert_eq!(issue.events.len(), 1);
+        assert_eq!(issue.events[0].changes.len(), 2);
+
+        server.reset().await;
+
+        // comments spanning multiple pages
+        let note = serde_json::json!({
+            "id": 301,
+            "body": "comment",
+            "author": {"id": 101, "username": "susan", "name": "Susan Miller"},
+            "created_at": "2024-02-15T16:00:26.000Z",
+        });
+        let notes = vec![note; PAGE_SIZE];
+        let template = ResponseTemplate::new(200).set_body_json(notes);
+        server
+            .respond_custom(matchers::query_param("page", "1"), template)
+            .await;
+        server
+            .respond_match(
+                matchers::query_param("page", "2"),
+                200,
+                path.join("get/notes.json"),
+            )
+            .await;
+        server.respond(200, path.join("get/single.json")).await;
+        let issue = service
+            .get([1])
+            .comments(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(issue.comments.len(), PAGE_SIZE + 2);
+    }
+}
@@ -0,0 +1,520 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use futures_util::Stream;
+use itertools::{Either, Itertools};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use strum::{Display, EnumIter, EnumString};
+use url::Url;
+
+use crate::Error;
+use crate::args::ExistsOrValues;
+use crate::objects::gitlab::Issue;
+use crate::objects::{Range, RangeOp, RangeOrValue};
+use crate::query::{Order, Query};
+use crate::service::gitlab::Gitlab;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{
+    Api, InjectAuth, Merge, RequestPagedStream, RequestTemplate, WebClient, WebService,
+};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Gitlab,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// Iterator of consecutive, paged requests.
+struct PagedIterator {
+    request: Request,
+}
+
+impl Iterator for PagedIterator {
+    type Item = Request;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let req = self.request.clone();
+        self.request.params.page = self.request.params.page.unwrap_or(1).checked_add(1);
+        req.params.page.map(|_| req)
+    }
+}
+
+impl RequestPagedStream for Request {
+    type Item = Issue;
+
+    fn concurrent(&self) -> Option<usize> {
+        self.service.config().client.concurrent
+    }
+
+    fn paged(&mut self) -> Option<usize> {
+        if self.params.paged.unwrap_or_default() || self.params.limit.is_none() {
+            self.params
+                .limit
+                .get_or_insert_with(|| self.service.config().max_search_results);
+            self.params.page.get_or_insert(1);
+            self.params.limit
+        } else {
+            None
+        }
+    }
+
+    fn paged_requests(self, paged: Option<usize>) -> impl Iterator<Item = Self> {
+        if paged.is_some() {
+            Either::Left(PagedIterator { request: self })
+        } else {
+            Either::Right([self].into_iter())
+        }
+    }
+
+    async fn send(self) -> crate::Result<Vec<Issue>> {
+        let mut url = self.service.config().api_base().join("issues")?;
+        let query = self.encode()?;
+        url.query_pairs_mut().extend_pairs(query.iter());
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self.service.parse_response(response).await?;
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issues: {e}")))
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Gitlab) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Issue>> + 'static {
+        RequestPagedStream::paged_stream(self.clone())
+    }
+
+    fn encode(&self) -> crate::Result<QueryBuilder<'_>> {
+        let mut query = QueryBuilder::new(&self.service);
+
+        if let Some(values) = &self.params.assignee {
+            match values {
+                ExistsOrValues::Exists(value) => query.exists("assignee_id", *value),
+                ExistsOrValues::Values(values) => {
+                    for value in values {
+                        query.append("assignee_username[]", value);
+                    }
+                }
+            }
+        }
+
+        if let Some(value) = &self.params.author {
+            query.insert("author_username", value);
+        }
+
+        if let Some(values) = &self.params.ids {
+            for value in values {
+                query.append("iids[]", value);
+            }
+        }
+
+        if let Some(values) = &self.params.labels {
+            match values {
+                ExistsOrValues::Exists(value) => query.exists("labels", *value),
+                ExistsOrValues::Values(values) => query.insert("labels", values.iter().join(",")),
+            }
+        }
+
+        if let Some(value) = &self.params.milestone {
+            match value {
+                ExistsOrValues::Exists(value) => query.exists("milestone", *value),
+                ExistsOrValues::Values(values) => match values.as_slice() {
+                    [value] => query.insert("milestone", value),
+                    _ => {
+                        return Err(Error::InvalidValue(
+                            "multiple milestones specified".to_string(),
+                        ));
+                    }
+                },
+            }
+        }
+
+        if let Some(value) = &self.params.created {
+            query.time("created", value)?;
+        }
+
+        if let Some(value) = &self.params.updated {
+            query.time("updated", value)?;
+        }
+
+        if let Some(values) = &self.params.title {
+            query.insert("search", values.iter().join(" "));
+            query.insert("in", "title");
+        }
+
+        // limit to open issues by default
+        query.state(self.params.state.as_deref().unwrap_or("opened"))?;
+
+        if let Some(value) = &self.params.order {
+            let (field, sort) = match value {
+                Order::Ascending(field) => (field, "asc"),
+                Order::Descending(field) => (field, "desc"),
+            };
+            query.insert("order_by", field);
+            query.insert("sort", sort);
+        }
+
+        if let Some(value) = &self.params.limit {
+            query.insert("per_page", value);
+        }
+
+        if let Some(value) = &self.params.page {
+            query.insert("page", value);
+        }
+
+        Ok(query)
+    }
+
+    /// Return the website URL for a query.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let base = self.service.base().as_str().trim_end_matches('/');
+        let mut url = Url::parse(&format!("{base}/-/issues"))?;
+        let query = self.encode()?;
+        url.query_pairs_mut().extend_pairs(query.iter());
+        Ok(url)
+    }
+
+    pub fn id<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.params.ids = Some(values.into_iter().collect());
+        self
+    }
+
+    pub fn assignee(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.assignee = Some(value);
+        self
+    }
+
+    pub fn author<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.author = Some(value.into());
+        self
+    }
+
+    pub fn labels(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.labels = Some(value);
+        self
+    }
+
+    pub fn milestone(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.milestone = Some(value);
+        self
+    }
+
+    pub fn created(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.created = Some(value);
+        self
+    }
+
+    pub fn updated(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+
+    pub fn order(&mut self, value: Order<OrderField>) -> &mut Self {
+        self.params.order = Some(value);
+        self
+    }
+
+    pub fn state<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.state = Some(value.into());
+        self
+    }
+
+    pub fn title<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.title = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Gitlab;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Issue search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<ExistsOrValues<String>>,
+    pub author: Option<String>,
+    pub ids: Option<Vec<u64>>,
+    pub labels: Option<ExistsOrValues<String>>,
+    pub milestone: Option<ExistsOrValues<String>>,
+
+    pub created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+    pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    pub limit: Option<usize>,
+    pub page: Option<usize>,
+    pub order: Option<Order<OrderField>>,
+    pub paged: Option<bool>,
+
+    pub state: Option<String>,
+    pub title: Option<Vec<String>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            author: self.author.merge(other.author),
+            ids: self.ids.merge(other.ids),
+            labels: self.labels.merge(other.labels),
+            milestone: self.milestone.merge(other.milestone),
+            created: self.created.merge(other.created),
+            updated: self.updated.merge(other.updated),
+            limit: self.limit.merge(other.limit),
+            page: self.page.merge(other.page),
+            order: self.order.merge(other.order),
+            paged: self.paged.merge(other.paged),
+            state: self.state.merge(other.state),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+struct QueryBuilder<'a> {
+    _service: &'a Gitlab,
+    query: Query,
+}
+
+impl Deref for QueryBuilder<'_> {
+    type Target = Query;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl DerefMut for QueryBuilder<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+impl<'a> QueryBuilder<'a> {
+    fn new(_service: &'a Gitlab) -> Self {
+        Self {
+            _service,
+            query: Default::default(),
+        }
+    }
+
+    /// Match conditionally existent field values.
+    fn exists(&mut self, field: &str, status: bool) {
+        let status = if status { "Any" } else { "None" };
+        self.insert(field, status);
+    }
+
+    fn state(&mut self, value: &str) -> crate::Result<()> {
+        match value {
+            "opened" | "closed" => self.insert("state", value),
+            // all states are returned when unspecified
+            "all" => (),
+            _ => return Err(Error::InvalidValue(format!("invalid state: {value}"))),
+        }
+
+        Ok(())
+    }
+
+    // GitLab only supports exclusive before and after time bounds, so inclusive
+    // bounds are treated as exclusive.
+    fn time(&mut self, field: &str, value: &RangeOrValue<TimeDeltaOrStatic>) -> crate::Result<()> {
+        match value {
+            RangeOrValue::Value(value) => self.insert(format!("{field}_after"), value.api()),
+            RangeOrValue::RangeOp(value) => self.range_op(field, value)?,
+            RangeOrValue::Range(value) => self.range(field, value),
+        }
+
+        Ok(())
+    }
+
+    fn range_op<T>(&mut self, field: &str, value: &RangeOp<T>) -> crate::Result<()>
+    where
+        T: Api + Eq + fmt::Display,
+    {
+        match value {
+            RangeOp::Less(value) | RangeOp::LessOrEqual(value) => {
+                self.insert(format!("{field}_before"), value.api());
+            }
+            RangeOp::GreaterOrEqual(value) | RangeOp::Greater(value) => {
+                self.insert(format!("{field}_after"), value.api());
+            }
+            RangeOp::Equal(_) | RangeOp::NotEqual(_) => {
+                return Err(Error::InvalidValue(format!(
+                    "unsupported time operator: {value}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn range<T>(&mut self, field: &str, value: &Range<T>)
+    where
+        T: Api + Eq,
+    {
+        match value {
+            Range::Range(r) => {
+                self.insert(format!("{field}_after"), r.start.api());
+                self.insert(format!("{field}_before"), r.end.api());
+            }
+            Range::Inclusive(r) => {
+                self.insert(format!("{field}_after"), r.start().api());
+                self.insert(format!("{field}_before"), r.end().api());
+            }
+            Range::To(r) => self.insert(format!("{field}_before"), r.end.api()),
+            Range::ToInclusive(r) => self.insert(format!("{field}_before"), r.end.api()),
+            Range::From(r) => self.insert(format!("{field}_after"), r.start.api()),
+            Range::Full(_) => (),
+        }
+    }
+}
+
+/// Valid search order sorting terms.
+#[derive(Display, EnumIter, EnumString, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OrderField {
+    /// time when the issue was created
+    Created,
+    /// issue due date
+    Due,
+    /// issue popularity
+    Popularity,
+    /// issue priority
+    Priority,
+    /// issue title
+    Title,
+    /// time when the issue was last updated
+    Updated,
+}
+
+impl Api for OrderField {
+    fn api(&self) -> String {
+        let value = match self {
+            Self::Created => "created_at",
+            Self::Due => "due_date",
+            Self::Popularity => "popularity",
+            Self::Priority => "priority",
+            Self::Title => "title",
+            Self::Updated => "updated_at",
+        };
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitlab");
+        let server = TestServer::new().await;
+        let service = Gitlab::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid TimeDeltaOrStatic values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+            "<1d",
+            "<=1w",
+            ">=1m",
+            ">1y",
+            "2020..2021",
+            "2020..=2021",
+            "..2021",
+            "..=2021",
+            "2021..",
+            "..",
+        ];
+
+        // ids
+        stream!(service.search().id([1]));
+        stream!(service.search().id([1, 2]));
+
+        // time related combinators
+        for time in &times {
+            // created
+            stream!(service.search().created(time.parse().unwrap()));
+
+            // updated
+            stream!(service.search().updated(time.parse().unwrap()));
+        }
+        let err = stream_result!(service.search().created("=2020".parse().unwrap())).unwrap_err();
+        assert_err_re!(err, "unsupported time operator: =2020");
+
+        // order
+        for field in OrderField::iter() {
+            stream!(service.search().order(Order::Ascending(field)));
+            stream!(service.search().order(Order::Descending(field)));
+        }
+
+        // users
+        stream!(service.search().author("user"));
+        stream!(service.search().assignee(ExistsOrValues::Exists(true)));
+        stream!(service.search().assignee(ExistsOrValues::Exists(false)));
+        stream!(service.search().assignee("user".parse().unwrap()));
+
+        // labels
+        stream!(service.search().labels(ExistsOrValues::Exists(true)));
+        stream!(service.search().labels("a,b".parse().unwrap()));
+
+        // milestone
+        stream!(service.search().milestone(ExistsOrValues::Exists(false)));
+        stream!(service.search().milestone("v1".parse().unwrap()));
+        let err = stream_result!(service.search().milestone("v1,v2".parse().unwrap())).unwrap_err();
+        assert_err_re!(err, "multiple milestones specified");
+
+        // title
+        stream!(service.search().title(["test"]));
+        stream!(service.search().title(["test1", "test2"]));
+
+        // state
+        stream!(service.search().state("opened"));
+        stream!(service.search().state("closed"));
+        stream!(service.search().state("all"));
+        let err = stream_result!(service.search().state("invalid")).unwrap_err();
+        assert_err_re!(err, "invalid state: invalid");
+    }
+}
@@ -0,0 +1,226 @@
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+use strum::{Display, EnumString};
+
+use crate::Error;
+use crate::service::bugzilla::update::SetChange;
+use crate::service::gitlab::Gitlab;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Gitlab,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        let base = self.service.config().api_base();
+        let params = self.encode();
+        for id in &self.ids {
+            if let Some(params) = &params {
+                let url = base.join(&format!("issues/{id}"))?;
+                let request = self
+                    .service
+                    .client()
+                    .put(url)
+                    .json(params)
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+
+            if let Some(comment) = &self.params.comment {
+                let url = base.join(&format!("issues/{id}/notes"))?;
+                let request = self
+                    .service
+                    .client()
+                    .post(url)
+                    .json(&json!({"body": comment}))
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Gitlab;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Gitlab, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode issue attribute changes into the form required for the request.
+    fn encode(&self) -> Option<RequestParameters<'_>> {
+        let mut params = RequestParameters {
+            assignee_ids: self.params.assignees.as_deref(),
+            description: self.params.description.as_deref(),
+            milestone_id: self.params.milestone,
+            state_event: self.params.state,
+            title: self.params.title.as_deref(),
+            ..Default::default()
+        };
+
+        if let Some(values) = &self.params.labels {
+            let mut add = IndexSet::new();
+            let mut remove = IndexSet::new();
+            let mut set = IndexSet::new();
+            for change in values {
+                match change {
+                    SetChange::Add(value) => add.insert(value.as_str()),
+                    SetChange::Remove(value) => remove.insert(value.as_str()),
+                    SetChange::Set(value) => set.insert(value.as_str()),
+                };
+            }
+
+            // set values override the entire list, ignoring any prefixed values
+            if !set.is_empty() {
+                params.labels = Some(set.iter().join(","));
+            } else {
+                if !add.is_empty() {
+                    params.add_labels = Some(add.iter().join(","));
+                }
+                if !remove.is_empty() {
+                    params.remove_labels = Some(remove.iter().join(","));
+                }
+            }
+        }
+
+        if params == RequestParameters::default() {
+            None
+        } else {
+            Some(params)
+        }
+    }
+}
+
+/// Issue state transitions.
+#[derive(Display, EnumString, Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum StateEvent {
+    Close,
+    Reopen,
+}
+
+/// Issue update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignees: Option<Vec<u64>>,
+    pub comment: Option<String>,
+    pub description: Option<String>,
+    pub labels: Option<Vec<SetChange<String>>>,
+    pub milestone: Option<u64>,
+    pub state: Option<StateEvent>,
+    pub title: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignees: self.assignees.merge(other.assignees),
+            comment: self.comment.merge(other.comment),
+            description: self.description.merge(other.description),
+            labels: self.labels.merge(other.labels),
+            milestone: self.milestone.merge(other.milestone),
+            state: self.state.merge(other.state),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+/// Internal issue update request parameters.
+///
+/// See https://docs.gitlab.com/api/issues/#edit-an-issue for more information.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+struct RequestParameters<'a> {
+    add_labels: Option<String>,
+    assignee_ids: Option<&'a [u64]>,
+    description: Option<&'a str>,
+    labels: Option<String>,
+    milestone_id: Option<u64>,
+    remove_labels: Option<String>,
+    state_event: Option<StateEvent>,
+    title: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("gitlab");
+        let server = TestServer::new().await;
+        let service = Gitlab::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // valid
+        server.respond(200, path.join("get/single.json")).await;
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.labels = Some(vec!["+bug".parse().unwrap(), "-triage".parse().unwrap()]);
+        request.params.state = Some(StateEvent::Close);
+        request.send().await.unwrap();
+    }
+}
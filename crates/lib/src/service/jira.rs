@@ -0,0 +1,433 @@
+use std::fmt;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod comment;
+pub mod create;
+pub mod get;
+pub mod search;
+pub mod transition;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub user: Option<String>,
+    pub password: Option<String>,
+    pub token: Option<String>,
+}
+
+/// Supported REST API versions.
+///
+/// Version 3 is only provided by Jira Cloud and uses the Atlassian Document
+/// Format for rich text fields.
+#[derive(
+    Display,
+    EnumString,
+    DeserializeFromStr,
+    SerializeDisplay,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+pub enum ApiVersion {
+    #[default]
+    #[strum(serialize = "2")]
+    V2,
+    #[strum(serialize = "3")]
+    V3,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// REST API version to target.
+    #[serde(default)]
+    pub api_version: ApiVersion,
+
+    /// Maximum number of results that can be returned by a search request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of jira's max search results.
+fn default_max_search_results() -> usize {
+    50
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            api_version: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the REST API URL for a given path.
+    fn api_url(&self, path: &str) -> crate::Result<Url> {
+        let version = self.api_version;
+        Ok(self.base.join(&format!("rest/api/{version}/{path}"))?)
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Jira
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn api_version(mut self, value: ApiVersion) -> Self {
+        self.config.api_version = value;
+        self
+    }
+
+    pub fn user(mut self, value: &str) -> Self {
+        self.config.auth.user = Some(value.to_string());
+        self
+    }
+
+    pub fn password(mut self, value: &str) -> Self {
+        self.config.auth.password = Some(value.to_string());
+        self
+    }
+
+    pub fn token(mut self, value: &str) -> Self {
+        self.config.auth.token = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Jira> {
+        Ok(Jira(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Jira(Arc<Service>);
+
+impl PartialEq for Jira {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Jira {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Jira {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Jira, name)?
+            .into_jira()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/browse/{id}")
+    }
+
+    /// Encode text into the rich text format required by the targeted API version.
+    ///
+    /// API v3 requires Atlassian Document Format content, see
+    /// https://developer.atlassian.com/cloud/jira/platform/apis/document/structure/.
+    fn encode_text(&self, value: &str) -> serde_json::Value {
+        match self.config().api_version {
+            ApiVersion::V2 => json!(value),
+            ApiVersion::V3 => {
+                let paragraphs: Vec<_> = value
+                    .split("\n\n")
+                    .map(|x| x.trim())
+                    .filter(|x| !x.is_empty())
+                    .map(|x| {
+                        json!({
+                            "type": "paragraph",
+                            "content": [{"type": "text", "text": x}],
+                        })
+                    })
+                    .collect();
+                json!({"type": "doc", "version": 1, "content": paragraphs})
+            }
+        }
+    }
+
+    pub fn comment<I, S>(&self, ids: I) -> comment::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        comment::Request::new(self, ids)
+    }
+
+    pub fn create(&self) -> create::Request {
+        create::Request::new(self)
+    }
+
+    pub fn get<I, S>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn transition<I, S>(&self, ids: I) -> transition::Request
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        transition::Request::new(self, ids)
+    }
+}
+
+impl WebService for Jira {
+    const API_VERSION: &'static str = "2";
+    type Response = serde_json::Value;
+
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        let auth = &self.config().auth;
+        match (&auth.user, &auth.password, &auth.token) {
+            // Jira Cloud uses API tokens as basic auth passwords
+            (Some(user), _, Some(token)) => Ok(request.basic_auth(user, Some(token))),
+            // personal access tokens are supported by Jira Data Center
+            (None, _, Some(token)) => Ok(request.bearer_auth(token)),
+            (Some(user), Some(pass), None) => Ok(request.basic_auth(user, Some(pass))),
+            _ if !required => Ok(request),
+            _ => Err(Error::Auth),
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        match response.error_for_status_ref() {
+            Ok(_) if response.status() == StatusCode::NO_CONTENT => Ok(serde_json::Value::Null),
+            Ok(_) => {
+                let data: serde_json::Value = response.json().await?;
+                debug!(
+                    "response data:\n{}",
+                    serde_json::to_string_pretty(&data).unwrap()
+                );
+                Ok(data)
+            }
+            Err(e) => {
+                if let Ok(data) = response.json::<ErrorResponse>().await {
+                    debug!("error: {data:?}");
+                    let errors: Vec<_> = data
+                        .error_messages
+                        .into_iter()
+                        .chain(data.errors.into_iter().map(|(k, v)| format!("{k}: {v}")))
+                        .collect();
+                    if !errors.is_empty() {
+                        return Err(Error::Jira(errors.join(", ")));
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+}
+
+/// Error response data.
+///
+/// See https://developer.atlassian.com/server/jira/platform/rest/v10000/intro/#status-codes.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct ErrorResponse {
+    error_messages: Vec<String>,
+    errors: IndexMap<String, String>,
+}
+
+impl WebClient for Jira {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssueField {
+    /// person the issue is assigned to
+    Assignee,
+    /// issue components
+    Components,
+    /// time when the issue was created
+    Created,
+    /// issue key
+    Id,
+    /// issue labels
+    Labels,
+    /// issue priority
+    Priority,
+    /// issue project
+    Project,
+    /// person who reported the issue
+    Reporter,
+    /// issue resolution
+    Resolution,
+    /// time when the issue was resolved
+    Resolved,
+    /// issue status
+    Status,
+    /// issue summary
+    Summary,
+    /// issue type
+    Type,
+    /// time when the issue was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+    use crate::traits::RequestSend;
+
+    use super::*;
+
+    #[test]
+    fn api_url() {
+        let config = Config::new("https://issues.apache.org/jira").unwrap();
+        assert_eq!(
+            config.api_url("issue/A-1").unwrap().as_str(),
+            "https://issues.apache.org/jira/rest/api/2/issue/A-1"
+        );
+    }
+
+    #[test]
+    fn encode_text() {
+        let service = Jira::new("https://jira.test").unwrap();
+        assert_eq!(service.encode_text("text"), json!("text"));
+
+        let service = Jira::builder("https://jira.test")
+            .unwrap()
+            .api_version(ApiVersion::V3)
+            .build()
+            .unwrap();
+        let value = service.encode_text("a\n\nb");
+        assert_eq!(value["type"], "doc");
+        assert_eq!(value["content"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::new(server.uri()).unwrap();
+
+        server
+            .respond(404, path.join("errors/nonexistent.json"))
+            .await;
+        let err = service.get(["TEST-1"]).send().await.unwrap_err();
+        assert_matches!(err, Error::Jira(_));
+        assert_err_re!(err, "Issue Does Not Exist");
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use serde_json::json;
+
+use crate::Error;
+use crate::service::jira::Jira;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Jira,
+    pub ids: Vec<String>,
+    text: Option<String>,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Jira, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|x| x.to_string()).collect(),
+            text: None,
+        }
+    }
+
+    /// Set the comment text.
+    pub fn text<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.text = Some(value.into());
+        self
+    }
+}
+
+impl RequestSend for Request {
+    /// IDs of the created comments.
+    type Output = Vec<u64>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let text = self.text.as_deref().unwrap_or_default().trim();
+        if text.is_empty() {
+            return Err(Error::EmptyParams);
+        }
+
+        let body = json!({"body": self.service.encode_text(text)});
+        let mut comments = vec![];
+        for id in &self.ids {
+            let url = self
+                .service
+                .config()
+                .api_url(&format!("issue/{id}/comment"))?;
+            let request = self
+                .service
+                .client()
+                .post(url)
+                .json(&body)
+                .auth(&self.service)?;
+            let response = request.send().await?;
+            let mut data = self.service.parse_response(response).await?;
+            let comment = data["id"]
+                .take()
+                .as_str()
+                .and_then(|x| x.parse().ok())
+                .ok_or_else(|| Error::InvalidResponse("invalid comment ID".to_string()))?;
+            comments.push(comment);
+        }
+
+        Ok(comments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<String>::new();
+        let err = service.comment(ids).text("text").send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty comment
+        let err = service.comment(["TEST-1"]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // valid
+        server.respond(201, path.join("comment/valid.json")).await;
+        let ids = service
+            .comment(["TEST-1"])
+            .text("comment")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(ids, [20002]);
+    }
+}
@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::service::jira::{ApiVersion, Jira};
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Jira,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = String;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        let url = self.service.config().api_url("issue")?;
+        let params = self.encode()?;
+        let request = self
+            .service
+            .client()
+            .post(url)
+            .json(&params)
+            .auth(&self.service)?;
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        serde_json::from_value(data["key"].take())
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing key: {e}")))
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Jira;
+    const TYPE: &'static str = "create";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Jira) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode parameters into the form required for the request.
+    ///
+    /// See https://developer.atlassian.com/server/jira/platform/rest/v10000/api-group-issue/#api-api-2-issue-post
+    /// for more information.
+    fn encode(&self) -> crate::Result<serde_json::Value> {
+        // verify required fields
+        let mut missing = vec![];
+        if self.params.project.is_none() {
+            missing.push("project");
+        }
+        if self.params.issue_type.is_none() {
+            missing.push("type");
+        }
+        if self.params.summary.is_none() {
+            missing.push("summary");
+        }
+        if !missing.is_empty() {
+            let fields = missing.join(", ");
+            return Err(Error::InvalidRequest(format!(
+                "missing required fields: {fields}"
+            )));
+        }
+
+        let mut fields = json!({
+            "project": {"key": self.params.project},
+            "issuetype": {"name": self.params.issue_type},
+            "summary": self.params.summary,
+        });
+
+        if let Some(value) = &self.params.assignee {
+            // Jira Cloud only supports identifying users by account ID
+            fields["assignee"] = match self.service.config().api_version {
+                ApiVersion::V2 => json!({"name": value}),
+                ApiVersion::V3 => json!({"accountId": value}),
+            };
+        }
+
+        if let Some(values) = &self.params.components {
+            let values: Vec<_> = values.iter().map(|x| json!({"name": x})).collect();
+            fields["components"] = json!(values);
+        }
+
+        if let Some(value) = &self.params.description {
+            fields["description"] = self.service.encode_text(value);
+        }
+
+        if let Some(values) = &self.params.labels {
+            fields["labels"] = json!(values);
+        }
+
+        if let Some(value) = &self.params.priority {
+            fields["priority"] = json!({"name": value});
+        }
+
+        Ok(json!({"fields": fields}))
+    }
+
+    pub fn assignee<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.assignee = Some(value.into());
+        self
+    }
+
+    pub fn components<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.components = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn description<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.description = Some(value.into());
+        self
+    }
+
+    pub fn issue_type<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.issue_type = Some(value.into());
+        self
+    }
+
+    pub fn labels<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.labels = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn priority<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.priority = Some(value.into());
+        self
+    }
+
+    pub fn project<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.project = Some(value.into());
+        self
+    }
+
+    pub fn summary<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.summary = Some(value.into());
+        self
+    }
+}
+
+/// Issue creation parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<String>,
+    pub components: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub issue_type: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub priority: Option<String>,
+    pub project: Option<String>,
+    pub summary: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            components: self.components.merge(other.components),
+            description: self.description.merge(other.description),
+            issue_type: self.issue_type.merge(other.issue_type),
+            labels: self.labels.merge(other.labels),
+            priority: self.priority.merge(other.priority),
+            project: self.project.merge(other.project),
+            summary: self.summary.merge(other.summary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // missing required fields
+        let err = service.create().send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "missing required fields: project, type, summary");
+
+        // valid
+        server.respond(200, path.join("create/valid.json")).await;
+        let id = service
+            .create()
+            .project("TEST")
+            .issue_type("Bug")
+            .summary("summary")
+            .description("description")
+            .labels(["test"])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(id, "TEST-1");
+    }
+}
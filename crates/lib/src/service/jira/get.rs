@@ -0,0 +1,162 @@
+use std::fmt;
+
+use futures_util::future::try_join_all;
+use reqwest::StatusCode;
+
+use crate::Error;
+use crate::objects::jira::{Comment, Issue};
+use crate::service::jira::Jira;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Jira,
+    pub ids: Vec<String>,
+    comments: bool,
+    changelog: bool,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Jira, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|x| x.to_string()).collect(),
+            comments: false,
+            changelog: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching the changelog.
+    pub fn changelog(&mut self, fetch: bool) -> &mut Self {
+        self.changelog = fetch;
+        self
+    }
+
+    async fn issue(&self, id: &str) -> crate::Result<Issue> {
+        let mut url = self.service.config().api_url(&format!("issue/{id}"))?;
+        // TODO: support fetching all comment pages for heavily commented issues
+        let fields = if self.comments {
+            "*all"
+        } else {
+            "*all,-comment"
+        };
+        url.query_pairs_mut().append_pair("fields", fields);
+        if self.changelog {
+            url.query_pairs_mut().append_pair("expand", "changelog");
+        }
+
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self
+            .service
+            .parse_response(response)
+            .await
+            .map_err(|e| match e {
+                Error::Request(e) if e.status() == Some(StatusCode::NOT_FOUND) => {
+                    Error::Jira(format!("nonexistent issue: {id}"))
+                }
+                _ => e,
+            })?;
+        let mut issue: Issue = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issue: {e}")))?;
+
+        if self.comments {
+            // treat description as a comment
+            if let (Some(text), Some(created)) = (issue.description.take(), issue.created) {
+                let comment = Comment {
+                    id: 0,
+                    count: 0,
+                    text: Some(text),
+                    author: issue.reporter.clone(),
+                    created,
+                };
+                issue.comments.insert(0, comment);
+            }
+        }
+
+        Ok(issue)
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Issue>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.issue(id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<String>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get(["TEST-1"]).send().await.unwrap_err();
+        assert_matches!(err, Error::Jira(_));
+        assert_err_re!(err, "nonexistent issue: TEST-1");
+
+        server.reset().await;
+
+        // single without comments or changelog
+        server.respond(200, path.join("get/single.json")).await;
+        let issues = service.get(["TEST-1"]).send().await.unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id.as_str()), ["TEST-1"]);
+        let issue = &issues[0];
+        assert!(issue.comments.is_empty());
+        assert_eq!(issue.status.as_deref(), Some("Open"));
+
+        server.reset().await;
+
+        // comments and changelog
+        server.respond(200, path.join("get/full.json")).await;
+        let issue = service
+            .get(["TEST-1"])
+            .comments(true)
+            .changelog(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        // description and a single user comment
+        assert_ordered_eq!(issue.comments.iter().map(|x| x.count), [0, 1]);
+        assert_eq!(issue.events.len(), 1);
+        assert_eq!(issue.events[0].changes.len(), 2);
+    }
+}
@@ -0,0 +1,661 @@
+use std::fmt;
+
+use chrono::Utc;
+use futures_util::Stream;
+use itertools::{Either, Itertools};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use strum::{Display, EnumIter, EnumString};
+use url::Url;
+
+use crate::Error;
+use crate::args::ExistsOrValues;
+use crate::objects::jira::Issue;
+use crate::objects::{Range, RangeOp, RangeOrValue};
+use crate::query::{Order, Query};
+use crate::service::jira::Jira;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{
+    Api, InjectAuth, Merge, RequestPagedStream, RequestTemplate, WebClient, WebService,
+};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Jira,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// Iterator of consecutive, paged requests.
+struct PagedIterator {
+    paged: usize,
+    request: Request,
+}
+
+impl Iterator for PagedIterator {
+    type Item = Request;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let req = self.request.clone();
+        self.request.params.offset = self
+            .request
+            .params
+            .offset
+            .unwrap_or_default()
+            .checked_add(self.paged);
+        req.params.offset.map(|_| req)
+    }
+}
+
+impl RequestPagedStream for Request {
+    type Item = Issue;
+
+    fn concurrent(&self) -> Option<usize> {
+        self.service.config().client.concurrent
+    }
+
+    fn paged(&mut self) -> Option<usize> {
+        if self.params.paged.unwrap_or_default() || self.params.limit.is_none() {
+            self.params
+                .limit
+                .get_or_insert_with(|| self.service.config().max_search_results);
+            self.params.offset.get_or_insert_with(Default::default);
+            self.params.limit
+        } else {
+            None
+        }
+    }
+
+    fn paged_requests(self, paged: Option<usize>) -> impl Iterator<Item = Self> {
+        if let Some(value) = paged {
+            Either::Left(PagedIterator {
+                paged: value,
+                request: self,
+            })
+        } else {
+            Either::Right([self].into_iter())
+        }
+    }
+
+    // TODO: support the token-based search/jql endpoint required by recent Jira Cloud releases
+    async fn send(self) -> crate::Result<Vec<Issue>> {
+        let mut url = self.service.config().api_url("search")?;
+        let query = self.encode();
+        url.query_pairs_mut().extend_pairs(query.iter());
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        let data = data["issues"].take();
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issues: {e}")))
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Jira) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Issue>> + 'static {
+        RequestPagedStream::paged_stream(self.clone())
+    }
+
+    /// Return the JQL query string for the request parameters.
+    pub fn to_jql(&self) -> String {
+        let mut jql = Jql::default();
+
+        // raw JQL is combined with all other restrictions
+        if let Some(value) = &self.params.jql {
+            jql.clauses.push(format!("({value})"));
+        }
+
+        if let Some(values) = &self.params.ids {
+            jql.any("key", values);
+        }
+
+        if let Some(values) = &self.params.project {
+            jql.any("project", values);
+        }
+
+        if let Some(values) = &self.params.issue_type {
+            jql.any("issuetype", values);
+        }
+
+        if let Some(values) = &self.params.status {
+            jql.any("status", values);
+        }
+
+        if let Some(values) = &self.params.priority {
+            jql.any("priority", values);
+        }
+
+        if let Some(value) = &self.params.resolution {
+            jql.exists_or_any("resolution", value);
+        }
+
+        if let Some(value) = &self.params.assignee {
+            jql.exists_or_any("assignee", value);
+        }
+
+        if let Some(values) = &self.params.reporter {
+            jql.any("reporter", values);
+        }
+
+        if let Some(value) = &self.params.components {
+            jql.exists_or_any("component", value);
+        }
+
+        if let Some(value) = &self.params.labels {
+            match value {
+                ExistsOrValues::Exists(value) => jql.exists("labels", *value),
+                ExistsOrValues::Values(values) => {
+                    // match all specified labels
+                    for value in values {
+                        jql.clauses.push(format!("labels = {}", quote(value)));
+                    }
+                }
+            }
+        }
+
+        if let Some(value) = &self.params.created {
+            jql.time("created", value);
+        }
+
+        if let Some(value) = &self.params.updated {
+            jql.time("updated", value);
+        }
+
+        if let Some(value) = &self.params.resolved {
+            jql.time("resolved", value);
+        }
+
+        if let Some(values) = &self.params.summary {
+            for value in values {
+                jql.clauses.push(format!("summary ~ {}", quote(value)));
+            }
+        }
+
+        let mut query = jql.clauses.iter().join(" AND ");
+
+        if let Some(values) = &self.params.order {
+            let order = values
+                .iter()
+                .map(|x| match x {
+                    Order::Ascending(field) => format!("{} ASC", field.api()),
+                    Order::Descending(field) => format!("{} DESC", field.api()),
+                })
+                .join(", ");
+            if !query.is_empty() {
+                query.push(' ');
+            }
+            query.push_str(&format!("ORDER BY {order}"));
+        }
+
+        query
+    }
+
+    fn encode(&self) -> Query {
+        let mut query = Query::default();
+        query.insert("jql", self.to_jql());
+
+        if let Some(value) = &self.params.limit {
+            query.insert("maxResults", value);
+        }
+
+        if let Some(value) = &self.params.offset {
+            query.insert("startAt", value);
+        }
+
+        query
+    }
+
+    /// Return the website URL for a query.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let base = self.service.base().as_str().trim_end_matches('/');
+        let mut url = Url::parse(&format!("{base}/issues/"))?;
+        url.query_pairs_mut().append_pair("jql", &self.to_jql());
+        Ok(url)
+    }
+
+    pub fn id<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        self.params.ids = Some(values.into_iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    pub fn assignee(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.assignee = Some(value);
+        self
+    }
+
+    pub fn components(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.components = Some(value);
+        self
+    }
+
+    pub fn issue_type<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.issue_type = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn jql<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.jql = Some(value.into());
+        self
+    }
+
+    pub fn labels(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.labels = Some(value);
+        self
+    }
+
+    pub fn order<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Order<OrderField>>,
+    {
+        self.params.order = Some(values.into_iter().collect());
+        self
+    }
+
+    pub fn priority<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.priority = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn project<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.project = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn reporter<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.reporter = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn resolution(&mut self, value: ExistsOrValues<String>) -> &mut Self {
+        self.params.resolution = Some(value);
+        self
+    }
+
+    pub fn status<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.status = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn created(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.created = Some(value);
+        self
+    }
+
+    pub fn updated(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+
+    pub fn resolved(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.resolved = Some(value);
+        self
+    }
+
+    pub fn summary<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.summary = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Jira;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Issue search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<ExistsOrValues<String>>,
+    pub components: Option<ExistsOrValues<String>>,
+    pub ids: Option<Vec<String>>,
+    pub issue_type: Option<Vec<String>>,
+    pub labels: Option<ExistsOrValues<String>>,
+    pub priority: Option<Vec<String>>,
+    pub project: Option<Vec<String>>,
+    pub reporter: Option<Vec<String>>,
+    pub resolution: Option<ExistsOrValues<String>>,
+    pub status: Option<Vec<String>>,
+
+    pub created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+    pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+    pub resolved: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub order: Option<Vec<Order<OrderField>>>,
+    pub paged: Option<bool>,
+
+    pub jql: Option<String>,
+    pub summary: Option<Vec<String>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            components: self.components.merge(other.components),
+            ids: self.ids.merge(other.ids),
+            issue_type: self.issue_type.merge(other.issue_type),
+            labels: self.labels.merge(other.labels),
+            priority: self.priority.merge(other.priority),
+            project: self.project.merge(other.project),
+            reporter: self.reporter.merge(other.reporter),
+            resolution: self.resolution.merge(other.resolution),
+            status: self.status.merge(other.status),
+            created: self.created.merge(other.created),
+            updated: self.updated.merge(other.updated),
+            resolved: self.resolved.merge(other.resolved),
+            limit: self.limit.merge(other.limit),
+            offset: self.offset.merge(other.offset),
+            order: self.order.merge(other.order),
+            paged: self.paged.merge(other.paged),
+            jql: self.jql.merge(other.jql),
+            summary: self.summary.merge(other.summary),
+        }
+    }
+}
+
+/// Quote a JQL string value.
+fn quote(value: &str) -> String {
+    let value = value.replace('\\', r"\\").replace('"', r#"\""#);
+    format!(r#""{value}""#)
+}
+
+/// Convert a time value into the format supported by JQL.
+///
+/// Absolute times are interpreted using the user's profile timezone so times are converted to
+/// minute offsets relative to the current time instead.
+fn jql_time(value: &TimeDeltaOrStatic) -> String {
+    let minutes = (value.datetime() - Utc::now()).num_minutes();
+    quote(&format!("{minutes}m"))
+}
+
+/// JQL query builder combining clauses using logical AND.
+#[derive(Default)]
+struct Jql {
+    clauses: Vec<String>,
+}
+
+impl Jql {
+    /// Match any of the given field values.
+    fn any<S: AsRef<str>>(&mut self, field: &str, values: &[S]) {
+        match values {
+            [] => (),
+            [value] => self
+                .clauses
+                .push(format!("{field} = {}", quote(value.as_ref()))),
+            values => {
+                let values = values.iter().map(|x| quote(x.as_ref())).join(", ");
+                self.clauses.push(format!("{field} in ({values})"));
+            }
+        }
+    }
+
+    /// Match conditionally existent field values.
+    fn exists(&mut self, field: &str, status: bool) {
+        if status {
+            self.clauses.push(format!("{field} is not EMPTY"));
+        } else {
+            self.clauses.push(format!("{field} is EMPTY"));
+        }
+    }
+
+    fn exists_or_any(&mut self, field: &str, value: &ExistsOrValues<String>) {
+        match value {
+            ExistsOrValues::Exists(value) => self.exists(field, *value),
+            ExistsOrValues::Values(values) => self.any(field, values),
+        }
+    }
+
+    fn time(&mut self, field: &str, value: &RangeOrValue<TimeDeltaOrStatic>) {
+        match value {
+            RangeOrValue::Value(value) => {
+                self.clauses.push(format!("{field} >= {}", jql_time(value)));
+            }
+            RangeOrValue::RangeOp(value) => self.range_op(field, value),
+            RangeOrValue::Range(value) => self.range(field, value),
+        }
+    }
+
+    fn range_op(&mut self, field: &str, value: &RangeOp<TimeDeltaOrStatic>) {
+        let (op, value) = match value {
+            RangeOp::Less(value) => ("<", value),
+            RangeOp::LessOrEqual(value) => ("<=", value),
+            RangeOp::Equal(value) => ("=", value),
+            RangeOp::NotEqual(value) => ("!=", value),
+            RangeOp::GreaterOrEqual(value) => (">=", value),
+            RangeOp::Greater(value) => (">", value),
+        };
+        self.clauses
+            .push(format!("{field} {op} {}", jql_time(value)));
+    }
+
+    fn range(&mut self, field: &str, value: &Range<TimeDeltaOrStatic>) {
+        let mut push = |op: &str, value: &TimeDeltaOrStatic| {
+            self.clauses
+                .push(format!("{field} {op} {}", jql_time(value)));
+        };
+
+        match value {
+            Range::Range(r) => {
+                push(">=", &r.start);
+                push("<", &r.end);
+            }
+            Range::Inclusive(r) => {
+                push(">=", r.start());
+                push("<=", r.end());
+            }
+            Range::To(r) => push("<", &r.end),
+            Range::ToInclusive(r) => push("<=", &r.end),
+            Range::From(r) => push(">=", &r.start),
+            Range::Full(_) => (),
+        }
+    }
+}
+
+/// Valid search order sorting terms.
+#[derive(Display, EnumIter, EnumString, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum OrderField {
+    /// time when the issue was created
+    Created,
+    /// issue key
+    Id,
+    /// issue priority
+    Priority,
+    /// issue project
+    Project,
+    /// time when the issue was resolved
+    Resolved,
+    /// issue status
+    Status,
+    /// issue summary
+    Summary,
+    /// time when the issue was last updated
+    Updated,
+}
+
+impl Api for OrderField {
+    fn api(&self) -> String {
+        let value = match self {
+            Self::Created => "created",
+            Self::Id => "key",
+            Self::Priority => "priority",
+            Self::Project => "project",
+            Self::Resolved => "resolved",
+            Self::Status => "status",
+            Self::Summary => "summary",
+            Self::Updated => "updated",
+        };
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[test]
+    fn jql() {
+        let service = Jira::new("https://jira.test").unwrap();
+
+        // empty
+        assert_eq!(service.search().to_jql(), "");
+
+        // raw JQL combined with typed parameters
+        let jql = service
+            .search()
+            .jql("text ~ crash")
+            .project(["TEST"])
+            .status(["Open", "In Progress"])
+            .assignee(ExistsOrValues::Exists(false))
+            .labels("a,b".parse().unwrap())
+            .summary([r#"quoted "term""#])
+            .order([Order::Descending(OrderField::Created)])
+            .to_jql();
+        assert_eq!(
+            jql,
+            r#"(text ~ crash) AND project = "TEST" AND status in ("Open", "In Progress") AND assignee is EMPTY AND labels = "a" AND labels = "b" AND summary ~ "quoted \"term\"" ORDER BY created DESC"#
+        );
+
+        // time ranges use offsets relative to the current time
+        let offset = |s: &str| {
+            let time: TimeDeltaOrStatic = s.parse().unwrap();
+            (time.datetime() - Utc::now()).num_minutes()
+        };
+        let (start, end) = (offset("2020"), offset("2021"));
+        let jql = service
+            .search()
+            .created("2020..2021".parse().unwrap())
+            .to_jql();
+        let re = regex::Regex::new(r#"^created >= "(-\d+)m" AND created < "(-\d+)m"$"#).unwrap();
+        let captures = re.captures(&jql).unwrap();
+        for (value, expected) in [(&captures[1], start), (&captures[2], end)] {
+            let value: i64 = value.parse().unwrap();
+            assert!((value - expected).abs() <= 1, "{value} != {expected}");
+        }
+        let jql = service.search().updated("1h".parse().unwrap()).to_jql();
+        assert!(jql == r#"updated >= "-60m""# || jql == r#"updated >= "-59m""#);
+    }
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid TimeDeltaOrStatic values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+            "<1d",
+            "<=1w",
+            "=1w",
+            "!=1w",
+            ">=1m",
+            ">1y",
+            "2020..2021",
+            "2020..=2021",
+            "..2021",
+            "..=2021",
+            "2021..",
+            "..",
+        ];
+
+        // ids
+        stream!(service.search().id(["TEST-1"]));
+        stream!(service.search().id(["TEST-1", "TEST-2"]));
+
+        // time related combinators
+        for time in &times {
+            stream!(service.search().created(time.parse().unwrap()));
+            stream!(service.search().updated(time.parse().unwrap()));
+            stream!(service.search().resolved(time.parse().unwrap()));
+        }
+
+        // order
+        for field in OrderField::iter() {
+            stream!(service.search().order([Order::Ascending(field)]));
+            stream!(service.search().order([Order::Descending(field)]));
+        }
+
+        // users
+        stream!(service.search().assignee(ExistsOrValues::Exists(true)));
+        stream!(service.search().assignee("user".parse().unwrap()));
+        stream!(service.search().reporter(["user1", "user2"]));
+
+        // existence
+        stream!(service.search().resolution(ExistsOrValues::Exists(false)));
+        stream!(service.search().components(ExistsOrValues::Exists(true)));
+        stream!(service.search().labels(ExistsOrValues::Exists(true)));
+
+        // raw JQL
+        stream!(service.search().jql("project = TEST"));
+
+        server.reset().await;
+
+        // matches
+        server.respond(200, path.join("search/issues.json")).await;
+        let issues = stream_result!(service.search().project(["TEST"])).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id.as_str()), ["TEST-1", "TEST-2"]);
+    }
+}
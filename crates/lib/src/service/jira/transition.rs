@@ -0,0 +1,243 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::service::jira::Jira;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Jira,
+    #[serde(skip)]
+    pub ids: Vec<String>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// Workflow transition available for an issue.
+#[derive(Deserialize, Debug)]
+struct Transition {
+    id: String,
+    name: String,
+    to: Status,
+}
+
+#[derive(Deserialize, Debug)]
+struct Status {
+    name: String,
+}
+
+impl Request {
+    pub(super) fn new<I, S>(service: &Jira, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: fmt::Display,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().map(|x| x.to_string()).collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Determine the workflow transition ID matching a transition or target status name.
+    async fn transition_id(&self, id: &str, name: &str) -> crate::Result<String> {
+        let url = self
+            .service
+            .config()
+            .api_url(&format!("issue/{id}/transitions"))?;
+        let request = self.service.client().get(url).auth(&self.service)?;
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        let transitions: Vec<Transition> = serde_json::from_value(data["transitions"].take())
+            .map_err(|e| {
+                Error::InvalidResponse(format!("failed deserializing transitions: {e}"))
+            })?;
+
+        transitions
+            .into_iter()
+            .find(|x| x.name.eq_ignore_ascii_case(name) || x.to.name.eq_ignore_ascii_case(name))
+            .map(|x| x.id)
+            .ok_or_else(|| Error::Jira(format!("invalid transition for {id}: {name}")))
+    }
+
+    pub fn status<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.status = Some(value.into());
+        self
+    }
+
+    pub fn comment<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.comment = Some(value.into());
+        self
+    }
+
+    pub fn resolution<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.resolution = Some(value.into());
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let Some(status) = self.params.status.as_deref() else {
+            return Err(Error::InvalidRequest(
+                "missing required fields: status".to_string(),
+            ));
+        };
+
+        for id in &self.ids {
+            let transition = self.transition_id(id, status).await?;
+            let mut params = json!({"transition": {"id": transition}});
+            if let Some(value) = &self.params.resolution {
+                params["fields"] = json!({"resolution": {"name": value}});
+            }
+            if let Some(value) = &self.params.comment {
+                let body = self.service.encode_text(value);
+                params["update"] = json!({"comment": [{"add": {"body": body}}]});
+            }
+
+            let url = self
+                .service
+                .config()
+                .api_url(&format!("issue/{id}/transitions"))?;
+            let request = self
+                .service
+                .client()
+                .post(url)
+                .json(&params)
+                .auth(&self.service)?;
+            let response = request.send().await?;
+            self.service.parse_response(response).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Jira;
+    const TYPE: &'static str = "transition";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Issue transition parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    /// Transition or target status name.
+    pub status: Option<String>,
+    pub comment: Option<String>,
+    pub resolution: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            status: self.status.merge(other.status),
+            comment: self.comment.merge(other.comment),
+            resolution: self.resolution.merge(other.resolution),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("jira");
+        let server = TestServer::new().await;
+        let service = Jira::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<String>::new();
+        let err = service.transition(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // missing status
+        let err = service.transition(["TEST-1"]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "missing required fields: status");
+
+        // authentication required
+        let anon = Jira::new(server.uri()).unwrap();
+        let err = anon
+            .transition(["TEST-1"])
+            .status("resolved")
+            .send()
+            .await
+            .unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        server
+            .respond_match(
+                matchers::method("GET"),
+                200,
+                path.join("transition/transitions.json"),
+            )
+            .await;
+        server
+            .respond_custom(matchers::method("POST"), ResponseTemplate::new(204))
+            .await;
+
+        // invalid transition
+        let err = service
+            .transition(["TEST-1"])
+            .status("invalid")
+            .send()
+            .await
+            .unwrap_err();
+        assert_matches!(err, Error::Jira(_));
+        assert_err_re!(err, "invalid transition for TEST-1: invalid");
+
+        // transition and target status names
+        for status in ["Resolve Issue", "resolved", "in progress"] {
+            service
+                .transition(["TEST-1"])
+                .status(status)
+                .comment("comment")
+                .send()
+                .await
+                .unwrap();
+        }
+    }
+}
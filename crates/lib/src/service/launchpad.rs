@@ -0,0 +1,472 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use chrono::Utc;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod comment;
+pub mod get;
+pub mod search;
+pub mod update;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub consumer_key: Option<String>,
+    pub token: Option<String>,
+    pub token_secret: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(skip)]
+    api_base: OnceLock<Url>,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Maximum number of results that can be returned by a search request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of launchpad's max search results.
+fn default_max_search_results() -> usize {
+    75
+}
+
+/// Return true if a URL points to a Launchpad host, otherwise false.
+fn is_launchpad(url: &Url) -> bool {
+    url.host_str()
+        .map(|x| x == "launchpad.net" || x.ends_with(".launchpad.net"))
+        .unwrap_or_default()
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            api_base: Default::default(),
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the bug target path, e.g. a project or distribution source package.
+    fn target(&self) -> &str {
+        self.base.path().trim_matches('/')
+    }
+
+    /// Return the API root URL for the service.
+    ///
+    /// Launchpad hosts map to their related API hosts, e.g.
+    /// https://launchpad.net/ubuntu maps to https://api.launchpad.net/devel/.
+    fn api_base(&self) -> &Url {
+        self.api_base.get_or_init(|| {
+            let mut url = self.base.clone();
+            if is_launchpad(&url) {
+                let host = url.host_str().unwrap_or_default();
+                let host = host.strip_prefix("bugs.").unwrap_or(host);
+                if !host.starts_with("api.") {
+                    url.set_host(Some(&format!("api.{host}"))).ok();
+                }
+            }
+            url.set_path("/devel/");
+            url
+        })
+    }
+
+    /// Return the API URL for the bug target, falling back to the global bug
+    /// collection if no target is configured.
+    fn target_url(&self) -> crate::Result<Url> {
+        match self.target() {
+            "" => Ok(self.api_base().join("bugs")?),
+            target => Ok(self.api_base().join(target)?),
+        }
+    }
+
+    /// Return the website URL for bug related pages.
+    fn web_base(&self) -> Url {
+        let mut url = self.base.clone();
+        if is_launchpad(&url) {
+            let host = url.host_str().unwrap_or_default();
+            if !host.starts_with("bugs.") {
+                url.set_host(Some(&format!("bugs.{host}"))).ok();
+            }
+        }
+        url.set_path("/");
+        url
+    }
+
+    /// Return the API URL for a link returned by the service.
+    ///
+    /// Links are remapped onto the configured API host so requests target the
+    /// same instance, e.g. when using staging or a local test server.
+    fn api_link(&self, link: &str) -> crate::Result<Url> {
+        let link = Url::parse(link)
+            .map_err(|e| Error::InvalidResponse(format!("invalid API link: {link}: {e}")))?;
+        let mut url = self.api_base().clone();
+        url.set_path(link.path());
+        Ok(url)
+    }
+
+    /// Return the API URL for a user.
+    fn person_url(&self, name: &str) -> crate::Result<Url> {
+        Ok(self.api_base().join(&format!("~{name}"))?)
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Launchpad
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn token(mut self, value: &str) -> Self {
+        self.config.auth.token = Some(value.to_string());
+        self
+    }
+
+    pub fn token_secret(mut self, value: &str) -> Self {
+        self.config.auth.token_secret = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Launchpad> {
+        Ok(Launchpad(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Launchpad(Arc<Service>);
+
+impl PartialEq for Launchpad {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Launchpad {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Launchpad {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Launchpad, name)?
+            .into_launchpad()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.config().web_base();
+        let base = base.as_str().trim_end_matches('/');
+        format!("{base}/bugs/{id}")
+    }
+
+    pub fn comment<I>(&self, ids: I) -> comment::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        comment::Request::new(self, ids)
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+}
+
+/// Percent-encode a value as required for OAuth parameters.
+fn oauth_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Return a unique nonce for OAuth requests.
+fn oauth_nonce() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    format!("{nanos}{count}")
+}
+
+impl WebService for Launchpad {
+    const API_VERSION: &'static str = "devel";
+    type Response = serde_json::Value;
+
+    // Launchpad uses OAuth 1.0 with PLAINTEXT signatures for API access, see
+    // https://help.launchpad.net/API/SigningRequests for more information.
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        let auth = &self.config().auth;
+        if let (Some(token), Some(secret)) = (&auth.token, &auth.token_secret) {
+            let consumer = auth.consumer_key.as_deref().unwrap_or("bugbite");
+            let params = [
+                ("oauth_consumer_key", oauth_encode(consumer)),
+                ("oauth_token", oauth_encode(token)),
+                ("oauth_signature_method", "PLAINTEXT".to_string()),
+                ("oauth_signature", format!("&{}", oauth_encode(secret))),
+                ("oauth_timestamp", Utc::now().timestamp().to_string()),
+                ("oauth_nonce", oauth_nonce()),
+                ("oauth_version", "1.0".to_string()),
+            ];
+            let mut header = r#"OAuth realm="https://api.launchpad.net/""#.to_string();
+            for (key, value) in params {
+                header.push_str(&format!(r#", {key}="{value}""#));
+            }
+            Ok(request.header("Authorization", header))
+        } else if !required {
+            Ok(request)
+        } else {
+            Err(Error::Auth)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        let status = response.error_for_status_ref().map(|_| ());
+        let text = response.text().await?;
+        match status {
+            // requests such as adding comments return no content
+            Ok(_) if text.trim().is_empty() => Ok(serde_json::Value::Null),
+            Ok(_) => {
+                let data: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+                    Error::InvalidResponse(format!("failed deserializing response: {e}"))
+                })?;
+                debug!(
+                    "response data:\n{}",
+                    serde_json::to_string_pretty(&data).unwrap()
+                );
+                Ok(data)
+            }
+            // errors are returned as plain text
+            Err(e) => {
+                debug!("error:\n{text}");
+                match text.trim() {
+                    "" => Err(e.into()),
+                    message => Err(Error::Launchpad(message.to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl WebClient for Launchpad {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum BugTaskField {
+    /// person the bug task is assigned to
+    Assignee,
+    /// time when the bug task was closed
+    Closed,
+    /// time when the bug task was created
+    Created,
+    /// bug ID
+    Id,
+    /// bug task importance
+    Importance,
+    /// person who reported the bug task
+    Reporter,
+    /// bug task status
+    Status,
+    /// bug task target
+    Target,
+    /// bug title
+    Title,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+    use crate::traits::RequestSend;
+
+    use super::*;
+
+    #[test]
+    fn urls() {
+        let config = Config::new("https://launchpad.net/ubuntu/+source/bash").unwrap();
+        assert_eq!(
+            config.target_url().unwrap().as_str(),
+            "https://api.launchpad.net/devel/ubuntu/+source/bash"
+        );
+        assert_eq!(config.web_base().as_str(), "https://bugs.launchpad.net/");
+
+        let config = Config::new("https://bugs.staging.launchpad.net").unwrap();
+        assert_eq!(
+            config.target_url().unwrap().as_str(),
+            "https://api.staging.launchpad.net/devel/bugs"
+        );
+        assert_eq!(
+            config
+                .api_link("https://api.launchpad.net/devel/bugbite/+bug/1")
+                .unwrap()
+                .as_str(),
+            "https://api.staging.launchpad.net/devel/bugbite/+bug/1"
+        );
+
+        let service = Launchpad::new("https://launchpad.net/bugbite").unwrap();
+        assert_eq!(service.item_url(1), "https://bugs.launchpad.net/bugs/1");
+    }
+
+    #[test]
+    fn oauth() {
+        assert_eq!(oauth_encode("a b&c~"), "a%20b%26c~");
+
+        let service = Launchpad::builder("https://launchpad.net/bugbite")
+            .unwrap()
+            .token("token")
+            .token_secret("secret")
+            .build()
+            .unwrap();
+        let request = service.client().get("https://api.launchpad.net/devel/");
+        let request = service.inject_auth(request, true).unwrap().build().unwrap();
+        let header = request.headers()["Authorization"].to_str().unwrap();
+        assert!(header.starts_with("OAuth realm="));
+        assert!(header.contains(r#"oauth_token="token""#));
+        assert!(header.contains(r#"oauth_signature="&secret""#));
+
+        // missing credentials
+        let service = Launchpad::new("https://launchpad.net/bugbite").unwrap();
+        let request = service.client().get("https://api.launchpad.net/devel/");
+        let err = service.inject_auth(request, true).unwrap_err();
+        assert_matches!(err, Error::Auth);
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let server = TestServer::new().await;
+        let service = Launchpad::new(server.uri()).unwrap();
+
+        // plain text error messages are used when available
+        let template =
+            ResponseTemplate::new(400).set_body_raw("tags: invalid tag name", "text/plain");
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Launchpad(_));
+        assert_err_re!(err, "tags: invalid tag name");
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Error;
+use crate::service::launchpad::Launchpad;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Launchpad,
+    pub ids: Vec<u64>,
+    text: Option<String>,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Launchpad, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            text: None,
+        }
+    }
+
+    /// Set the comment text.
+    pub fn text<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.text = Some(value.into());
+        self
+    }
+}
+
+/// Add a comment to a bug.
+pub(super) async fn add(service: &Launchpad, id: u64, text: &str) -> crate::Result<()> {
+    let url = service.config().api_base().join(&format!("bugs/{id}"))?;
+    let request = service
+        .client()
+        .post(url)
+        .form(&[("ws.op", "newMessage"), ("content", text)])
+        .auth(service)?;
+    let response = request.send().await?;
+    service.parse_response(response).await?;
+    Ok(())
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let text = self.text.as_deref().unwrap_or_default().trim();
+        if text.is_empty() {
+            return Err(Error::EmptyParams);
+        }
+
+        for id in &self.ids {
+            add(&self.service, *id, text).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let server = TestServer::new().await;
+        let service = Launchpad::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .token_secret("secret")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.comment(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty comment
+        let err = service.comment([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // authentication required
+        let unauthed = Launchpad::new(server.uri()).unwrap();
+        let err = unauthed.comment([1]).text("text").send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        // valid
+        let matcher = matchers::body_string_contains("ws.op=newMessage");
+        let template = ResponseTemplate::new(201);
+        server.respond_custom(matcher, template).await;
+        service.comment([1, 2]).text("text").send().await.unwrap();
+    }
+}
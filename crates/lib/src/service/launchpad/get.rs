@@ -0,0 +1,226 @@
+use chrono::prelude::*;
+use futures_util::future::try_join_all;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::Error;
+use crate::objects::launchpad::{Bug, BugTask, Change, Comment, Event, person};
+use crate::service::launchpad::Launchpad;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Launchpad,
+    pub ids: Vec<u64>,
+    comments: bool,
+    history: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Launchpad, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+            history: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching changes.
+    pub fn history(&mut self, fetch: bool) -> &mut Self {
+        self.history = fetch;
+        self
+    }
+
+    fn url(&self, id: u64, path: &str) -> crate::Result<Url> {
+        let base = self.service.config().api_base();
+        let mut url = base.join(&format!("bugs/{id}{path}"))?;
+        if !path.is_empty() {
+            // TODO: support fetching all pages for heavily updated bugs
+            let limit = self.service.config().max_search_results;
+            url.query_pairs_mut()
+                .append_pair("ws.size", &limit.to_string());
+        }
+        Ok(url)
+    }
+
+    /// Send a request for a given bug subpath, deserializing the response.
+    async fn fetch<T>(&self, id: u64, path: &str) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.url(id, path)?;
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(Error::Launchpad(format!("nonexistent bug: {id}")));
+        }
+        let data = self.service.parse_response(response).await?;
+        serde_json::from_value(data).map_err(|e| {
+            let name = path.trim_start_matches('/');
+            let name = if name.is_empty() { "bug" } else { name };
+            Error::InvalidResponse(format!("failed deserializing {name}: {e}"))
+        })
+    }
+
+    async fn bug(&self, id: u64) -> crate::Result<Bug> {
+        let mut bug: Bug = self.fetch(id, "").await?;
+        let tasks: Collection<BugTask> = self.fetch(id, "/bug_tasks").await?;
+        bug.tasks = tasks.entries;
+
+        if self.comments {
+            let messages: Collection<Comment> = self.fetch(id, "/messages").await?;
+            // the initial message contains the description
+            for (i, mut comment) in messages.entries.into_iter().enumerate() {
+                comment.count = i as u64;
+                bug.comments.push(comment);
+            }
+        }
+
+        if self.history {
+            let activity: Collection<Activity> = self.fetch(id, "/activity").await?;
+
+            // combine changes made by the same user at the same time
+            for (user, created, change) in
+                activity.entries.into_iter().filter_map(|x| x.into_change())
+            {
+                match bug.events.last_mut() {
+                    Some(event) if event.user == user && event.created == created => {
+                        event.changes.push(change);
+                    }
+                    _ => bug.events.push(Event {
+                        user,
+                        created,
+                        changes: vec![change],
+                    }),
+                }
+            }
+        }
+
+        Ok(bug)
+    }
+}
+
+/// Generic API collection response.
+#[derive(Deserialize)]
+pub(super) struct Collection<T> {
+    #[serde(default = "Vec::new")]
+    pub(super) entries: Vec<T>,
+}
+
+/// Bug activity entry.
+#[derive(Deserialize)]
+struct Activity {
+    datechanged: DateTime<Utc>,
+    #[serde(default, rename = "person_link", deserialize_with = "person")]
+    person: Option<String>,
+    whatchanged: String,
+    oldvalue: Option<String>,
+    newvalue: Option<String>,
+}
+
+impl Activity {
+    fn into_change(self) -> Option<(String, DateTime<Utc>, Change)> {
+        // skip long text changes and entries without values, e.g. subscriptions
+        if self.whatchanged == "description" {
+            return None;
+        }
+        let removed = self.oldvalue.filter(|x| !x.is_empty());
+        let added = self.newvalue.filter(|x| !x.is_empty());
+        if removed.is_none() && added.is_none() {
+            return None;
+        }
+
+        let change = Change {
+            field: self.whatchanged,
+            removed,
+            added,
+        };
+        Some((self.person?, self.datechanged, change))
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Bug>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.bug(*id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("launchpad");
+        let server = TestServer::new().await;
+        let service = Launchpad::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Launchpad(_));
+        assert_err_re!(err, "nonexistent bug: 1");
+
+        server.reset().await;
+
+        // comments and history
+        for (name, file) in [
+            ("bug_tasks", "get/tasks.json"),
+            ("messages", "get/messages.json"),
+            ("activity", "get/activity.json"),
+        ] {
+            let matcher = matchers::path_regex(format!("/{name}$"));
+            server.respond_match(matcher, 200, path.join(file)).await;
+        }
+        server.respond(200, path.join("get/single.json")).await;
+        let bug = service
+            .get([1])
+            .comments(true)
+            .history(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(bug.id, 1);
+        assert_eq!(bug.tasks.len(), 2);
+        // description and a single user comment
+        assert_ordered_eq!(bug.comments.iter().map(|x| x.count), [0, 1]);
+        // description changes are skipped and simultaneous changes are combined
+        assert_eq!(bug.events.len(), 1);
+        assert_eq!(bug.events[0].changes.len(), 2);
+    }
+}
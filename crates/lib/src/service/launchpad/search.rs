@@ -0,0 +1,405 @@
+use std::ops::{Deref, DerefMut};
+
+use futures_util::Stream;
+use itertools::{Either, Itertools};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::launchpad::BugTask;
+use crate::query::Query;
+use crate::service::launchpad::Launchpad;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{Api, InjectAuth, Merge, RequestPagedStream, RequestTemplate, WebService};
+
+use super::get::Collection;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Launchpad,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+/// Iterator of consecutive, paged requests.
+struct PagedIterator {
+    paged: usize,
+    request: Request,
+}
+
+impl Iterator for PagedIterator {
+    type Item = Request;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let req = self.request.clone();
+        self.request.params.offset = self
+            .request
+            .params
+            .offset
+            .unwrap_or_default()
+            .checked_add(self.paged);
+        req.params.offset.map(|_| req)
+    }
+}
+
+impl RequestPagedStream for Request {
+    type Item = BugTask;
+
+    fn concurrent(&self) -> Option<usize> {
+        self.service.config().client.concurrent
+    }
+
+    fn paged(&mut self) -> Option<usize> {
+        if self.params.paged.unwrap_or_default() || self.params.limit.is_none() {
+            self.params
+                .limit
+                .get_or_insert_with(|| self.service.config().max_search_results);
+            self.params.offset.get_or_insert_with(Default::default);
+            self.params.limit
+        } else {
+            None
+        }
+    }
+
+    fn paged_requests(self, paged: Option<usize>) -> impl Iterator<Item = Self> {
+        if let Some(value) = paged {
+            Either::Left(PagedIterator {
+                paged: value,
+                request: self,
+            })
+        } else {
+            Either::Right([self].into_iter())
+        }
+    }
+
+    async fn send(self) -> crate::Result<Vec<BugTask>> {
+        let mut url = self.service.config().target_url()?;
+        let query = self.encode()?;
+        url.query_pairs_mut().extend_pairs(query.iter());
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let data = self.service.parse_response(response).await?;
+        let tasks: Collection<BugTask> = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing bug tasks: {e}")))?;
+        Ok(tasks.entries)
+    }
+}
+
+impl Request {
+    pub(super) fn new(service: &Launchpad) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<BugTask>> + 'static {
+        RequestPagedStream::paged_stream(self.clone())
+    }
+
+    fn encode(&self) -> crate::Result<QueryBuilder<'_>> {
+        let mut query = QueryBuilder::new(&self.service);
+        query.insert("ws.op", "searchTasks");
+
+        if let Some(value) = &self.params.assignee {
+            query.person("assignee", value)?;
+        }
+
+        if let Some(value) = &self.params.reporter {
+            query.person("bug_reporter", value)?;
+        }
+
+        if let Some(values) = &self.params.importance {
+            for value in values {
+                query.append("importance", value);
+            }
+        }
+
+        if let Some(values) = &self.params.status {
+            for value in values {
+                query.append("status", value);
+            }
+        }
+
+        if let Some(values) = &self.params.tags {
+            for value in values {
+                query.append("tags", value);
+            }
+            // match all tags instead of any
+            query.insert("tags_combinator", "All");
+        }
+
+        if let Some(value) = &self.params.created {
+            query.insert("created_since", value.api());
+        }
+
+        if let Some(value) = &self.params.updated {
+            query.insert("modified_since", value.api());
+        }
+
+        if let Some(values) = &self.params.terms {
+            query.insert("search_text", values.iter().join(" "));
+        }
+
+        if let Some(value) = &self.params.limit {
+            query.insert("ws.size", value);
+        }
+
+        if let Some(value) = &self.params.offset {
+            query.insert("ws.start", value);
+        }
+
+        Ok(query)
+    }
+
+    /// Return the website URL for a query.
+    ///
+    /// Only the status, importance, tags, and terms are used since the website
+    /// doesn't support the other attributes.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let config = self.service.config();
+        let base = config.web_base();
+        let base = base.as_str().trim_end_matches('/');
+        let target = config.target();
+        let mut url = if target.is_empty() {
+            Url::parse(&format!("{base}/bugs/+bugs"))?
+        } else {
+            Url::parse(&format!("{base}/{target}/+bugs"))?
+        };
+
+        {
+            let mut query = url.query_pairs_mut();
+            for value in self.params.status.iter().flatten() {
+                query.append_pair("field.status:list", value);
+            }
+            for value in self.params.importance.iter().flatten() {
+                query.append_pair("field.importance:list", value);
+            }
+            if let Some(values) = &self.params.tags {
+                query.append_pair("field.tag", &values.iter().join(" "));
+                query.append_pair("field.tags_combinator", "ALL");
+            }
+            if let Some(values) = &self.params.terms {
+                query.append_pair("field.searchtext", &values.iter().join(" "));
+            }
+        }
+
+        Ok(url)
+    }
+
+    pub fn assignee<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.assignee = Some(value.into());
+        self
+    }
+
+    pub fn created(&mut self, value: TimeDeltaOrStatic) -> &mut Self {
+        self.params.created = Some(value);
+        self
+    }
+
+    pub fn importance<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.importance = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn reporter<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.reporter = Some(value.into());
+        self
+    }
+
+    pub fn status<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.status = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn tags<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.tags = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn terms<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.terms = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn updated(&mut self, value: TimeDeltaOrStatic) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Launchpad;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Bug task search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<String>,
+    pub importance: Option<Vec<String>>,
+    pub reporter: Option<String>,
+    pub status: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+
+    pub created: Option<TimeDeltaOrStatic>,
+    pub updated: Option<TimeDeltaOrStatic>,
+
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub paged: Option<bool>,
+
+    pub terms: Option<Vec<String>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            importance: self.importance.merge(other.importance),
+            reporter: self.reporter.merge(other.reporter),
+            status: self.status.merge(other.status),
+            tags: self.tags.merge(other.tags),
+            created: self.created.merge(other.created),
+            updated: self.updated.merge(other.updated),
+            limit: self.limit.merge(other.limit),
+            offset: self.offset.merge(other.offset),
+            paged: self.paged.merge(other.paged),
+            terms: self.terms.merge(other.terms),
+        }
+    }
+}
+
+struct QueryBuilder<'a> {
+    service: &'a Launchpad,
+    query: Query,
+}
+
+impl Deref for QueryBuilder<'_> {
+    type Target = Query;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl DerefMut for QueryBuilder<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+impl<'a> QueryBuilder<'a> {
+    fn new(service: &'a Launchpad) -> Self {
+        Self {
+            service,
+            query: Default::default(),
+        }
+    }
+
+    /// Users are matched using their related API link.
+    fn person(&mut self, key: &str, name: &str) -> crate::Result<()> {
+        let url = self.service.config().person_url(name)?;
+        self.insert(key, url.as_str());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("launchpad");
+        let server = TestServer::new().await;
+        let service = Launchpad::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid TimeDeltaOrStatic values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+        ];
+        for time in &times {
+            stream!(service.search().created(time.parse().unwrap()));
+            stream!(service.search().updated(time.parse().unwrap()));
+        }
+
+        // users
+        stream!(service.search().assignee("user"));
+        stream!(service.search().reporter("user"));
+
+        // multi-valued attributes
+        stream!(service.search().status(["New", "Confirmed"]));
+        stream!(service.search().importance(["High", "Critical"]));
+        stream!(service.search().tags(["bugbite", "regression"]));
+
+        // terms
+        stream!(service.search().terms(["test1", "test2"]));
+
+        // users are matched via their API links
+        server.reset().await;
+        let matcher = matchers::query_param("assignee", format!("{}/devel/~user", server.uri()));
+        server
+            .respond_match(matcher, 200, path.join("search/tasks.json"))
+            .await;
+        let tasks = stream_result!(service.search().assignee("user")).unwrap();
+        assert_ordered_eq!(tasks.iter().map(|x| x.id), [1, 2]);
+    }
+
+    #[test]
+    fn search_url() {
+        let service = Launchpad::new("https://launchpad.net/ubuntu/+source/bash").unwrap();
+        let mut request = service.search();
+        request.status(["New"]).terms(["crash"]);
+        let url = request.search_url().unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://bugs.launchpad.net/ubuntu/+source/bash/+bugs?field.status%3Alist=New&field.searchtext=crash"
+        );
+    }
+}
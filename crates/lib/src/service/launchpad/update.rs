@@ -0,0 +1,332 @@
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::launchpad::{Bug, BugTask};
+use crate::service::bugzilla::update::SetChange;
+use crate::service::launchpad::Launchpad;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+use super::get::Collection;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Launchpad,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        for id in &self.ids {
+            if let Some(params) = self.bug_params(*id).await? {
+                let url = self
+                    .service
+                    .config()
+                    .api_base()
+                    .join(&format!("bugs/{id}"))?;
+                self.patch(url.as_str(), &params).await?;
+            }
+
+            if let Some(params) = self.task_params()? {
+                let task = self.task(*id).await?;
+                let link = task.self_link.as_deref().ok_or_else(|| {
+                    Error::InvalidResponse(format!("bug task missing link: {id}"))
+                })?;
+                let url = self.service.config().api_link(link)?;
+                self.patch(url.as_str(), &params).await?;
+            }
+
+            if let Some(comment) = &self.params.comment {
+                super::comment::add(&self.service, *id, comment).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Launchpad;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Launchpad, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Send a partial update for a given API object.
+    async fn patch(&self, url: &str, params: &serde_json::Value) -> crate::Result<()> {
+        let request = self
+            .service
+            .client()
+            .patch(url)
+            .json(params)
+            .auth(&self.service)?;
+        let response = request.send().await?;
+        self.service.parse_response(response).await?;
+        Ok(())
+    }
+
+    /// Encode bug attribute changes into the form required for the request.
+    ///
+    /// Tag additions and removals require the current tags since the entire
+    /// list must be submitted.
+    async fn bug_params(&self, id: u64) -> crate::Result<Option<serde_json::Value>> {
+        let mut tags: Option<IndexSet<String>> = None;
+        if let Some(changes) = &self.params.tags {
+            // set values override the entire list, ignoring any prefixed values
+            let set: IndexSet<_> = changes
+                .iter()
+                .filter_map(|x| match x {
+                    SetChange::Set(value) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            if set.is_empty() {
+                let url = self
+                    .service
+                    .config()
+                    .api_base()
+                    .join(&format!("bugs/{id}"))?;
+                let request = self.service.client().get(url).auth(&self.service)?;
+                let response = request.send().await?;
+                let data = self.service.parse_response(response).await?;
+                let bug: Bug = serde_json::from_value(data).map_err(|e| {
+                    Error::InvalidResponse(format!("failed deserializing bug: {e}"))
+                })?;
+                let mut values = bug.tags;
+                for change in changes {
+                    match change {
+                        SetChange::Add(value) => values.insert(value.clone()),
+                        SetChange::Remove(value) => values.shift_remove(value),
+                        SetChange::Set(_) => unreachable!("set values already handled"),
+                    };
+                }
+                tags = Some(values);
+            } else {
+                tags = Some(set);
+            }
+        }
+
+        let params = RequestParameters {
+            description: self.params.description.as_deref(),
+            tags,
+            title: self.params.title.as_deref(),
+        };
+
+        if params == RequestParameters::default() {
+            Ok(None)
+        } else {
+            Ok(Some(json!(params)))
+        }
+    }
+
+    /// Encode bug task attribute changes into the form required for the request.
+    fn task_params(&self) -> crate::Result<Option<serde_json::Value>> {
+        let assignee = self
+            .params
+            .assignee
+            .as_deref()
+            .map(|x| self.service.config().person_url(x))
+            .transpose()?;
+        let params = TaskParameters {
+            assignee_link: assignee.as_ref().map(|x| x.as_str()),
+            importance: self.params.importance.as_deref(),
+            status: self.params.status.as_deref(),
+        };
+
+        if params == TaskParameters::default() {
+            Ok(None)
+        } else {
+            Ok(Some(json!(params)))
+        }
+    }
+
+    /// Return the bug task for the configured target.
+    ///
+    /// Bugs with a single task use it regardless of the target.
+    async fn task(&self, id: u64) -> crate::Result<BugTask> {
+        let config = self.service.config();
+        let url = config.api_base().join(&format!("bugs/{id}/bug_tasks"))?;
+        let request = self.service.client().get(url).auth(&self.service)?;
+        let response = request.send().await?;
+        let data = self.service.parse_response(response).await?;
+        let mut tasks: Collection<BugTask> = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing bug tasks: {e}")))?;
+
+        if tasks.entries.len() == 1 {
+            return Ok(tasks.entries.remove(0));
+        }
+
+        // match against URL paths since API hosts can vary, e.g. staging instances
+        let target = config.target_url()?;
+        let target = target.path().trim_end_matches('/');
+        tasks
+            .entries
+            .into_iter()
+            .find(|x| {
+                x.target_link
+                    .as_deref()
+                    .and_then(|x| Url::parse(x).ok())
+                    .is_some_and(|x| x.path().trim_end_matches('/') == target)
+            })
+            .ok_or_else(|| Error::Launchpad(format!("no matching bug task: {id}")))
+    }
+}
+
+/// Bug update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub assignee: Option<String>,
+    pub comment: Option<String>,
+    pub description: Option<String>,
+    pub importance: Option<String>,
+    pub status: Option<String>,
+    pub tags: Option<Vec<SetChange<String>>>,
+    pub title: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            assignee: self.assignee.merge(other.assignee),
+            comment: self.comment.merge(other.comment),
+            description: self.description.merge(other.description),
+            importance: self.importance.merge(other.importance),
+            status: self.status.merge(other.status),
+            tags: self.tags.merge(other.tags),
+            title: self.title.merge(other.title),
+        }
+    }
+}
+
+/// Internal bug update request parameters.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+struct RequestParameters<'a> {
+    description: Option<&'a str>,
+    tags: Option<IndexSet<String>>,
+    title: Option<&'a str>,
+}
+
+/// Internal bug task update request parameters.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+struct TaskParameters<'a> {
+    assignee_link: Option<&'a str>,
+    importance: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("launchpad");
+        let server = TestServer::new().await;
+        let service = Launchpad::builder(&format!("{}/bugbite", server.uri()))
+            .unwrap()
+            .token("token")
+            .token_secret("secret")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // authentication required
+        let unauthed = Launchpad::new(server.uri()).unwrap();
+        let mut request = unauthed.update([1]);
+        request.params.status = Some("Confirmed".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        // valid
+        let template = ResponseTemplate::new(209);
+        server
+            .respond_custom(matchers::method("PATCH"), template)
+            .await;
+        let template = ResponseTemplate::new(201);
+        server
+            .respond_custom(matchers::method("POST"), template)
+            .await;
+        server
+            .respond_match(
+                matchers::path_regex("/bug_tasks$"),
+                200,
+                path.join("get/tasks.json"),
+            )
+            .await;
+        server.respond(200, path.join("get/single.json")).await;
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.tags = Some(vec!["+new".parse().unwrap(), "-bugbite".parse().unwrap()]);
+        request.params.status = Some("Confirmed".to_string());
+        request.send().await.unwrap();
+
+        // bugs with multiple tasks require a matching target
+        let service = Launchpad::builder(&format!("{}/unknown", server.uri()))
+            .unwrap()
+            .token("token")
+            .token_secret("secret")
+            .build()
+            .unwrap();
+        let mut request = service.update([1]);
+        request.params.status = Some("Confirmed".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Launchpad(_));
+        assert_err_re!(err, "no matching bug task: 1");
+    }
+}
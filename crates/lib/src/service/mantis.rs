@@ -0,0 +1,319 @@
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod get;
+pub mod search;
+pub mod update;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub token: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Maximum number of results that can be returned by a search request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of mantis's max search results.
+fn default_max_search_results() -> usize {
+    50
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the base URL for REST API requests.
+    fn api_base(&self) -> crate::Result<Url> {
+        Ok(self.base.join("api/rest/")?)
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Mantis
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn token(mut self, value: &str) -> Self {
+        self.config.auth.token = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Mantis> {
+        Ok(Mantis(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Mantis(Arc<Service>);
+
+impl PartialEq for Mantis {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Mantis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Mantis {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Mantis, name)?
+            .into_mantis()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/view.php?id={id}")
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+}
+
+impl WebService for Mantis {
+    const API_VERSION: &'static str = "2.0";
+    type Response = serde_json::Value;
+
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        if let Some(token) = self.config().auth.token.as_ref() {
+            Ok(request.header("Authorization", token))
+        } else if !required {
+            Ok(request)
+        } else {
+            Err(Error::Auth)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        match response.error_for_status_ref() {
+            // requests that modify data can return no content on success
+            Ok(_) if response.status() == StatusCode::NO_CONTENT => Ok(serde_json::Value::Null),
+            Ok(_) => {
+                let data: serde_json::Value = response.json().await?;
+                debug!(
+                    "response data:\n{}",
+                    serde_json::to_string_pretty(&data).unwrap()
+                );
+                Ok(data)
+            }
+            Err(e) => {
+                if let Ok(data) = response.json::<serde_json::Value>().await {
+                    debug!("error:\n{}", serde_json::to_string_pretty(&data).unwrap());
+                    if let Some(message) = data["message"].as_str() {
+                        return Err(Error::Mantis(message.to_string()));
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+}
+
+impl WebClient for Mantis {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssueField {
+    /// issue category
+    Category,
+    /// time when the issue was created
+    Created,
+    /// person the issue is assigned to
+    Handler,
+    /// issue ID
+    Id,
+    /// issue priority
+    Priority,
+    /// issue project
+    Project,
+    /// person who reported the issue
+    Reporter,
+    /// issue resolution
+    Resolution,
+    /// issue severity
+    Severity,
+    /// issue status
+    Status,
+    /// issue summary
+    Summary,
+    /// time when the issue was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+    use crate::traits::RequestSend;
+
+    use super::*;
+
+    #[test]
+    fn urls() {
+        let service = Mantis::new("https://mantisbt.org/bugs").unwrap();
+        assert_eq!(
+            service.config().api_base().unwrap().as_str(),
+            "https://mantisbt.org/bugs/api/rest/"
+        );
+        assert_eq!(
+            service.item_url(1),
+            "https://mantisbt.org/bugs/view.php?id=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let path = TESTDATA_PATH.join("mantis");
+        let server = TestServer::new().await;
+        let service = Mantis::new(server.uri()).unwrap();
+
+        // error messages are extracted from responses
+        server.respond(403, path.join("errors/access.json")).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Mantis(_));
+        assert_err_re!(err, "Access denied");
+
+        // errors without messages fall back to status errors
+        server.reset().await;
+        let template = ResponseTemplate::new(500);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Request(_));
+    }
+}
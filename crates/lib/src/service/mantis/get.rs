@@ -0,0 +1,249 @@
+use chrono::prelude::*;
+use futures_util::future::try_join_all;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::Error;
+use crate::objects::mantis::{Change, Comment, Event, Issue, Person};
+use crate::service::mantis::Mantis;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Mantis,
+    pub ids: Vec<u64>,
+    comments: bool,
+    history: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Mantis, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+            history: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching changes.
+    pub fn history(&mut self, fetch: bool) -> &mut Self {
+        self.history = fetch;
+        self
+    }
+
+    async fn issue(&self, id: u64) -> crate::Result<Issue> {
+        let url = self
+            .service
+            .config()
+            .api_base()?
+            .join(&format!("issues/{id}"))?;
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(Error::Mantis(format!("nonexistent issue: {id}")));
+        }
+        let mut data = self.service.parse_response(response).await?;
+        let mut data = data["issues"][0].take();
+        let notes = data["notes"].take();
+        let history = data["history"].take();
+        let mut issue: Issue = serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issue: {e}")))?;
+
+        if self.comments {
+            // treat description as a comment
+            if let (Some(text), Some(user), Some(created)) =
+                (issue.description.take(), &issue.reporter, issue.created)
+            {
+                issue.comments.push(Comment {
+                    count: 0,
+                    text,
+                    user: user.clone(),
+                    created,
+                });
+            }
+
+            if !notes.is_null() {
+                let notes: Vec<Comment> = serde_json::from_value(notes).map_err(|e| {
+                    Error::InvalidResponse(format!("failed deserializing notes: {e}"))
+                })?;
+                for (i, mut comment) in notes.into_iter().enumerate() {
+                    comment.count = i as u64 + 1;
+                    issue.comments.push(comment);
+                }
+            }
+        }
+
+        if self.history && !history.is_null() {
+            let entries: Vec<HistoryEntry> = serde_json::from_value(history).map_err(|e| {
+                Error::InvalidResponse(format!("failed deserializing history: {e}"))
+            })?;
+            for entry in entries {
+                let Some((user, created, change)) = entry.into_change() else {
+                    continue;
+                };
+
+                // combine changes made by the same user at the same time
+                match issue.events.last_mut() {
+                    Some(event) if event.user == user && event.created == created => {
+                        event.changes.push(change);
+                    }
+                    _ => issue.events.push(Event {
+                        user,
+                        created,
+                        changes: vec![change],
+                    }),
+                }
+            }
+        }
+
+        Ok(issue)
+    }
+}
+
+/// Issue history entry.
+#[derive(Deserialize)]
+struct HistoryEntry {
+    created_at: DateTime<Utc>,
+    user: Person,
+    #[serde(rename = "type")]
+    kind: HistoryType,
+    field: Option<HistoryField>,
+    #[serde(default)]
+    old_value: serde_json::Value,
+    #[serde(default)]
+    new_value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct HistoryType {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct HistoryField {
+    name: String,
+}
+
+/// Convert a history value into its string representation.
+///
+/// Enumerated values such as statuses are objects while others are strings.
+fn history_value(value: serde_json::Value) -> Option<String> {
+    let value = match value {
+        serde_json::Value::Object(mut map) => match map.remove("name") {
+            Some(serde_json::Value::String(s)) => s,
+            _ => return None,
+        },
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    Some(value).filter(|x| !x.is_empty())
+}
+
+impl HistoryEntry {
+    /// Convert field update entries into changes, skipping other entry types.
+    fn into_change(self) -> Option<(String, DateTime<Utc>, Change)> {
+        if self.kind.name != "field-updated" {
+            return None;
+        }
+
+        // skip long text changes
+        let field = self.field?.name;
+        if field == "description" {
+            return None;
+        }
+
+        let removed = history_value(self.old_value);
+        let added = history_value(self.new_value);
+        if removed.is_none() && added.is_none() {
+            return None;
+        }
+
+        let change = Change {
+            field,
+            removed,
+            added,
+        };
+        Some((self.user.name, self.created_at, change))
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Issue>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.issue(*id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("mantis");
+        let server = TestServer::new().await;
+        let service = Mantis::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Mantis(_));
+        assert_err_re!(err, "nonexistent issue: 1");
+
+        server.reset().await;
+
+        // without comments or history
+        server.respond(200, path.join("get/single.json")).await;
+        let issue = service.get([1]).send().await.unwrap().remove(0);
+        assert_eq!(issue.id, 1);
+        assert_eq!(issue.status.as_deref(), Some("assigned"));
+        assert!(issue.description.is_some());
+        assert!(issue.comments.is_empty());
+        assert!(issue.events.is_empty());
+
+        // description and notes
+        let issue = service
+            .get([1])
+            .comments(true)
+            .history(true)
+            .send()
+            .await
+            .unwrap()
+            .remove(0);
+        assert_ordered_eq!(issue.comments.iter().map(|x| x.count), [0, 1, 2]);
+        // non-field updates and description changes are skipped while
+        // simultaneous changes are combined
+        assert_eq!(issue.events.len(), 1);
+        assert_eq!(issue.events[0].changes.len(), 2);
+    }
+}
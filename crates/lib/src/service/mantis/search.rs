@@ -0,0 +1,375 @@
+use std::ops::{Bound, RangeBounds};
+
+use async_stream::try_stream;
+use chrono::prelude::*;
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::mantis::Issue;
+use crate::objects::{Range, RangeOp, RangeOrValue};
+use crate::service::mantis::Mantis;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{InjectAuth, Merge, RequestTemplate, WebClient, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Mantis,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl Request {
+    pub(super) fn new(service: &Mantis) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    ///
+    /// The REST API only supports restricting issues by project and filter so
+    /// remaining parameters are applied to the returned issues. Issues are
+    /// returned in order of their last update, newest first, allowing paging
+    /// to stop early when restricting by update time.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Issue>> + 'static {
+        let request = self.clone();
+        try_stream! {
+            let filter = Filter::new(&request.params)?;
+            let size = request.service.config().max_search_results;
+            let mut count = 0;
+            let mut page = 1;
+
+            loop {
+                let issues = request.page(page, size).await?;
+                let last = issues.len() < size;
+
+                for issue in issues {
+                    if filter.expired(&issue) {
+                        return;
+                    }
+
+                    if filter.matches(&issue) {
+                        yield issue;
+                        count += 1;
+                        if request.params.limit.is_some_and(|x| count >= x) {
+                            return;
+                        }
+                    }
+                }
+
+                if last {
+                    break;
+                }
+                page += 1;
+            }
+        }
+    }
+
+    /// Fetch a page of issues.
+    async fn page(&self, page: usize, size: usize) -> crate::Result<Vec<Issue>> {
+        let mut url = self.service.config().api_base()?.join("issues")?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("page_size", &size.to_string());
+            query.append_pair("page", &page.to_string());
+            if let Some(value) = &self.params.project {
+                query.append_pair("project_id", &value.to_string());
+            }
+            if let Some(value) = &self.params.filter {
+                query.append_pair("filter_id", value);
+            }
+        }
+
+        let request = self.service.client().get(url).auth_optional(&self.service);
+        let response = request.send().await?;
+        let mut data = self.service.parse_response(response).await?;
+        let data = data["issues"].take();
+        serde_json::from_value(data)
+            .map_err(|e| Error::InvalidResponse(format!("failed deserializing issues: {e}")))
+    }
+
+    /// Return the website URL for a query.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let mut url = self.service.base().join("view_all_set.php")?;
+        url.query_pairs_mut()
+            .append_pair("type", "1")
+            .append_pair("temporary", "y");
+        if let Some(value) = &self.params.project {
+            url.query_pairs_mut()
+                .append_pair("project_id", &value.to_string());
+        }
+        Ok(url)
+    }
+
+    pub fn filter<S>(&mut self, value: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.params.filter = Some(value.into());
+        self
+    }
+
+    pub fn handler<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.handler = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.params.limit = Some(value);
+        self
+    }
+
+    pub fn project(&mut self, value: u64) -> &mut Self {
+        self.params.project = Some(value);
+        self
+    }
+
+    pub fn reporter<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.reporter = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn status<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.status = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn updated(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Mantis;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Issue search parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub filter: Option<String>,
+    pub project: Option<u64>,
+
+    pub handler: Option<Vec<String>>,
+    pub reporter: Option<Vec<String>>,
+    pub status: Option<Vec<String>>,
+
+    pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    pub limit: Option<usize>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            filter: self.filter.merge(other.filter),
+            project: self.project.merge(other.project),
+            handler: self.handler.merge(other.handler),
+            reporter: self.reporter.merge(other.reporter),
+            status: self.status.merge(other.status),
+            updated: self.updated.merge(other.updated),
+            limit: self.limit.merge(other.limit),
+        }
+    }
+}
+
+/// Absolute datetime bounds for a time value.
+type TimeBounds = (Bound<DateTime<Utc>>, Bound<DateTime<Utc>>);
+
+/// Issue filter for parameters unsupported by the REST API.
+struct Filter<'a> {
+    params: &'a Parameters,
+    updated: Option<TimeBounds>,
+}
+
+impl<'a> Filter<'a> {
+    fn new(params: &'a Parameters) -> crate::Result<Self> {
+        let updated = params.updated.as_ref().map(bounds).transpose()?;
+        Ok(Self { params, updated })
+    }
+
+    /// Return true if no further issues can match due to their update time.
+    fn expired(&self, issue: &Issue) -> bool {
+        match (&self.updated, &issue.updated) {
+            (Some((Bound::Included(start), _)), Some(updated)) => updated < start,
+            (Some((Bound::Excluded(start), _)), Some(updated)) => updated <= start,
+            _ => false,
+        }
+    }
+
+    fn matches(&self, issue: &Issue) -> bool {
+        let any = |values: &Option<Vec<String>>, value: Option<&str>| -> bool {
+            values
+                .as_ref()
+                .is_none_or(|x| value.is_some_and(|v| x.iter().any(|x| x == v)))
+        };
+
+        // limit to open issues by default
+        let status = issue.status.as_deref();
+        let status = match &self.params.status {
+            Some(_) => any(&self.params.status, status),
+            None => status != Some("closed"),
+        };
+
+        status
+            && any(
+                &self.params.handler,
+                issue.handler.as_ref().map(|x| x.name.as_str()),
+            )
+            && any(
+                &self.params.reporter,
+                issue.reporter.as_ref().map(|x| x.name.as_str()),
+            )
+            && self
+                .updated
+                .as_ref()
+                .is_none_or(|r| issue.updated.is_some_and(|x| r.contains(&x)))
+    }
+}
+
+/// Convert a time value into absolute datetime bounds.
+fn bounds(value: &RangeOrValue<TimeDeltaOrStatic>) -> crate::Result<TimeBounds> {
+    use Bound::*;
+    let bounds = match value {
+        RangeOrValue::Value(value) => (Included(value.datetime()), Unbounded),
+        RangeOrValue::RangeOp(op) => match op {
+            RangeOp::Less(value) => (Unbounded, Excluded(value.datetime())),
+            RangeOp::LessOrEqual(value) => (Unbounded, Included(value.datetime())),
+            RangeOp::GreaterOrEqual(value) => (Included(value.datetime()), Unbounded),
+            RangeOp::Greater(value) => (Excluded(value.datetime()), Unbounded),
+            RangeOp::Equal(_) | RangeOp::NotEqual(_) => {
+                return Err(Error::InvalidValue(format!(
+                    "unsupported time operator: {op}"
+                )));
+            }
+        },
+        RangeOrValue::Range(range) => match range {
+            Range::Range(r) => (Included(r.start.datetime()), Excluded(r.end.datetime())),
+            Range::Inclusive(r) => (Included(r.start().datetime()), Included(r.end().datetime())),
+            Range::To(r) => (Unbounded, Excluded(r.end.datetime())),
+            Range::ToInclusive(r) => (Unbounded, Included(r.end.datetime())),
+            Range::From(r) => (Included(r.start.datetime()), Unbounded),
+            Range::Full(_) => (Unbounded, Unbounded),
+        },
+    };
+    Ok(bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::matchers;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("mantis");
+        let server = TestServer::new().await;
+        let service = Mantis::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid RangeOrValue<TimeDeltaOrStatic> values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+            "<1d",
+            "<=1w",
+            ">1m",
+            ">=1y",
+            "2020..2021",
+            "2020..=2021",
+            "..2021",
+            "..=2021",
+            "2021..",
+            "..",
+        ];
+        for time in &times {
+            stream!(service.search().updated(time.parse().unwrap()));
+        }
+
+        // invalid time operators
+        for time in ["=1d", "!=1d"] {
+            let err = stream_result!(service.search().updated(time.parse().unwrap())).unwrap_err();
+            assert_matches!(err, Error::InvalidValue(_));
+        }
+
+        server.reset().await;
+
+        // issues are filtered locally, excluding closed issues by default
+        server
+            .respond_match(
+                matchers::query_param("page", "1"),
+                200,
+                path.join("search/issues.json"),
+            )
+            .await;
+        server.respond(200, path.join("search/valid")).await;
+        let issues = stream_result!(service.search()).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [3, 1]);
+        let issues = stream_result!(service.search().status(["closed"])).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [2]);
+        let issues = stream_result!(service.search().handler(["developer"])).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [1]);
+        let issues = stream_result!(service.search().reporter(["user"])).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [3]);
+        let issues = stream_result!(service.search().limit(1)).unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [3]);
+
+        // paging stops once issues are older than the requested update time
+        let issues = stream_result!(
+            service
+                .search()
+                .status(["new", "assigned", "closed"])
+                .updated("2024-02-16..".parse().unwrap())
+        )
+        .unwrap();
+        assert_ordered_eq!(issues.iter().map(|x| x.id), [3, 2]);
+    }
+
+    #[test]
+    fn search_url() {
+        let service = Mantis::new("https://mantisbt.org/bugs").unwrap();
+        let mut request = service.search();
+        request.project(1);
+        assert_eq!(
+            request.search_url().unwrap().as_str(),
+            "https://mantisbt.org/bugs/view_all_set.php?type=1&temporary=y&project_id=1"
+        );
+    }
+}
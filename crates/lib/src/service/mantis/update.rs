@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::service::mantis::Mantis;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Mantis,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        let base = self.service.config().api_base()?;
+        for id in &self.ids {
+            // update issue attributes
+            if let Some(data) = self.encode() {
+                let url = base.join(&format!("issues/{id}"))?;
+                let request = self
+                    .service
+                    .client()
+                    .patch(url)
+                    .json(&data)
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+
+            // add note
+            if let Some(value) = &self.params.comment {
+                let url = base.join(&format!("issues/{id}/notes"))?;
+                let view_state = if self.params.private.unwrap_or_default() {
+                    "private"
+                } else {
+                    "public"
+                };
+                let data = json!({"text": value, "view_state": {"name": view_state}});
+                let request = self
+                    .service
+                    .client()
+                    .post(url)
+                    .json(&data)
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Mantis;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Mantis, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode issue attribute changes into the form required for the request.
+    fn encode(&self) -> Option<serde_json::Value> {
+        let mut issue = serde_json::Map::new();
+        if let Some(value) = &self.params.description {
+            issue.insert("description".to_string(), json!(value));
+        }
+        if let Some(value) = &self.params.summary {
+            issue.insert("summary".to_string(), json!(value));
+        }
+
+        // enumerated and user fields are referenced by name
+        let named = [
+            ("category", &self.params.category),
+            ("handler", &self.params.handler),
+            ("priority", &self.params.priority),
+            ("resolution", &self.params.resolution),
+            ("severity", &self.params.severity),
+            ("status", &self.params.status),
+        ];
+        for (field, value) in named {
+            if let Some(value) = value {
+                issue.insert(field.to_string(), json!({"name": value}));
+            }
+        }
+
+        if issue.is_empty() {
+            None
+        } else {
+            Some(serde_json::Value::Object(issue))
+        }
+    }
+}
+
+/// Issue update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub category: Option<String>,
+    pub comment: Option<String>,
+    pub description: Option<String>,
+    pub handler: Option<String>,
+    pub priority: Option<String>,
+    pub private: Option<bool>,
+    pub resolution: Option<String>,
+    pub severity: Option<String>,
+    pub status: Option<String>,
+    pub summary: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            category: self.category.merge(other.category),
+            comment: self.comment.merge(other.comment),
+            description: self.description.merge(other.description),
+            handler: self.handler.merge(other.handler),
+            priority: self.priority.merge(other.priority),
+            private: self.private.merge(other.private),
+            resolution: self.resolution.merge(other.resolution),
+            severity: self.severity.merge(other.severity),
+            status: self.status.merge(other.status),
+            summary: self.summary.merge(other.summary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("mantis");
+        let server = TestServer::new().await;
+        let service = Mantis::builder(server.uri())
+            .unwrap()
+            .token("token")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // authentication required
+        let unauthed = Mantis::new(server.uri()).unwrap();
+        let mut request = unauthed.update([1]);
+        request.params.status = Some("confirmed".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        // attributes and notes
+        server
+            .respond_match(matchers::method("PATCH"), 200, path.join("get/single.json"))
+            .await;
+        let template = ResponseTemplate::new(201).set_body_string("{}");
+        server
+            .respond_custom(matchers::method("POST"), template)
+            .await;
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.status = Some("confirmed".to_string());
+        request.params.summary = Some("summary".to_string());
+        request.send().await.unwrap();
+
+        // notes only
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.private = Some(true);
+        request.send().await.unwrap();
+    }
+
+    #[test]
+    fn encode() {
+        let service = Mantis::new("https://mantisbt.org/bugs").unwrap();
+        let mut request = service.update([1]);
+        assert!(request.encode().is_none());
+
+        request.params.handler = Some("developer".to_string());
+        request.params.summary = Some("summary".to_string());
+        assert_eq!(
+            request.encode().unwrap(),
+            json!({"handler": {"name": "developer"}, "summary": "summary"})
+        );
+    }
+}
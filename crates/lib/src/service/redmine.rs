@@ -1,7 +1,7 @@
 use std::fmt;
 use std::sync::{Arc, OnceLock};
 
-use reqwest::RequestBuilder;
+use reqwest::{RequestBuilder, StatusCode};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use tracing::{debug, trace};
@@ -14,6 +14,8 @@ use super::{ClientParameters, ServiceKind};
 
 pub mod get;
 pub mod search;
+pub mod update;
+pub mod watchers;
 
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
 pub struct Authentication {
@@ -110,6 +112,11 @@ impl ServiceBuilder {
         self
     }
 
+    pub fn key(mut self, value: &str) -> Self {
+        self.config.auth.key = Some(value.to_string());
+        self
+    }
+
     /// Create a new service.
     pub fn build(self) -> crate::Result<Redmine> {
         Ok(Redmine(Arc::new(Service {
@@ -184,6 +191,20 @@ impl Redmine {
     pub fn search(&self) -> search::Request {
         search::Request::new(self)
     }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+
+    pub fn watchers<I>(&self, ids: I) -> watchers::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        watchers::Request::new(self, ids)
+    }
 }
 
 impl WebService for Redmine {
@@ -210,6 +231,8 @@ impl WebService for Redmine {
     async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
         trace!("{response:?}");
         match response.error_for_status_ref() {
+            // requests that modify data return no content on success
+            Ok(_) if response.status() == StatusCode::NO_CONTENT => Ok(serde_json::Value::Null),
             Ok(_) => {
                 let mut data: serde_json::Value = response.json().await?;
                 debug!(
@@ -65,6 +65,14 @@ impl Request {
         }
         self
     }
+
+    /// Enable or disable fetching watchers.
+    pub fn watchers(&mut self, fetch: bool) -> &mut Self {
+        if fetch {
+            self.fields.insert(Field::Watchers);
+        }
+        self
+    }
 }
 
 /// Bug fields composed of value arrays.
@@ -73,6 +81,7 @@ impl Request {
 enum Field {
     Attachments,
     Journals,
+    Watchers,
 }
 
 impl RequestSend for Request {
@@ -179,5 +188,21 @@ mod tests {
         let ids = [1];
         let bugs = service.get(ids).send().await.unwrap();
         assert_ordered_eq!(bugs.iter().map(|x| x.id), ids);
+
+        server.reset().await;
+
+        // watchers
+        server.respond(200, path.join("get/watchers.json")).await;
+        let issue = service
+            .get([1])
+            .watchers(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let watchers = issue.watchers.unwrap();
+        assert_ordered_eq!(watchers.iter().map(|x| x.id), [100, 101]);
     }
 }
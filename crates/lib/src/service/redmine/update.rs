@@ -0,0 +1,257 @@
+use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::service::bugzilla::update::SetChange;
+use crate::service::redmine::Redmine;
+use crate::traits::{InjectAuth, Merge, RequestSend, RequestTemplate, WebService};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Redmine,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        // update issue attributes
+        if let Some(data) = self.encode() {
+            for id in &self.ids {
+                let url = self
+                    .service
+                    .config()
+                    .web_base()
+                    .join(&format!("issues/{id}.json"))?;
+                let request = self
+                    .service
+                    .client()
+                    .put(url)
+                    .json(&data)
+                    .auth(&self.service)?;
+                let response = request.send().await?;
+                self.service.parse_response(response).await?;
+            }
+        }
+
+        // update issue watchers
+        if let Some(changes) = &self.params.watchers {
+            let mut add = IndexSet::new();
+            let mut remove = IndexSet::new();
+            let mut set = IndexSet::new();
+            for change in changes {
+                match change {
+                    SetChange::Add(value) => add.insert(*value),
+                    SetChange::Remove(value) => remove.insert(*value),
+                    SetChange::Set(value) => set.insert(*value),
+                };
+            }
+
+            if set.is_empty() {
+                self.service
+                    .watchers(self.ids.iter().copied())
+                    .add(add)
+                    .remove(remove)
+                    .send()
+                    .await?;
+            } else {
+                // replacing watchers requires the current values for each issue
+                let issues = self
+                    .service
+                    .get(self.ids.iter().copied())
+                    .watchers(true)
+                    .send()
+                    .await?;
+                for issue in issues {
+                    let existing: IndexSet<_> = issue
+                        .watchers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|x| x.id)
+                        .collect();
+                    let target: IndexSet<_> = set.union(&add).copied().collect();
+                    let target: IndexSet<_> = target.difference(&remove).copied().collect();
+                    let add: Vec<_> = target.difference(&existing).copied().collect();
+                    let remove: Vec<_> = existing.difference(&target).copied().collect();
+                    // skip issues already matching the requested watchers
+                    if !(add.is_empty() && remove.is_empty()) {
+                        self.service
+                            .watchers([issue.id])
+                            .add(add)
+                            .remove(remove)
+                            .send()
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Redmine;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Redmine, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode issue attribute changes into the form required for the request.
+    fn encode(&self) -> Option<serde_json::Value> {
+        let mut issue = serde_json::Map::new();
+        if let Some(value) = &self.params.comment {
+            issue.insert("notes".to_string(), json!(value));
+        }
+        if let Some(value) = &self.params.subject {
+            issue.insert("subject".to_string(), json!(value));
+        }
+
+        if issue.is_empty() {
+            None
+        } else {
+            Some(json!({"issue": issue}))
+        }
+    }
+}
+
+/// Issue update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub comment: Option<String>,
+    pub subject: Option<String>,
+    pub watchers: Option<Vec<SetChange<u64>>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            comment: self.comment.merge(other.comment),
+            subject: self.subject.merge(other.subject),
+            watchers: self.watchers.merge(other.watchers),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{Mock, ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("redmine");
+        let server = TestServer::new().await;
+        let service = Redmine::builder(server.uri())
+            .unwrap()
+            .key("key")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // add and remove watchers
+        Mock::given(matchers::method("PUT"))
+            .and(matchers::path("/issues/1.json"))
+            .and(matchers::body_json(
+                json!({"issue": {"subject": "subject"}}),
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(server.mock())
+            .await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/issues/1/watchers.json"))
+            .and(matchers::body_json(json!({"user_id": 100})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(server.mock())
+            .await;
+        Mock::given(matchers::method("DELETE"))
+            .and(matchers::path("/issues/1/watchers/101.json"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(server.mock())
+            .await;
+        let mut request = service.update([1]);
+        request.params.subject = Some("subject".to_string());
+        request.params.watchers = Some(vec!["+100".parse().unwrap(), "-101".parse().unwrap()]);
+        request.send().await.unwrap();
+        server.mock().verify().await;
+
+        server.reset().await;
+
+        // replace watchers
+        server
+            .respond_match(matchers::method("GET"), 200, path.join("get/watchers.json"))
+            .await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/issues/1/watchers.json"))
+            .and(matchers::body_json(json!({"user_id": 102})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(server.mock())
+            .await;
+        for user in [100, 101] {
+            Mock::given(matchers::method("DELETE"))
+                .and(matchers::path(format!("/issues/1/watchers/{user}.json")))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(server.mock())
+                .await;
+        }
+        let mut request = service.update([1]);
+        request.params.watchers = Some(vec!["102".parse().unwrap()]);
+        request.send().await.unwrap();
+        server.mock().verify().await;
+    }
+}
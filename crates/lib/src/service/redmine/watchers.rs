@@ -0,0 +1,184 @@
+use futures_util::future::try_join_all;
+use indexmap::IndexSet;
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::Error;
+use crate::service::redmine::Redmine;
+use crate::traits::{InjectAuth, RequestSend, WebService};
+
+#[derive(Debug)]
+pub struct Request {
+    service: Redmine,
+    pub ids: Vec<u64>,
+    add: IndexSet<u64>,
+    remove: IndexSet<u64>,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Redmine, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            add: Default::default(),
+            remove: Default::default(),
+        }
+    }
+
+    /// Add users as watchers.
+    pub fn add<I>(&mut self, users: I) -> &mut Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.add.extend(users);
+        self
+    }
+
+    /// Remove users as watchers, ignoring users that aren't watching.
+    pub fn remove<I>(&mut self, users: I) -> &mut Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.remove.extend(users);
+        self
+    }
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        if self.add.is_empty() && self.remove.is_empty() {
+            return Err(Error::EmptyParams);
+        }
+
+        let base = self.service.config().web_base();
+        let mut requests = vec![];
+        for id in &self.ids {
+            for user in &self.add {
+                let url = base.join(&format!("issues/{id}/watchers.json"))?;
+                let request = self
+                    .service
+                    .client()
+                    .post(url)
+                    .json(&json!({"user_id": user}))
+                    .auth(&self.service)?;
+                requests.push((request, false));
+            }
+
+            for user in &self.remove {
+                let url = base.join(&format!("issues/{id}/watchers/{user}.json"))?;
+                let request = self.service.client().delete(url).auth(&self.service)?;
+                requests.push((request, true));
+            }
+        }
+
+        let futures = requests.into_iter().map(|(request, remove)| async move {
+            let response = request.send().await?;
+            // ignore removing users that aren't watching
+            if remove && response.status() == StatusCode::NOT_FOUND {
+                return Ok(());
+            }
+            self.service.parse_response(response).await.map(|_| ())
+        });
+        try_join_all(futures).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{Mock, ResponseTemplate, matchers};
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let server = TestServer::new().await;
+        let service = Redmine::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.watchers(ids).add([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // no changes
+        let err = service.watchers([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // missing authentication
+        let err = service.watchers([1]).add([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        let service = Redmine::builder(server.uri())
+            .unwrap()
+            .key("key")
+            .build()
+            .unwrap();
+
+        // add and remove
+        for id in [1, 2] {
+            Mock::given(matchers::method("POST"))
+                .and(matchers::path(format!("/issues/{id}/watchers.json")))
+                .and(matchers::body_json(json!({"user_id": 100})))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(server.mock())
+                .await;
+            Mock::given(matchers::method("DELETE"))
+                .and(matchers::path(format!("/issues/{id}/watchers/101.json")))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(server.mock())
+                .await;
+        }
+        service
+            .watchers([1, 2])
+            .add([100])
+            .remove([101])
+            .send()
+            .await
+            .unwrap();
+        server.mock().verify().await;
+
+        server.reset().await;
+
+        // removing users that aren't watching is ignored
+        for (user, status) in [(101, 204), (102, 404)] {
+            Mock::given(matchers::method("DELETE"))
+                .and(matchers::path(format!("/issues/1/watchers/{user}.json")))
+                .respond_with(ResponseTemplate::new(status))
+                .expect(1)
+                .mount(server.mock())
+                .await;
+        }
+        service
+            .watchers([1])
+            .remove([101, 102])
+            .send()
+            .await
+            .unwrap();
+        server.mock().verify().await;
+
+        server.reset().await;
+
+        // adding watchers to nonexistent issues fails
+        let template = ResponseTemplate::new(404);
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.watchers([1]).add([100]).send().await.unwrap_err();
+        assert_matches!(err, Error::Request(_));
+    }
+}
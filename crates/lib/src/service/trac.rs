@@ -0,0 +1,385 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use strum::{Display, EnumString, VariantNames};
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::Error;
+use crate::traits::{InjectAuth, Merge, WebClient, WebService};
+
+use super::{ClientParameters, ServiceKind};
+
+pub mod get;
+pub mod search;
+pub mod update;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct Authentication {
+    pub user: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Config {
+    base: Url,
+    pub name: String,
+    #[serde(flatten)]
+    pub auth: Authentication,
+    #[serde(flatten)]
+    pub client: ClientParameters,
+
+    /// Maximum number of tickets that can be fetched by a single request.
+    #[serde(default = "default_max_search_results")]
+    pub max_search_results: usize,
+}
+
+// TODO: replace with default field value when stabilized
+// (https://github.com/rust-lang/rfcs/pull/3681)
+/// Return the default size of trac's max search results.
+fn default_max_search_results() -> usize {
+    100
+}
+
+impl Config {
+    pub fn new(base: &str) -> crate::Result<Self> {
+        let base = base.trim_end_matches('/');
+        let base = Url::parse(&format!("{base}/"))
+            .map_err(|e| Error::InvalidValue(format!("invalid URL: {base}: {e}")))?;
+
+        Ok(Self {
+            base,
+            name: Default::default(),
+            auth: Default::default(),
+            client: Default::default(),
+            max_search_results: default_max_search_results(),
+        })
+    }
+
+    /// Return the RPC endpoint URL.
+    ///
+    /// Authenticated requests use a separate endpoint that requires logging in.
+    fn rpc_url(&self) -> crate::Result<Url> {
+        if self.auth.user.is_some() && self.auth.password.is_some() {
+            Ok(self.base.join("login/rpc")?)
+        } else {
+            Ok(self.base.join("rpc")?)
+        }
+    }
+}
+
+impl WebClient for Config {
+    fn base(&self) -> &Url {
+        &self.base
+    }
+
+    fn kind(&self) -> ServiceKind {
+        ServiceKind::Trac
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug)]
+struct Service {
+    client: reqwest::Client,
+    config: Config,
+    _cache: ServiceCache,
+}
+
+#[derive(Debug)]
+pub struct ServiceBuilder {
+    config: Config,
+}
+
+impl ServiceBuilder {
+    pub fn client(mut self, value: ClientParameters) -> Self {
+        self.config.client.merge(value);
+        self
+    }
+
+    pub fn user(mut self, value: &str) -> Self {
+        self.config.auth.user = Some(value.to_string());
+        self
+    }
+
+    pub fn password(mut self, value: &str) -> Self {
+        self.config.auth.password = Some(value.to_string());
+        self
+    }
+
+    /// Create a new service.
+    pub fn build(self) -> crate::Result<Trac> {
+        Ok(Trac(Arc::new(Service {
+            client: self.config.client.build()?,
+            config: self.config,
+            _cache: Default::default(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Trac(Arc<Service>);
+
+impl PartialEq for Trac {
+    fn eq(&self, other: &Self) -> bool {
+        self.config() == other.config()
+    }
+}
+
+impl fmt::Display for Trac {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -- {}", self.kind(), self.base())
+    }
+}
+
+impl Trac {
+    /// Create a new Service using a given base URL.
+    pub fn new(base: &str) -> crate::Result<Self> {
+        Self::builder(base)?.build()
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn builder(base: &str) -> crate::Result<ServiceBuilder> {
+        Ok(ServiceBuilder {
+            config: Config::new(base)?,
+        })
+    }
+
+    /// Create a new Service builder using a given base URL.
+    pub fn config_builder(
+        config: &crate::config::Config,
+        name: Option<&str>,
+    ) -> crate::Result<ServiceBuilder> {
+        let config = config
+            .get_kind(ServiceKind::Trac, name)?
+            .into_trac()
+            .unwrap();
+        Ok(ServiceBuilder { config })
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.0.config
+    }
+
+    pub fn client(&self) -> &reqwest::Client {
+        &self.0.client
+    }
+
+    /// Return the website URL for an item ID.
+    pub fn item_url<I: fmt::Display>(&self, id: I) -> String {
+        let base = self.base().as_str().trim_end_matches('/');
+        format!("{base}/ticket/{id}")
+    }
+
+    /// Send a JSON-RPC method call, returning the decoded result value.
+    async fn call(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        auth: bool,
+    ) -> crate::Result<serde_json::Value> {
+        static ID: AtomicU64 = AtomicU64::new(1);
+        let id = ID.fetch_add(1, Ordering::Relaxed);
+        let url = self.config().rpc_url()?;
+        let request = self
+            .client()
+            .post(url)
+            .json(&json!({"method": method, "params": params, "id": id}));
+        let request = if auth {
+            request.auth(self)?
+        } else {
+            request.auth_optional(self)
+        };
+        let response = request.send().await?;
+        self.parse_response(response).await
+    }
+
+    pub fn get<I>(&self, ids: I) -> get::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        get::Request::new(self, ids)
+    }
+
+    pub fn search(&self) -> search::Request {
+        search::Request::new(self)
+    }
+
+    pub fn update<I>(&self, ids: I) -> update::Request
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        update::Request::new(self, ids)
+    }
+}
+
+/// JSON-RPC response.
+#[derive(Deserialize)]
+struct RpcResponse {
+    #[serde(default)]
+    result: serde_json::Value,
+    error: Option<RpcError>,
+}
+
+/// JSON-RPC error.
+#[derive(Deserialize)]
+struct RpcError {
+    message: String,
+}
+
+impl RpcResponse {
+    fn into_result(self) -> crate::Result<serde_json::Value> {
+        match self.error {
+            Some(error) => Err(Error::Trac(error.message)),
+            None => Ok(self.result),
+        }
+    }
+}
+
+impl WebService for Trac {
+    const API_VERSION: &'static str = "1.1";
+    type Response = serde_json::Value;
+
+    fn inject_auth(
+        &self,
+        request: RequestBuilder,
+        required: bool,
+    ) -> crate::Result<RequestBuilder> {
+        let auth = &self.config().auth;
+        if let (Some(user), Some(pass)) = (&auth.user, &auth.password) {
+            Ok(request.basic_auth(user, Some(pass)))
+        } else if !required {
+            Ok(request)
+        } else {
+            Err(Error::Auth)
+        }
+    }
+
+    async fn parse_response(&self, response: reqwest::Response) -> crate::Result<Self::Response> {
+        trace!("{response:?}");
+        // errors are returned with server error statuses so parse them first
+        let status = response.error_for_status_ref().map(|_| ());
+        let text = response.text().await?;
+        debug!("response data:\n{text}");
+        match (serde_json::from_str::<RpcResponse>(&text), status) {
+            (Ok(data), _) if data.error.is_some() => data.into_result(),
+            (_, Err(e)) => Err(e.into()),
+            (Ok(data), Ok(_)) => data.into_result(),
+            (Err(e), Ok(_)) => Err(Error::InvalidResponse(format!(
+                "failed deserializing response: {e}"
+            ))),
+        }
+    }
+}
+
+impl WebClient for Trac {
+    fn base(&self) -> &Url {
+        self.config().base()
+    }
+
+    fn kind(&self) -> ServiceKind {
+        self.config().kind()
+    }
+
+    fn name(&self) -> &str {
+        self.config().name()
+    }
+}
+
+#[derive(Display, EnumString, VariantNames, Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TicketField {
+    /// ticket component
+    Component,
+    /// time when the ticket was created
+    Created,
+    /// ticket ID
+    Id,
+    /// ticket keywords
+    Keywords,
+    /// ticket milestone
+    Milestone,
+    /// person who owns the ticket
+    Owner,
+    /// ticket priority
+    Priority,
+    /// person who reported the ticket
+    Reporter,
+    /// ticket resolution
+    Resolution,
+    /// ticket status
+    Status,
+    /// ticket summary
+    Summary,
+    /// ticket type
+    Type,
+    /// time when the ticket was last updated
+    Updated,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceCache {}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use wiremock::{ResponseTemplate, matchers};
+
+    use crate::test::*;
+    use crate::traits::RequestSend;
+
+    use super::*;
+
+    #[test]
+    fn urls() {
+        let config = Config::new("https://trac.example.org/project").unwrap();
+        assert_eq!(
+            config.rpc_url().unwrap().as_str(),
+            "https://trac.example.org/project/rpc"
+        );
+
+        let service = Trac::builder("https://trac.example.org/project")
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+        assert_eq!(
+            service.config().rpc_url().unwrap().as_str(),
+            "https://trac.example.org/project/login/rpc"
+        );
+        assert_eq!(
+            service.item_url(1),
+            "https://trac.example.org/project/ticket/1"
+        );
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let path = TESTDATA_PATH.join("trac");
+        let server = TestServer::new().await;
+        let service = Trac::new(server.uri()).unwrap();
+
+        // errors are returned with server error statuses
+        server
+            .respond(500, path.join("errors/nonexistent.json"))
+            .await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Trac(_));
+
+        // invalid responses
+        server.reset().await;
+        let template = ResponseTemplate::new(200).set_body_string("<html></html>");
+        server.respond_custom(matchers::any(), template).await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidResponse(_));
+    }
+}
@@ -0,0 +1,244 @@
+use chrono::prelude::*;
+use futures_util::future::try_join_all;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+use serde_json::json;
+
+use crate::Error;
+use crate::objects::trac::{Change, Comment, Event, Ticket, datetime};
+use crate::service::trac::Trac;
+use crate::traits::RequestSend;
+
+#[derive(Debug)]
+pub struct Request {
+    service: Trac,
+    pub ids: Vec<u64>,
+    comments: bool,
+    history: bool,
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Trac, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            comments: false,
+            history: false,
+        }
+    }
+
+    /// Enable or disable fetching comments.
+    pub fn comments(&mut self, fetch: bool) -> &mut Self {
+        self.comments = fetch;
+        self
+    }
+
+    /// Enable or disable fetching changes.
+    pub fn history(&mut self, fetch: bool) -> &mut Self {
+        self.history = fetch;
+        self
+    }
+
+    async fn ticket(&self, id: u64) -> crate::Result<Ticket> {
+        let mut ticket = ticket(&self.service, id, false).await?;
+
+        if !self.comments && !self.history {
+            return Ok(ticket);
+        }
+
+        if self.comments {
+            // treat description as a comment
+            if let Some(text) = ticket.description.take() {
+                ticket.comments.push(Comment {
+                    count: 0,
+                    text,
+                    author: ticket.reporter.clone().unwrap_or_default(),
+                    created: ticket.created.unwrap_or_default(),
+                });
+            }
+        }
+
+        let data = self
+            .service
+            .call("ticket.changeLog", json!([id]), false)
+            .await?;
+        let entries: Vec<ChangeLogEntry> = serde_json::from_value(data).map_err(|e| {
+            Error::InvalidResponse(format!("failed deserializing ticket changelog: {e}"))
+        })?;
+
+        for entry in entries {
+            match entry.into_update() {
+                Some(Update::Comment(comment)) if self.comments => ticket.comments.push(comment),
+                Some(Update::Change(user, created, change)) if self.history => {
+                    // combine changes made by the same user at the same time
+                    match ticket.events.last_mut() {
+                        Some(event) if event.user == user && event.created == created => {
+                            event.changes.push(change);
+                        }
+                        _ => ticket.events.push(Event {
+                            user,
+                            created,
+                            changes: vec![change],
+                        }),
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Ok(ticket)
+    }
+}
+
+/// Fetch a ticket, optionally requiring authentication.
+pub(super) async fn ticket(service: &Trac, id: u64, auth: bool) -> crate::Result<Ticket> {
+    let data = match service.call("ticket.get", json!([id]), auth).await {
+        Err(Error::Trac(message)) if message.contains("does not exist") => {
+            return Err(Error::Trac(format!("nonexistent ticket: {id}")));
+        }
+        result => result?,
+    };
+    serde_json::from_value(data)
+        .map_err(|e| Error::InvalidResponse(format!("failed deserializing ticket: {e}")))
+}
+
+/// Ticket changelog entry in the form: [time, author, field, old, new, permanent].
+#[derive(Deserialize)]
+struct ChangeLogEntry(
+    #[serde(deserialize_with = "datetime")] DateTime<Utc>,
+    String,
+    String,
+    String,
+    String,
+    IgnoredAny,
+);
+
+/// Ticket update derived from a changelog entry.
+enum Update {
+    Comment(Comment),
+    Change(String, DateTime<Utc>, Change),
+}
+
+impl ChangeLogEntry {
+    fn into_update(self) -> Option<Update> {
+        let Self(created, author, field, old, new, _) = self;
+        match field.as_str() {
+            // comment entries exist for all change sets, even without text
+            "comment" if new.trim().is_empty() => None,
+            // comment numbers use the form N or P.N for replies
+            "comment" => {
+                let count = old.rsplit('.').next()?.parse().ok()?;
+                Some(Update::Comment(Comment {
+                    count,
+                    text: new,
+                    author,
+                    created,
+                }))
+            }
+            // skip long text changes and internal fields, e.g. comment edits
+            "description" => None,
+            field if field.starts_with('_') => None,
+            _ => {
+                let removed = Some(old).filter(|x| !x.is_empty());
+                let added = Some(new).filter(|x| !x.is_empty());
+                if removed.is_none() && added.is_none() {
+                    return None;
+                }
+
+                let change = Change {
+                    field,
+                    removed,
+                    added,
+                };
+                Some(Update::Change(author, created, change))
+            }
+        }
+    }
+}
+
+impl RequestSend for Request {
+    type Output = Vec<Ticket>;
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        let futures = self.ids.iter().map(|id| self.ticket(*id));
+        try_join_all(futures).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("trac");
+        let server = TestServer::new().await;
+        let service = Trac::new(server.uri()).unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.get(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // nonexistent
+        server
+            .respond(500, path.join("errors/nonexistent.json"))
+            .await;
+        let err = service.get([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::Trac(_));
+        assert_err_re!(err, "nonexistent ticket: 1");
+
+        server.reset().await;
+
+        // comments and history
+        server
+            .respond_rpc("ticket.get", path.join("get/ticket.json"))
+            .await;
+        server
+            .respond_rpc("ticket.changeLog", path.join("get/changelog.json"))
+            .await;
+        let ticket = service
+            .get([1])
+            .comments(true)
+            .history(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(ticket.id, 1);
+        assert_eq!(ticket.component.as_deref(), Some("cli"));
+        // description, a comment, and a reply
+        assert_ordered_eq!(ticket.comments.iter().map(|x| x.count), [0, 1, 2]);
+        // description changes and comment edits are skipped while simultaneous
+        // changes are combined
+        assert_eq!(ticket.events.len(), 1);
+        assert_eq!(ticket.events[0].changes.len(), 2);
+
+        // only comments
+        let ticket = service
+            .get([1])
+            .comments(true)
+            .send()
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(ticket.comments.len(), 3);
+        assert!(ticket.events.is_empty());
+    }
+}
@@ -0,0 +1,520 @@
+use std::fmt;
+
+use async_stream::try_stream;
+use futures_util::Stream;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::Error;
+use crate::objects::trac::Ticket;
+use crate::objects::{Range, RangeOp, RangeOrValue};
+use crate::service::trac::Trac;
+use crate::time::TimeDeltaOrStatic;
+use crate::traits::{Api, Merge, RequestTemplate, WebClient};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Trac,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl Request {
+    pub(super) fn new(service: &Trac) -> Self {
+        Self {
+            service: service.clone(),
+            params: Default::default(),
+        }
+    }
+
+    /// Return the matching stream of items for a given request.
+    ///
+    /// Matching ticket IDs are pulled in a single request, newest first, with
+    /// their data being fetched in chunks.
+    pub fn stream(&self) -> impl Stream<Item = crate::Result<Ticket>> + 'static {
+        let request = self.clone();
+        try_stream! {
+            let query = request.encode()?;
+            let data = request.service.call("ticket.query", json!([query]), false).await?;
+            let ids: Vec<u64> = serde_json::from_value(data).map_err(|e| {
+                Error::InvalidResponse(format!("failed deserializing ticket IDs: {e}"))
+            })?;
+
+            let size = request.service.config().max_search_results;
+            for chunk in ids.chunks(size) {
+                for ticket in tickets(&request.service, chunk).await? {
+                    yield ticket;
+                }
+            }
+        }
+    }
+
+    /// Encode parameters into the query language format.
+    fn encode(&self) -> crate::Result<String> {
+        let mut query = QueryBuilder::default();
+        query.constraints(&self.params)?;
+
+        // limit to open tickets by default
+        if self.params.status.is_none() {
+            query.push("status", "!=", "closed");
+        }
+
+        match self.params.limit {
+            Some(value) => query.push("max", "=", value),
+            None => query.push("max", "=", 0usize),
+        }
+        query.push("order", "=", "id");
+        query.push("desc", "=", 1usize);
+
+        Ok(query.iter().join("&"))
+    }
+
+    /// Return the website URL for a query.
+    pub fn search_url(self) -> crate::Result<Url> {
+        let base = self.service.base().as_str().trim_end_matches('/');
+        let mut url = Url::parse(&format!("{base}/query"))?;
+        let mut query = QueryBuilder::default();
+        query.constraints(&self.params)?;
+        if self.params.status.is_none() {
+            query.push("status", "!=", "closed");
+        }
+
+        // the website uses value prefixes for operators
+        url.query_pairs_mut().extend_pairs(
+            query
+                .0
+                .iter()
+                .map(|(field, op, value)| (field, format!("{}{value}", web_op(op)))),
+        );
+        Ok(url)
+    }
+
+    pub fn component<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.component = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn created(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.created = Some(value);
+        self
+    }
+
+    pub fn keywords<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.keywords = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn limit(&mut self, value: usize) -> &mut Self {
+        self.params.limit = Some(value);
+        self
+    }
+
+    pub fn milestone<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.milestone = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn owner<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.owner = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn priority<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.priority = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn reporter<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.reporter = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn resolution<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.resolution = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn status<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.status = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn summary<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.summary = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn kind<I, S>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.kind = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn updated(&mut self, value: RangeOrValue<TimeDeltaOrStatic>) -> &mut Self {
+        self.params.updated = Some(value);
+        self
+    }
+}
+
+/// Fetch a set of tickets using a single multicall request.
+async fn tickets(service: &Trac, ids: &[u64]) -> crate::Result<Vec<Ticket>> {
+    let calls: Vec<_> = ids
+        .iter()
+        .map(|id| json!({"method": "ticket.get", "params": [id]}))
+        .collect();
+    let data = service
+        .call("system.multicall", json!([calls]), false)
+        .await?;
+    let responses: Vec<super::RpcResponse> = serde_json::from_value(data).map_err(|e| {
+        Error::InvalidResponse(format!("failed deserializing multicall response: {e}"))
+    })?;
+
+    responses
+        .into_iter()
+        .map(|x| {
+            let data = x.into_result()?;
+            serde_json::from_value(data)
+                .map_err(|e| Error::InvalidResponse(format!("failed deserializing ticket: {e}")))
+        })
+        .collect()
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Trac;
+    const TYPE: &'static str = "search";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+/// Ticket search parameters.
+///
+/// Multi-valued attributes match if any of their values match while values
+/// prefixed with `!` invert the match for the entire attribute.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub component: Option<Vec<String>>,
+    pub keywords: Option<Vec<String>>,
+    pub milestone: Option<Vec<String>>,
+    pub owner: Option<Vec<String>>,
+    pub priority: Option<Vec<String>>,
+    pub reporter: Option<Vec<String>>,
+    pub resolution: Option<Vec<String>>,
+    pub status: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    pub kind: Option<Vec<String>>,
+
+    pub created: Option<RangeOrValue<TimeDeltaOrStatic>>,
+    pub updated: Option<RangeOrValue<TimeDeltaOrStatic>>,
+
+    pub limit: Option<usize>,
+
+    pub summary: Option<Vec<String>>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            component: self.component.merge(other.component),
+            keywords: self.keywords.merge(other.keywords),
+            milestone: self.milestone.merge(other.milestone),
+            owner: self.owner.merge(other.owner),
+            priority: self.priority.merge(other.priority),
+            reporter: self.reporter.merge(other.reporter),
+            resolution: self.resolution.merge(other.resolution),
+            status: self.status.merge(other.status),
+            kind: self.kind.merge(other.kind),
+            created: self.created.merge(other.created),
+            updated: self.updated.merge(other.updated),
+            limit: self.limit.merge(other.limit),
+            summary: self.summary.merge(other.summary),
+        }
+    }
+}
+
+/// Escape special characters in query values.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('&', r"\&")
+        .replace('|', r"\|")
+}
+
+/// Convert a query language operator into its website value prefix.
+fn web_op(op: &str) -> &str {
+    match op {
+        "!=" => "!",
+        "~=" => "~",
+        "!~=" => "!~",
+        _ => "",
+    }
+}
+
+/// Query constraints in the form of (field, operator, value).
+#[derive(Default)]
+struct QueryBuilder(Vec<(String, &'static str, String)>);
+
+impl QueryBuilder {
+    fn push<V: Api>(&mut self, field: &str, op: &'static str, value: V) {
+        self.0.push((field.to_string(), op, value.api()));
+    }
+
+    fn iter(&self) -> impl Iterator<Item = String> + '_ {
+        self.0
+            .iter()
+            .map(|(field, op, value)| format!("{field}{op}{value}"))
+    }
+
+    /// Add constraints for all search parameters.
+    fn constraints(&mut self, params: &Parameters) -> crate::Result<()> {
+        let fields = [
+            ("component", &params.component, "="),
+            ("milestone", &params.milestone, "="),
+            ("owner", &params.owner, "="),
+            ("priority", &params.priority, "="),
+            ("reporter", &params.reporter, "="),
+            ("resolution", &params.resolution, "="),
+            ("status", &params.status, "="),
+            ("type", &params.kind, "="),
+            ("keywords", &params.keywords, "~="),
+        ];
+
+        for (field, values, op) in fields {
+            if let Some(values) = values.as_deref().filter(|x| !x.is_empty()) {
+                self.values(field, op, values)?;
+            }
+        }
+
+        if let Some(value) = &params.created {
+            self.time("time", value)?;
+        }
+
+        if let Some(value) = &params.updated {
+            self.time("changetime", value)?;
+        }
+
+        if let Some(values) = &params.summary {
+            self.push("summary", "~=", escape(&values.iter().join(" ")));
+        }
+
+        Ok(())
+    }
+
+    /// Add a multi-valued constraint where values match if any of them match.
+    fn values(&mut self, field: &str, op: &'static str, values: &[String]) -> crate::Result<()> {
+        let (negated, values): (Vec<&str>, Vec<&str>) = values
+            .iter()
+            .map(|x| x.as_str())
+            .partition(|x| x.starts_with('!'));
+        let (op, values) = match (negated.is_empty(), values.is_empty()) {
+            (true, _) => (op, values),
+            (false, true) => {
+                let op = if op == "~=" { "!~=" } else { "!=" };
+                (op, negated.into_iter().map(|x| &x[1..]).collect())
+            }
+            (false, false) => {
+                return Err(Error::InvalidValue(format!(
+                    "mixed negated and non-negated values: {field}"
+                )));
+            }
+        };
+
+        let value = values.iter().map(|x| escape(x)).join("|");
+        self.push(field, op, value);
+        Ok(())
+    }
+
+    /// Add a time range constraint.
+    fn time(&mut self, field: &str, value: &RangeOrValue<TimeDeltaOrStatic>) -> crate::Result<()> {
+        let value = match value {
+            RangeOrValue::Value(value) => format!("{}..", value.api()),
+            RangeOrValue::RangeOp(value) => range_op(value)?,
+            RangeOrValue::Range(value) => range(value),
+        };
+        self.push(field, "=", value);
+        Ok(())
+    }
+}
+
+// Trac only supports inclusive start bounds and exclusive end bounds for
+// times, so other bounds are treated as those.
+fn range_op<T>(value: &RangeOp<T>) -> crate::Result<String>
+where
+    T: Api + Eq + fmt::Display,
+{
+    match value {
+        RangeOp::Less(value) | RangeOp::LessOrEqual(value) => Ok(format!("..{}", value.api())),
+        RangeOp::GreaterOrEqual(value) | RangeOp::Greater(value) => {
+            Ok(format!("{}..", value.api()))
+        }
+        RangeOp::Equal(_) | RangeOp::NotEqual(_) => Err(Error::InvalidValue(format!(
+            "unsupported time operator: {value}"
+        ))),
+    }
+}
+
+fn range<T>(value: &Range<T>) -> String
+where
+    T: Api + Eq,
+{
+    match value {
+        Range::Range(r) => format!("{}..{}", r.start.api(), r.end.api()),
+        Range::Inclusive(r) => format!("{}..{}", r.start().api(), r.end().api()),
+        Range::To(r) => format!("..{}", r.end.api()),
+        Range::ToInclusive(r) => format!("..{}", r.end.api()),
+        Range::From(r) => format!("{}..", r.start.api()),
+        Range::Full(_) => "..".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("trac");
+        let server = TestServer::new().await;
+        let service = Trac::new(server.uri()).unwrap();
+
+        server.respond(200, path.join("search/valid")).await;
+
+        // valid RangeOrValue<TimeDeltaOrStatic> values
+        let times = vec![
+            "2020",
+            "2020-02",
+            "2020-02-01",
+            "2020-02-01T01:02:03Z",
+            "1h",
+            "<1d",
+            "<=1w",
+            ">1m",
+            ">=1y",
+            "2020..2021",
+            "2020..=2021",
+            "..2021",
+            "..=2021",
+            "2021..",
+        ];
+        for time in &times {
+            stream!(service.search().created(time.parse().unwrap()));
+            stream!(service.search().updated(time.parse().unwrap()));
+        }
+
+        // invalid time operators
+        for time in ["=1d", "!=1d"] {
+            let err = stream_result!(service.search().updated(time.parse().unwrap())).unwrap_err();
+            assert_matches!(err, Error::InvalidValue(_));
+        }
+
+        // multi-valued attributes
+        stream!(service.search().component(["cli", "lib"]));
+        stream!(service.search().keywords(["crash"]));
+        stream!(service.search().status(["!closed", "!new"]));
+
+        // mixed negated values
+        let err = stream_result!(service.search().status(["closed", "!new"])).unwrap_err();
+        assert_matches!(err, Error::InvalidValue(_));
+
+        // tickets are fetched in chunks using multicall requests
+        server.reset().await;
+        server
+            .respond_rpc("ticket.query", path.join("search/ids.json"))
+            .await;
+        server
+            .respond_rpc("system.multicall", path.join("search/tickets.json"))
+            .await;
+        let tickets = stream_result!(service.search().summary(["crash"])).unwrap();
+        assert_ordered_eq!(tickets.iter().map(|x| x.id), [2, 1]);
+    }
+
+    #[test]
+    fn encode() {
+        let service = Trac::new("https://trac.example.org").unwrap();
+
+        // open tickets by default
+        let request = service.search();
+        assert_eq!(
+            request.encode().unwrap(),
+            "status!=closed&max=0&order=id&desc=1"
+        );
+
+        let mut request = service.search();
+        request
+            .component(["a|b", "c"])
+            .status(["!closed"])
+            .keywords(["crash"])
+            .summary(["foo", "bar"])
+            .limit(10);
+        assert_eq!(
+            request.encode().unwrap(),
+            r"component=a\|b|c&status!=closed&keywords~=crash&summary~=foo bar&max=10&order=id&desc=1"
+        );
+    }
+
+    #[test]
+    fn search_url() {
+        let service = Trac::new("https://trac.example.org/project").unwrap();
+        let mut request = service.search();
+        request.owner(["user"]).summary(["crash"]);
+        let url = request.search_url().unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://trac.example.org/project/query?owner=user&summary=%7Ecrash&status=%21closed"
+        );
+    }
+}
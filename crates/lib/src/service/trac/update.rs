@@ -0,0 +1,285 @@
+use indexmap::IndexSet;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::Error;
+use crate::objects::trac::Ticket;
+use crate::service::bugzilla::update::SetChange;
+use crate::service::trac::Trac;
+use crate::traits::{Merge, RequestSend, RequestTemplate};
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Request {
+    #[serde(skip)]
+    service: Trac,
+    #[serde(skip)]
+    pub ids: Vec<u64>,
+    #[serde(flatten)]
+    pub params: Parameters,
+}
+
+impl RequestSend for Request {
+    type Output = ();
+
+    async fn send(&self) -> crate::Result<Self::Output> {
+        if self.ids.is_empty() {
+            return Err(Error::InvalidRequest("no IDs specified".to_string()));
+        }
+
+        // verify parameters exist
+        if self.params == Parameters::default() {
+            return Err(Error::EmptyParams);
+        }
+
+        for id in &self.ids {
+            // the current ticket is required for its change timestamp
+            let ticket = super::get::ticket(&self.service, *id, true).await?;
+            let attrs = self.encode(&ticket);
+            let comment = self.params.comment.as_deref().unwrap_or_default();
+            self.service
+                .call("ticket.update", json!([id, comment, attrs, true]), true)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl RequestTemplate for Request {
+    type Params = Parameters;
+    type Service = Trac;
+    const TYPE: &'static str = "update";
+
+    fn service(&self) -> &Self::Service {
+        &self.service
+    }
+
+    fn params(&mut self) -> &mut Self::Params {
+        &mut self.params
+    }
+}
+
+impl Request {
+    pub(super) fn new<I>(service: &Trac, ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            service: service.clone(),
+            ids: ids.into_iter().collect(),
+            params: Default::default(),
+        }
+    }
+
+    /// Encode ticket attribute changes into the form required for the request.
+    fn encode(&self, ticket: &Ticket) -> RequestParameters<'_> {
+        let params = &self.params;
+
+        // the resolve action requires its resolution to be passed separately
+        let resolve_resolution = params
+            .resolution
+            .as_deref()
+            .filter(|_| params.action.as_deref() == Some("resolve"));
+
+        RequestParameters {
+            ts: ticket.ts.clone(),
+            action: params.action.as_deref(),
+            action_resolve_resolve_resolution: resolve_resolution,
+            cc: params
+                .cc
+                .as_ref()
+                .map(|x| apply(&ticket.cc, x).iter().join(", ")),
+            component: params.component.as_deref(),
+            description: params.description.as_deref(),
+            keywords: params
+                .keywords
+                .as_ref()
+                .map(|x| apply(&ticket.keywords, x).iter().join(" ")),
+            milestone: params.milestone.as_deref(),
+            owner: params.owner.as_deref(),
+            priority: params.priority.as_deref(),
+            resolution: params.resolution.as_deref(),
+            severity: params.severity.as_deref(),
+            summary: params.summary.as_deref(),
+            kind: params.kind.as_deref(),
+            version: params.version.as_deref(),
+        }
+    }
+}
+
+/// Apply a set of changes to existing values.
+///
+/// Set values override the entire list, ignoring any prefixed values.
+fn apply(existing: &IndexSet<String>, changes: &[SetChange<String>]) -> IndexSet<String> {
+    let set: IndexSet<_> = changes
+        .iter()
+        .filter_map(|x| match x {
+            SetChange::Set(value) => Some(value.clone()),
+            _ => None,
+        })
+        .collect();
+
+    if !set.is_empty() {
+        return set;
+    }
+
+    let mut values = existing.clone();
+    for change in changes {
+        match change {
+            SetChange::Add(value) => values.insert(value.clone()),
+            SetChange::Remove(value) => values.shift_remove(value),
+            SetChange::Set(_) => unreachable!("set values already handled"),
+        };
+    }
+    values
+}
+
+/// Ticket update parameters.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub action: Option<String>,
+    pub cc: Option<Vec<SetChange<String>>>,
+    pub comment: Option<String>,
+    pub component: Option<String>,
+    pub description: Option<String>,
+    pub keywords: Option<Vec<SetChange<String>>>,
+    pub milestone: Option<String>,
+    pub owner: Option<String>,
+    pub priority: Option<String>,
+    pub resolution: Option<String>,
+    pub severity: Option<String>,
+    pub summary: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub version: Option<String>,
+}
+
+impl Merge for Parameters {
+    fn merge(&mut self, other: Self) {
+        *self = Self {
+            action: self.action.merge(other.action),
+            cc: self.cc.merge(other.cc),
+            comment: self.comment.merge(other.comment),
+            component: self.component.merge(other.component),
+            description: self.description.merge(other.description),
+            keywords: self.keywords.merge(other.keywords),
+            milestone: self.milestone.merge(other.milestone),
+            owner: self.owner.merge(other.owner),
+            priority: self.priority.merge(other.priority),
+            resolution: self.resolution.merge(other.resolution),
+            severity: self.severity.merge(other.severity),
+            summary: self.summary.merge(other.summary),
+            kind: self.kind.merge(other.kind),
+            version: self.version.merge(other.version),
+        }
+    }
+}
+
+/// Internal ticket update request parameters.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+struct RequestParameters<'a> {
+    #[serde(rename = "_ts")]
+    ts: Option<String>,
+    action: Option<&'a str>,
+    action_resolve_resolve_resolution: Option<&'a str>,
+    cc: Option<String>,
+    component: Option<&'a str>,
+    description: Option<&'a str>,
+    keywords: Option<String>,
+    milestone: Option<&'a str>,
+    owner: Option<&'a str>,
+    priority: Option<&'a str>,
+    resolution: Option<&'a str>,
+    severity: Option<&'a str>,
+    summary: Option<&'a str>,
+    #[serde(rename = "type")]
+    kind: Option<&'a str>,
+    version: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use crate::test::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request() {
+        let path = TESTDATA_PATH.join("trac");
+        let server = TestServer::new().await;
+        let service = Trac::builder(server.uri())
+            .unwrap()
+            .user("user")
+            .password("pass")
+            .build()
+            .unwrap();
+
+        // no IDs
+        let ids = Vec::<u64>::new();
+        let err = service.update(ids).send().await.unwrap_err();
+        assert_matches!(err, Error::InvalidRequest(_));
+        assert_err_re!(err, "no IDs specified");
+
+        // empty params
+        let err = service.update([1]).send().await.unwrap_err();
+        assert_matches!(err, Error::EmptyParams);
+
+        // authentication required
+        let unauthed = Trac::new(server.uri()).unwrap();
+        let mut request = unauthed.update([1]);
+        request.params.summary = Some("summary".to_string());
+        let err = request.send().await.unwrap_err();
+        assert_matches!(err, Error::Auth);
+
+        // valid
+        server
+            .respond_rpc("ticket.get", path.join("get/ticket.json"))
+            .await;
+        server
+            .respond_rpc("ticket.update", path.join("get/ticket.json"))
+            .await;
+        let mut request = service.update([1]);
+        request.params.comment = Some("comment".to_string());
+        request.params.keywords = Some(vec!["+new".parse().unwrap(), "-crash".parse().unwrap()]);
+        request.params.action = Some("resolve".to_string());
+        request.params.resolution = Some("fixed".to_string());
+        request.send().await.unwrap();
+    }
+
+    #[test]
+    fn encode() {
+        let service = Trac::new("https://trac.example.org").unwrap();
+        let ticket = Ticket {
+            id: 1,
+            keywords: ["crash", "startup"].into_iter().map(Into::into).collect(),
+            ts: Some("1708077626123456".to_string()),
+            ..Default::default()
+        };
+
+        let mut request = service.update([1]);
+        request.params.keywords = Some(vec!["+new".parse().unwrap(), "-crash".parse().unwrap()]);
+        request.params.action = Some("resolve".to_string());
+        request.params.resolution = Some("fixed".to_string());
+        assert_eq!(
+            json!(request.encode(&ticket)),
+            json!({
+                "_ts": "1708077626123456",
+                "action": "resolve",
+                "action_resolve_resolve_resolution": "fixed",
+                "keywords": "startup new",
+                "resolution": "fixed",
+            })
+        );
+
+        // set values override existing values
+        let mut request = service.update([1]);
+        request.params.keywords = Some(vec!["a".parse().unwrap(), "+b".parse().unwrap()]);
+        assert_eq!(json!(request.encode(&ticket))["keywords"], "a");
+    }
+}
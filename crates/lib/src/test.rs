@@ -62,6 +62,23 @@ impl TestServer {
         self.respond_match(matchers::any(), status, path).await
     }
 
+    /// Respond to a SOAP method call with the XML response from a given file.
+    pub async fn respond_soap<P: AsRef<Utf8Path>>(&self, method: &str, path: P) {
+        let path = path.as_ref();
+        let xml = fs::read_to_string(path).unwrap_or_else(|e| panic!("invalid path: {path}: {e}"));
+        let template = ResponseTemplate::new(200).set_body_raw(xml.as_bytes(), "text/xml");
+        Mock::given(matchers::body_string_contains(format!("<{method} ")))
+            .respond_with(template)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Respond to a JSON-RPC method call with the JSON response from a given file.
+    pub async fn respond_rpc<P: AsRef<Utf8Path>>(&self, method: &str, path: P) {
+        let matcher = matchers::body_string_contains(format!(r#""method":"{method}""#));
+        self.respond_match(matcher, 200, path).await
+    }
+
     pub async fn respond_custom<M>(&self, matcher: M, response: ResponseTemplate)
     where
         M: 'static + Match,
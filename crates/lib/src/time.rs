@@ -1,6 +1,7 @@
 use std::fmt;
 use std::str::FromStr;
 
+use chrono::{DateTime, Utc};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 use crate::Error;
@@ -17,6 +18,16 @@ pub enum TimeDeltaOrStatic {
     Static(TimeStatic),
 }
 
+impl TimeDeltaOrStatic {
+    /// Return the absolute datetime for the value.
+    pub fn datetime(&self) -> DateTime<Utc> {
+        match self {
+            Self::Delta(value) => value.datetime(),
+            Self::Static(value) => value.datetime(),
+        }
+    }
+}
+
 impl FromStr for TimeDeltaOrStatic {
     type Err = Error;
 
@@ -83,4 +94,12 @@ mod tests {
             assert!(DateTime::parse_from_rfc3339(&api).is_ok());
         }
     }
+
+    #[test]
+    fn datetime() {
+        let time = TimeDeltaOrStatic::from_str("2020-08-09").unwrap();
+        assert_eq!(time.datetime().to_rfc3339(), "2020-08-09T00:00:00+00:00");
+        let time = TimeDeltaOrStatic::from_str("1d").unwrap();
+        assert!(time.datetime() < Utc::now());
+    }
 }
@@ -2,6 +2,7 @@ use std::fmt;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
+use chrono::DateTime;
 use chrono::offset::Utc;
 use chronoutil::RelativeDuration;
 use regex::Regex;
@@ -23,6 +24,27 @@ impl TimeDelta {
     fn delta(&self) -> RelativeDuration {
         self.delta
     }
+
+    /// Return the absolute datetime relative to the current time.
+    pub fn datetime(&self) -> DateTime<Utc> {
+        Utc::now() - self.delta()
+    }
+
+    /// Return the fixed duration of the interval.
+    ///
+    /// Intervals using calendar units, months and years, return None since their lengths
+    /// vary.
+    pub fn duration(&self) -> Option<chrono::TimeDelta> {
+        let calendar = RELATIVE_TIME_RE
+            .captures_iter(&self.raw)
+            .any(|c| matches!(&c["unit"], "y" | "m"));
+        if calendar {
+            None
+        } else {
+            let now = Utc::now();
+            Some(now - (now - self.delta()))
+        }
+    }
 }
 
 /// Convert a raw, relative time interval to its numeric equivalent.
@@ -84,8 +106,7 @@ impl AsRef<str> for TimeDelta {
 
 impl Api for TimeDelta {
     fn api(&self) -> String {
-        let datetime = Utc::now() - self.delta();
-        datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+        self.datetime().format("%Y-%m-%dT%H:%M:%SZ").to_string()
     }
 }
 
@@ -139,4 +160,30 @@ mod tests {
             assert!(DateTime::parse_from_rfc3339(&api).is_ok());
         }
     }
+
+    #[test]
+    fn datetime() {
+        let delta: TimeDelta = "1h".parse().unwrap();
+        let expected = Utc::now() - chrono::TimeDelta::hours(1);
+        let diff = (delta.datetime() - expected).abs();
+        assert!(diff < chrono::TimeDelta::seconds(5));
+    }
+
+    #[test]
+    fn duration() {
+        for (s, expected) in [
+            ("3w", chrono::TimeDelta::weeks(3)),
+            ("10min", chrono::TimeDelta::minutes(10)),
+            ("100s", chrono::TimeDelta::seconds(100)),
+        ] {
+            let delta: TimeDelta = s.parse().unwrap();
+            assert_eq!(delta.duration(), Some(expected));
+        }
+
+        // calendar units
+        for s in ["1y", "2m"] {
+            let delta: TimeDelta = s.parse().unwrap();
+            assert!(delta.duration().is_none());
+        }
+    }
 }
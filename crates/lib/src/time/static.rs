@@ -53,6 +53,13 @@ impl FromStr for TimeStatic {
     }
 }
 
+impl TimeStatic {
+    /// Return the absolute datetime.
+    pub fn datetime(&self) -> DateTime<Utc> {
+        self.value
+    }
+}
+
 impl fmt::Display for TimeStatic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.raw)
@@ -99,4 +106,10 @@ mod tests {
             assert_eq!(time.api(), api);
         }
     }
+
+    #[test]
+    fn datetime() {
+        let time = TimeStatic::from_str("2020-08-09").unwrap();
+        assert_eq!(time.datetime().to_rfc3339(), "2020-08-09T00:00:00+00:00");
+    }
 }